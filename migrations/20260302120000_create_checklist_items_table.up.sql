@@ -0,0 +1,15 @@
+-- Пункты чек-листа внутри задачи.
+CREATE TABLE checklist_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    done BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now()
+);
+
+CREATE INDEX idx_checklist_items_task_id ON checklist_items(task_id);
+
+-- Если true — задача автоматически переходит в "done",
+-- когда отмечены все пункты её чек-листа.
+ALTER TABLE tasks ADD COLUMN auto_complete BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,6 @@
+DROP TABLE IF EXISTS task_completions;
+ALTER TABLE tasks DROP COLUMN IF EXISTS series_id;
+ALTER TABLE tasks DROP COLUMN IF EXISTS recurrence_interval_days;
+ALTER TABLE tasks DROP COLUMN IF EXISTS recurrence_mode;
+ALTER TABLE tasks DROP COLUMN IF EXISTS rrule;
+ALTER TABLE tasks DROP COLUMN IF EXISTS due_at;
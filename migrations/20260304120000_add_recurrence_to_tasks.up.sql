@@ -0,0 +1,29 @@
+-- Срок выполнения задачи (опционально).
+ALTER TABLE tasks ADD COLUMN due_at TIMESTAMPTZ;
+
+-- Повторяющиеся задачи.
+--   rrule                    — правило RFC 5545 (для режима 'schedule');
+--   recurrence_mode          — 'schedule' (по расписанию RRULE) или
+--                              'after_completion' (через N дней после выполнения);
+--   recurrence_interval_days — N для режима 'after_completion';
+--   series_id                — общая серия всех повторений (ID первой задачи).
+ALTER TABLE tasks ADD COLUMN rrule TEXT;
+ALTER TABLE tasks ADD COLUMN recurrence_mode TEXT
+    CHECK (recurrence_mode IN ('schedule', 'after_completion'));
+ALTER TABLE tasks ADD COLUMN recurrence_interval_days INTEGER
+    CHECK (recurrence_interval_days > 0);
+ALTER TABLE tasks ADD COLUMN series_id UUID;
+
+CREATE INDEX idx_tasks_series_id ON tasks(series_id);
+
+-- История выполнений серии. task_id обнуляется, если повторение удалили,
+-- но сама запись о выполнении остаётся.
+CREATE TABLE task_completions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    series_id UUID NOT NULL,
+    task_id UUID REFERENCES tasks(id) ON DELETE SET NULL,
+    due_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_task_completions_series_id ON task_completions(series_id);
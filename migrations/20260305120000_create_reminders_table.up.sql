@@ -0,0 +1,22 @@
+-- Напоминания по задачам.
+--   channel — канал доставки ('email' или 'webhook');
+--   target  — адрес получателя (email или URL вебхука);
+--   status  — 'pending' → 'sent' (или 'failed' после исчерпания попыток).
+CREATE TABLE reminders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    remind_at TIMESTAMPTZ NOT NULL,
+    channel TEXT NOT NULL CHECK (channel IN ('email', 'webhook')),
+    target TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'sent', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    sent_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now()
+);
+
+-- Воркер выбирает только ожидающие напоминания по времени срабатывания.
+CREATE INDEX idx_reminders_pending ON reminders(remind_at) WHERE status = 'pending';
+CREATE INDEX idx_reminders_task_id ON reminders(task_id);
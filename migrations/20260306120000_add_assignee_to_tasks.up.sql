@@ -0,0 +1,5 @@
+-- Исполнитель задачи — любой зарегистрированный пользователь.
+-- При удалении пользователя задача остаётся, но без исполнителя.
+ALTER TABLE tasks ADD COLUMN assignee_id UUID REFERENCES users(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_tasks_assignee_id ON tasks(assignee_id);
@@ -0,0 +1,12 @@
+-- Комментарии к задачам. body — Markdown (хранится как есть).
+-- edited_at заполняется при каждом редактировании (NULL — не редактировался).
+CREATE TABLE task_comments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    task_id UUID NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    edited_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_task_comments_task_id ON task_comments(task_id, created_at);
@@ -0,0 +1,2 @@
+ALTER TABLE todo_lists DROP COLUMN IF EXISTS position;
+ALTER TABLE tasks DROP COLUMN IF EXISTS position;
@@ -0,0 +1,21 @@
+-- Ручная сортировка: дробные лексикографические ранги (см. models::rank).
+-- COLLATE "C" — побайтовое сравнение, от локали БД порядок не зависит.
+-- Существующие строки получают ранги в текущем порядке выдачи:
+-- задачи — по created_at ASC, списки — по created_at DESC.
+ALTER TABLE tasks ADD COLUMN position TEXT COLLATE "C";
+UPDATE tasks t SET position = lpad(to_hex(r.rn), 8, '0') || 'i'
+FROM (
+    SELECT id, row_number() OVER (PARTITION BY list_id ORDER BY created_at, id) AS rn FROM tasks
+) r
+WHERE r.id = t.id;
+ALTER TABLE tasks ALTER COLUMN position SET NOT NULL;
+CREATE INDEX idx_tasks_list_position ON tasks(list_id, position);
+
+ALTER TABLE todo_lists ADD COLUMN position TEXT COLLATE "C";
+UPDATE todo_lists l SET position = lpad(to_hex(r.rn), 8, '0') || 'i'
+FROM (
+    SELECT id, row_number() OVER (PARTITION BY user_id ORDER BY created_at DESC, id) AS rn FROM todo_lists
+) r
+WHERE r.id = l.id;
+ALTER TABLE todo_lists ALTER COLUMN position SET NOT NULL;
+CREATE INDEX idx_todo_lists_user_position ON todo_lists(user_id, position);
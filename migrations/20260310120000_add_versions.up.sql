@@ -0,0 +1,4 @@
+-- Версия строки для оптимистичных блокировок: растёт на каждое изменение,
+-- отдаётся клиенту в заголовке ETag и сверяется с If-Match.
+ALTER TABLE todo_lists ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE tasks ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
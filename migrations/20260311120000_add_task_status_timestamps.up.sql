@@ -0,0 +1,9 @@
+-- Отметки переходов статуса задачи:
+--   started_at   — первый переход в in_progress;
+--   completed_at — переход в done (очищается при переоткрытии).
+-- Для существующих задач точное время неизвестно — берём updated_at.
+ALTER TABLE tasks ADD COLUMN started_at TIMESTAMPTZ;
+ALTER TABLE tasks ADD COLUMN completed_at TIMESTAMPTZ;
+
+UPDATE tasks SET started_at = updated_at WHERE status = 'in_progress';
+UPDATE tasks SET completed_at = updated_at WHERE status = 'done';
@@ -0,0 +1,43 @@
+-- Пользовательские статусы списка — колонки канбан-доски.
+-- Каждый статус относится к одной из категорий, которая задаёт tasks.status:
+--   not_started → todo, active → in_progress, done → done.
+-- В каждой категории у списка всегда есть хотя бы один статус (проверяет сервис).
+CREATE TABLE list_statuses (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    list_id UUID NOT NULL REFERENCES todo_lists(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    category TEXT NOT NULL CHECK (category IN ('not_started', 'active', 'done')),
+    position TEXT COLLATE "C" NOT NULL,
+    -- Сколько задач может одновременно стоять в колонке (NULL — без ограничения).
+    wip_limit INTEGER CHECK (wip_limit > 0),
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now(),
+    UNIQUE (list_id, name)
+);
+
+CREATE INDEX idx_list_statuses_list_position ON list_statuses(list_id, position);
+
+-- Существующие списки получают набор по умолчанию — те же три статуса.
+INSERT INTO list_statuses (list_id, name, category, position)
+SELECT l.id, d.name, d.category, d.position
+FROM todo_lists l
+CROSS JOIN (VALUES
+    ('To Do', 'not_started', '00000001i'),
+    ('In Progress', 'active', '00000002i'),
+    ('Done', 'done', '00000003i')
+) AS d(name, category, position);
+
+-- Колонка задачи на доске. NULL (или статус другой категории) —
+-- задача стоит в первой колонке категории своего tasks.status.
+ALTER TABLE tasks ADD COLUMN status_id UUID REFERENCES list_statuses(id) ON DELETE SET NULL;
+
+UPDATE tasks t SET status_id = s.id
+FROM list_statuses s
+WHERE s.list_id = t.list_id
+  AND s.category = CASE t.status
+      WHEN 'todo' THEN 'not_started'
+      WHEN 'in_progress' THEN 'active'
+      ELSE 'done'
+  END;
+
+CREATE INDEX idx_tasks_status_id ON tasks(status_id);
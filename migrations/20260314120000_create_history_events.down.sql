@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS history_events;
+DROP FUNCTION IF EXISTS history_events_append_only();
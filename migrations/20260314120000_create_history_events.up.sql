@@ -0,0 +1,32 @@
+-- Журнал изменений списков и задач (append-only).
+-- changes: {"поле": {"old": ..., "new": ...}} — только изменившиеся поля.
+-- Внешних ключей на список и задачу нет: записи переживают удаление
+-- и остаются в журнале. Автор при удалении пользователя обнуляется.
+CREATE TABLE history_events (
+    id BIGSERIAL PRIMARY KEY,
+    list_id UUID NOT NULL,
+    task_id UUID,
+    actor_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    action TEXT NOT NULL CHECK (action IN ('created', 'updated', 'deleted')),
+    changes JSONB NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_history_events_list_id ON history_events(list_id, id);
+CREATE INDEX idx_history_events_task_id ON history_events(task_id, id);
+
+-- Записи журнала нельзя менять или удалять напрямую. Разрешены только
+-- каскады внешних ключей (обнуление автора): они выполняются из триггеров
+-- ссылочной целостности, то есть на глубине больше 1.
+CREATE FUNCTION history_events_append_only() RETURNS trigger AS $$
+BEGIN
+    IF pg_trigger_depth() > 1 THEN
+        RETURN CASE WHEN TG_OP = 'DELETE' THEN OLD ELSE NEW END;
+    END IF;
+    RAISE EXCEPTION 'history_events is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER history_events_append_only
+BEFORE UPDATE OR DELETE ON history_events
+FOR EACH ROW EXECUTE FUNCTION history_events_append_only();
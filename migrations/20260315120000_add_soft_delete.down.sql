@@ -0,0 +1,17 @@
+ALTER TABLE history_events DISABLE TRIGGER history_events_append_only;
+DELETE FROM history_events WHERE action = 'restored';
+ALTER TABLE history_events ENABLE TRIGGER history_events_append_only;
+
+ALTER TABLE history_events DROP CONSTRAINT history_events_action_check;
+ALTER TABLE history_events ADD CONSTRAINT history_events_action_check
+    CHECK (action IN ('created', 'updated', 'deleted'));
+
+-- Содержимое корзины удаляется окончательно.
+DELETE FROM tasks WHERE deleted_at IS NOT NULL;
+DELETE FROM todo_lists WHERE deleted_at IS NOT NULL;
+
+DROP INDEX IF EXISTS idx_tasks_deleted_at;
+DROP INDEX IF EXISTS idx_todo_lists_deleted_at;
+
+ALTER TABLE tasks DROP COLUMN deleted_at;
+ALTER TABLE todo_lists DROP COLUMN deleted_at;
@@ -0,0 +1,13 @@
+-- Мягкое удаление списков и задач: строка остаётся в корзине до очистки.
+-- Задачи удалённого списка получают ту же отметку deleted_at, что и список, —
+-- по ней восстановление списка возвращает ровно те задачи, что удалены вместе с ним.
+ALTER TABLE todo_lists ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE tasks ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX idx_todo_lists_deleted_at ON todo_lists(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX idx_tasks_deleted_at ON tasks(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- Восстановление из корзины попадает в журнал изменений.
+ALTER TABLE history_events DROP CONSTRAINT history_events_action_check;
+ALTER TABLE history_events ADD CONSTRAINT history_events_action_check
+    CHECK (action IN ('created', 'updated', 'deleted', 'restored'));
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_todo_lists_auto_archive;
+
+ALTER TABLE tasks DROP COLUMN archived_at;
+ALTER TABLE todo_lists DROP COLUMN auto_archive_days;
+ALTER TABLE todo_lists DROP COLUMN archived_at;
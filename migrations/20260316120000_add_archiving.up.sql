@@ -0,0 +1,10 @@
+-- Архив: архивный список скрыт из GET /lists и доступен только на чтение.
+-- auto_archive_days — правило списка: выполненные задачи архивируются
+-- через столько дней после перехода в done (фоновым воркером).
+ALTER TABLE todo_lists ADD COLUMN archived_at TIMESTAMPTZ;
+ALTER TABLE todo_lists ADD COLUMN auto_archive_days INTEGER CHECK (auto_archive_days > 0);
+
+-- Архивные задачи скрыты из списка задач и с доски.
+ALTER TABLE tasks ADD COLUMN archived_at TIMESTAMPTZ;
+
+CREATE INDEX idx_todo_lists_auto_archive ON todo_lists(id) WHERE auto_archive_days IS NOT NULL;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_tasks_search;
+ALTER TABLE tasks DROP COLUMN IF EXISTS search_vector;
+
+DROP INDEX IF EXISTS idx_todo_lists_search;
+ALTER TABLE todo_lists DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,13 @@
+-- Полнотекстовый поиск по названиям списков и задач.
+-- Конфигурация 'russian' разбирает кириллицу русским стеммером, а латиницу —
+-- английским (asciiword → english_stem), поэтому одна колонка покрывает оба языка.
+ALTER TABLE todo_lists ADD COLUMN search_vector tsvector
+    GENERATED ALWAYS AS (to_tsvector('russian', title)) STORED;
+CREATE INDEX idx_todo_lists_search ON todo_lists USING GIN (search_vector);
+
+ALTER TABLE tasks ADD COLUMN search_vector tsvector
+    GENERATED ALWAYS AS (to_tsvector('russian', title)) STORED;
+CREATE INDEX idx_tasks_search ON tasks USING GIN (search_vector);
+
+-- Триграммы — для поиска с опечатками (word_similarity).
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
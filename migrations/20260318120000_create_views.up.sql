@@ -0,0 +1,14 @@
+-- Сохранённые представления: фильтр и сортировка задач по всем спискам
+-- пользователя. filter и sort — строки языка запросов задач (как в
+-- GET /lists/{id}/tasks); разбираются заново при каждом запросе, поэтому
+-- относительные даты (`now-7d`) всегда считаются от текущего момента.
+CREATE TABLE views (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    filter TEXT NOT NULL DEFAULT '',
+    sort TEXT NOT NULL DEFAULT '',
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now(),
+    UNIQUE (user_id, name)
+);
@@ -0,0 +1,22 @@
+-- Шаблоны списков: название и задачи в заданном порядке. Срок задачи
+-- хранится как смещение в минутах от начала списка: при сохранении
+-- шаблона — от самого раннего срока исходного списка (у этой задачи
+-- смещение 0), у нового списка — от `starts_at`.
+CREATE TABLE list_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    title TEXT NOT NULL,
+    created_at TIMESTAMPTZ DEFAULT now(),
+    updated_at TIMESTAMPTZ DEFAULT now()
+);
+
+CREATE INDEX idx_list_templates_user_id ON list_templates(user_id);
+
+CREATE TABLE list_template_tasks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    template_id UUID NOT NULL REFERENCES list_templates(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    due_offset_minutes INTEGER,
+    UNIQUE (template_id, position)
+);
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_reminders_sending;
+UPDATE reminders SET status = 'pending' WHERE status = 'sending';
+ALTER TABLE reminders DROP COLUMN IF EXISTS lease_until;
+ALTER TABLE reminders DROP CONSTRAINT reminders_status_check;
+ALTER TABLE reminders ADD CONSTRAINT reminders_status_check
+    CHECK (status IN ('pending', 'sent', 'failed'));
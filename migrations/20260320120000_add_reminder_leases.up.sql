@@ -0,0 +1,10 @@
+-- Доставка напоминаний вне транзакции: воркер сначала захватывает строки
+-- ('pending' → 'sending' с арендой до lease_until) коротким коммитом, потом
+-- отправляет и отмечает каждое напоминание отдельным запросом. Если воркер
+-- упал посреди отправки, по истечении аренды напоминание захватят снова.
+ALTER TABLE reminders DROP CONSTRAINT reminders_status_check;
+ALTER TABLE reminders ADD CONSTRAINT reminders_status_check
+    CHECK (status IN ('pending', 'sending', 'sent', 'failed'));
+ALTER TABLE reminders ADD COLUMN lease_until TIMESTAMPTZ;
+
+CREATE INDEX idx_reminders_sending ON reminders(lease_until) WHERE status = 'sending';
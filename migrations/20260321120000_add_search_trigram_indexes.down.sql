@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_tasks_title_trgm;
+DROP INDEX IF EXISTS idx_todo_lists_title_trgm;
@@ -0,0 +1,4 @@
+-- Триграммные индексы для поиска с опечатками (`q <% title`): без них
+-- word_similarity считается по каждой строке пользователя.
+CREATE INDEX idx_todo_lists_title_trgm ON todo_lists USING GIN (title gin_trgm_ops);
+CREATE INDEX idx_tasks_title_trgm ON tasks USING GIN (title gin_trgm_ops);
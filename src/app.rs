@@ -21,13 +21,105 @@ use crate::state::AppState;
         handlers::lists::get_all,
         handlers::lists::get_one,
         handlers::lists::update,
+        handlers::lists::patch,
         handlers::lists::delete,
+        handlers::lists::reposition,
+        handlers::lists::archive,
+        handlers::lists::unarchive,
         // Tasks
         handlers::tasks::create,
         handlers::tasks::get_all,
+        handlers::tasks::bulk,
         handlers::tasks::get_one,
         handlers::tasks::update,
+        handlers::tasks::patch,
+        handlers::tasks::update_status,
+        handlers::tasks::reopen,
         handlers::tasks::delete,
+        handlers::tasks::reposition,
+        handlers::tasks::move_task,
+        handlers::tasks::copy_task,
+        handlers::tasks::attach_label,
+        handlers::tasks::detach_label,
+        // Assignees
+        handlers::assignees::assign,
+        handlers::assignees::unassign,
+        handlers::assignees::assigned_to_me,
+        // Attachments
+        handlers::attachments::upload,
+        handlers::attachments::get_all,
+        handlers::attachments::download,
+        handlers::attachments::delete,
+        handlers::attachments::storage_usage,
+        // Labels
+        handlers::labels::create,
+        handlers::labels::get_all,
+        handlers::labels::get_one,
+        handlers::labels::update,
+        handlers::labels::delete,
+        // Checklist
+        handlers::checklist::create,
+        handlers::checklist::get_all,
+        handlers::checklist::update,
+        handlers::checklist::delete,
+        // Comments
+        handlers::comments::create,
+        handlers::comments::get_all,
+        handlers::comments::update,
+        handlers::comments::delete,
+        // Dependencies
+        handlers::dependencies::add,
+        handlers::dependencies::remove,
+        // Recurrence
+        handlers::recurrence::set,
+        handlers::recurrence::clear,
+        handlers::recurrence::history,
+        // Reminders
+        handlers::reminders::create,
+        handlers::reminders::get_all,
+        handlers::reminders::snooze,
+        handlers::reminders::delete,
+        // Board
+        handlers::statuses::create,
+        handlers::statuses::get_all,
+        handlers::statuses::update,
+        handlers::statuses::delete,
+        handlers::statuses::reposition,
+        handlers::statuses::board,
+        handlers::statuses::move_to_column,
+        // Time tracking
+        handlers::time_tracking::start_timer,
+        handlers::time_tracking::stop_timer,
+        handlers::time_tracking::create_entry,
+        handlers::time_tracking::get_entries,
+        handlers::time_tracking::delete_entry,
+        handlers::time_tracking::list_time,
+        handlers::time_tracking::running_timer,
+        handlers::time_tracking::report,
+        // History
+        handlers::history::list_history,
+        handlers::history::task_history,
+        // Trash
+        handlers::trash::get_trash,
+        handlers::trash::restore_list,
+        handlers::trash::restore_task,
+        // Search
+        handlers::search::search,
+        // Views
+        handlers::views::create,
+        handlers::views::get_all,
+        handlers::views::get_one,
+        handlers::views::update,
+        handlers::views::delete,
+        handlers::views::tasks,
+        // Quick add
+        handlers::quick_add::quick_add,
+        // Templates
+        handlers::templates::save,
+        handlers::templates::get_all,
+        handlers::templates::get_one,
+        handlers::templates::delete,
+        handlers::templates::instantiate,
     ),
     components(
         schemas(
@@ -39,11 +131,85 @@ use crate::state::AppState;
             // Lists
             crate::dto::lists::CreateListRequest,
             crate::dto::lists::UpdateListRequest,
+            crate::dto::lists::PatchListRequest,
             crate::dto::lists::ListResponse,
+            crate::dto::positions::PositionRequest,
             // Tasks
             crate::dto::tasks::CreateTaskRequest,
             crate::dto::tasks::UpdateTaskRequest,
+            crate::dto::tasks::PatchTaskRequest,
+            crate::dto::bulk::BulkMode,
+            crate::dto::bulk::BulkOperation,
+            crate::dto::bulk::BulkRequest,
+            crate::dto::bulk::BulkItemResult,
+            crate::dto::bulk::BulkResponse,
             crate::dto::tasks::TaskResponse,
+            crate::dto::tasks::UpdateTaskStatusRequest,
+            crate::dto::tasks::TaskTargetRequest,
+            crate::dto::tasks::AddDependencyRequest,
+            crate::dto::tasks::AssignTaskRequest,
+            crate::dto::tasks::AssigneeResponse,
+            // Attachments
+            crate::dto::attachments::UploadAttachmentForm,
+            crate::dto::attachments::AttachmentResponse,
+            crate::dto::attachments::StorageUsageResponse,
+            // Labels
+            crate::dto::labels::CreateLabelRequest,
+            crate::dto::labels::UpdateLabelRequest,
+            crate::dto::labels::LabelResponse,
+            // Checklist
+            crate::dto::checklist::CreateChecklistItemRequest,
+            crate::dto::checklist::UpdateChecklistItemRequest,
+            crate::dto::checklist::ChecklistItemResponse,
+            crate::dto::checklist::ChecklistProgressResponse,
+            // Comments
+            crate::dto::comments::CommentRequest,
+            crate::dto::comments::CommentResponse,
+            // Recurrence
+            crate::dto::recurrence::SetRecurrenceRequest,
+            crate::dto::recurrence::RecurrenceResponse,
+            crate::dto::recurrence::CompletionResponse,
+            // Reminders
+            crate::dto::reminders::CreateReminderRequest,
+            crate::dto::reminders::SnoozeReminderRequest,
+            crate::dto::reminders::ReminderResponse,
+            // Board
+            crate::dto::statuses::StatusRequest,
+            crate::dto::statuses::StatusResponse,
+            crate::dto::statuses::TaskColumnResponse,
+            crate::dto::statuses::MoveToColumnRequest,
+            crate::dto::statuses::BoardColumnResponse,
+            crate::dto::statuses::BoardResponse,
+            // Time tracking
+            crate::dto::time_tracking::CreateTimeEntryRequest,
+            crate::dto::time_tracking::TimeEntryResponse,
+            crate::dto::time_tracking::TaskTimeResponse,
+            crate::dto::time_tracking::ListTimeResponse,
+            crate::dto::time_tracking::ReportFormat,
+            crate::dto::time_tracking::TimeReportRowResponse,
+            crate::dto::time_tracking::TimeReportResponse,
+            // History
+            crate::dto::history::HistoryEventResponse,
+            crate::dto::history::HistoryPageResponse,
+            // Trash
+            crate::dto::trash::TrashedListResponse,
+            crate::dto::trash::TrashedTaskResponse,
+            crate::dto::trash::TrashResponse,
+            // Search
+            crate::dto::search::SearchResultResponse,
+            // Views
+            crate::dto::views::CreateViewRequest,
+            crate::dto::views::UpdateViewRequest,
+            crate::dto::views::ViewResponse,
+            // Quick add
+            crate::dto::quick_add::QuickAddRequest,
+            crate::dto::quick_add::QuickAddInterpretation,
+            crate::dto::quick_add::QuickAddResponse,
+            // Templates
+            crate::dto::templates::SaveTemplateRequest,
+            crate::dto::templates::InstantiateTemplateRequest,
+            crate::dto::templates::TemplateTaskResponse,
+            crate::dto::templates::TemplateResponse,
             // Errors
             crate::dto::ErrorResponse,
         )
@@ -53,7 +219,22 @@ use crate::state::AppState;
         (name = "Health", description = "Проверка жизни сервиса"),
         (name = "Auth", description = "Регистрация и авторизация (JWT)"),
         (name = "Lists", description = "CRUD TODO-листов"),
-        (name = "Tasks", description = "CRUD задач внутри списков")
+        (name = "Tasks", description = "CRUD задач внутри списков"),
+        (name = "Assignees", description = "Исполнители задач и задачи, назначенные мне"),
+        (name = "Attachments", description = "Файлы, прикреплённые к задачам"),
+        (name = "Labels", description = "Метки (теги), общие для всех списков пользователя"),
+        (name = "Checklist", description = "Чек-лист (подзадачи) внутри задачи"),
+        (name = "Comments", description = "Обсуждение задачи (Markdown)"),
+        (name = "Dependencies", description = "Зависимости между задачами (blocked by)"),
+        (name = "Recurrence", description = "Повторяющиеся задачи (RRULE)"),
+        (name = "Reminders", description = "Напоминания по задачам (email, webhook)"),
+        (name = "Board", description = "Статусы списка (колонки) и канбан-доска"),
+        (name = "Time tracking", description = "Оценки, таймеры и отчёты по учтённому времени"),
+        (name = "History", description = "Журнал изменений списков и задач"),
+        (name = "Trash", description = "Корзина: восстановление удалённых списков и задач"),
+        (name = "Search", description = "Полнотекстовый поиск по спискам и задачам"),
+        (name = "Views", description = "Представления: задачи из всех списков по сохранённым фильтрам"),
+        (name = "Templates", description = "Шаблоны списков: сохранить список и создать из него новый")
     )
 )]
 pub struct ApiDoc;
@@ -80,5 +261,21 @@ pub fn create_router() -> Router<AppState> {
         .merge(routes::auth::router())
         .merge(routes::lists::router())
         .merge(routes::tasks::router())
+        .merge(routes::assignees::router())
+        .merge(routes::attachments::router())
+        .merge(routes::labels::router())
+        .merge(routes::checklist::router())
+        .merge(routes::comments::router())
+        .merge(routes::dependencies::router())
+        .merge(routes::recurrence::router())
+        .merge(routes::reminders::router())
+        .merge(routes::statuses::router())
+        .merge(routes::time_tracking::router())
+        .merge(routes::history::router())
+        .merge(routes::trash::router())
+        .merge(routes::search::router())
+        .merge(routes::views::router())
+        .merge(routes::quick_add::router())
+        .merge(routes::templates::router())
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
 }
@@ -0,0 +1,35 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Тело multipart-запроса на загрузку (только для документации OpenAPI).
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct UploadAttachmentForm {
+    /// Файл (поле `file`).
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+/// Ответ с метаданными вложения.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    #[schema(example = "receipt.pdf")]
+    pub filename: String,
+    #[schema(example = "application/pdf")]
+    pub content_type: String,
+    #[schema(example = 48213)]
+    pub size_bytes: i64,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Использование хранилища пользователем.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StorageUsageResponse {
+    #[schema(example = 1048576)]
+    pub used_bytes: i64,
+    #[schema(example = 104857600)]
+    pub quota_bytes: i64,
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::dto::tasks::{CreateTaskRequest, TaskResponse};
+
+/// Режим пакетной обработки.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkMode {
+    /// Всё или ничего: первая ошибка откатывает весь пакет.
+    #[default]
+    Atomic,
+    /// Ошибочные операции пропускаются, остальные применяются.
+    BestEffort,
+}
+
+/// Одна операция пакета. Все задачи — из списка в пути запроса.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum BulkOperation {
+    /// Создать задачу.
+    Create(CreateTaskRequest),
+    /// Сменить статус (правила блокировки и повторения — как в PUT .../status).
+    UpdateStatus {
+        task_id: Uuid,
+        #[schema(example = "done")]
+        status: String,
+        #[serde(default)]
+        force: bool,
+    },
+    /// Перенести задачу в другой список пользователя.
+    Move { task_id: Uuid, target_list_id: Uuid },
+    /// Навесить метку.
+    Label { task_id: Uuid, label_id: Uuid },
+    /// Удалить задачу.
+    Delete { task_id: Uuid },
+}
+
+/// Запрос POST /lists/{list_id}/tasks/bulk.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BulkRequest {
+    #[serde(default)]
+    pub mode: BulkMode,
+    /// Операции выполняются по порядку (не больше 100).
+    pub operations: Vec<BulkOperation>,
+}
+
+/// Результат одной операции пакета.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkItemResult {
+    /// Номер операции в запросе (с нуля).
+    pub index: usize,
+    /// HTTP-статус, который вернул бы одиночный запрос.
+    /// 424 — операция прошла, но пакет откатан или до неё не дошло.
+    #[schema(example = 200)]
+    pub status: u16,
+    /// Задача после операции (для create/update_status/move/label).
+    pub task: Option<TaskResponse>,
+    pub error: Option<String>,
+}
+
+/// Ответ на пакетный запрос.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkResponse {
+    pub mode: BulkMode,
+    /// Были ли изменения зафиксированы в БД.
+    pub committed: bool,
+    pub results: Vec<BulkItemResult>,
+}
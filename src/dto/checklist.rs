@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на создание пункта чек-листа.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateChecklistItemRequest {
+    #[schema(example = "Сравнить цены")]
+    pub title: String,
+}
+
+/// Запрос на обновление пункта чек-листа (title + done).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateChecklistItemRequest {
+    #[schema(example = "Сравнить цены в трёх магазинах")]
+    pub title: String,
+    #[schema(example = true)]
+    pub done: bool,
+}
+
+/// Ответ с пунктом чек-листа.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChecklistItemResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub done: bool,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Прогресс чек-листа задачи — встраивается в `TaskResponse`.
+#[derive(Debug, Clone, Default, Serialize, ToSchema)]
+pub struct ChecklistProgressResponse {
+    /// Сколько пунктов отмечено.
+    #[schema(example = 2)]
+    pub done: i64,
+    /// Сколько пунктов всего.
+    #[schema(example = 5)]
+    pub total: i64,
+}
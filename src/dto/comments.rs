@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на создание или редактирование комментария.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CommentRequest {
+    /// Текст комментария в Markdown.
+    #[schema(example = "Согласовал с **бухгалтерией**, можно отправлять.")]
+    pub body: String,
+}
+
+/// Ответ с комментарием.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub author_id: Uuid,
+    #[schema(example = "user@example.com")]
+    pub author_email: String,
+    /// Текст в Markdown (рендеринг — на стороне клиента).
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    /// Время последнего редактирования (`null` — не редактировался).
+    pub edited_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Query-параметры журнала (страницы от новых событий к старым).
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HistoryQuery {
+    /// Размер страницы: от 1 до 100 (по умолчанию 50).
+    pub limit: Option<i64>,
+    /// Только события старше этого ID (`next_before` предыдущей страницы).
+    pub before: Option<i64>,
+}
+
+/// Событие журнала — то, что видит клиент.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HistoryEventResponse {
+    pub id: i64,
+    pub list_id: Uuid,
+    /// `null` — событие самого списка.
+    pub task_id: Option<Uuid>,
+    /// Кто внёс изменение (`null` — пользователь удалён).
+    pub actor_id: Option<Uuid>,
+    #[schema(example = "owner@example.com")]
+    pub actor_email: Option<String>,
+    /// `created`, `updated`, `deleted` или `restored`.
+    #[schema(example = "updated")]
+    pub action: String,
+    /// Изменившиеся поля: `{"поле": {"old": ..., "new": ...}}`.
+    #[schema(value_type = Object, example = json!({ "status": { "old": "todo", "new": "done" } }))]
+    pub changes: serde_json::Value,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Страница журнала.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HistoryPageResponse {
+    /// События от новых к старым.
+    pub events: Vec<HistoryEventResponse>,
+    /// Значение `before` для следующей страницы (`null` — это последняя).
+    pub next_before: Option<i64>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на создание метки.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateLabelRequest {
+    #[schema(example = "work")]
+    pub name: String,
+    /// Цвет в формате `#RRGGBB`. Если не указан — серый.
+    #[schema(example = "#ff9800")]
+    pub color: Option<String>,
+}
+
+/// Запрос на обновление метки (name + color).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateLabelRequest {
+    #[schema(example = "blocked-on-vendor")]
+    pub name: String,
+    #[schema(example = "#f44336")]
+    pub color: String,
+}
+
+/// Ответ с меткой — то, что видит клиент.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LabelResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
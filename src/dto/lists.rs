@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use crate::dto::patch::Patch;
+
 /// Запрос на создание списка.
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateListRequest {
@@ -16,11 +18,48 @@ pub struct UpdateListRequest {
     pub title: String,
 }
 
+/// Частичное обновление списка (PATCH). Отсутствующие поля не меняются.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PatchListRequest {
+    #[serde(default)]
+    #[schema(value_type = Option<String>, example = "Покупки на неделю")]
+    pub title: Patch<String>,
+    /// Архивировать выполненные задачи через столько дней (`null` — выключить).
+    #[serde(default)]
+    #[schema(value_type = Option<i32>, example = 14)]
+    pub auto_archive_days: Patch<i32>,
+}
+
+/// Query-параметры для GET /lists.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListQuery {
+    /// Включить архивные списки (по умолчанию они скрыты).
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Размер страницы: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
+}
+
 /// Ответ со списком — то, что видит клиент.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ListResponse {
     pub id: Uuid,
     pub title: String,
+    /// Ранг ручной сортировки: списки выдаются по возрастанию.
+    #[schema(example = "i")]
+    pub position: String,
+    /// Версия; она же отдаётся в заголовке `ETag`.
+    #[schema(example = 1)]
+    pub version: i32,
+    /// Когда список отправлен в архив (`null` — активен). Архивный список доступен только на чтение.
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Через сколько дней после `done` задачи архивируются автоматически.
+    #[schema(example = 14)]
+    pub auto_archive_days: Option<i32>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
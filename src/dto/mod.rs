@@ -1,6 +1,24 @@
+pub mod attachments;
 pub mod auth;
+pub mod bulk;
+pub mod checklist;
+pub mod comments;
+pub mod history;
+pub mod labels;
 pub mod lists;
+pub mod pagination;
+pub mod patch;
+pub mod positions;
+pub mod quick_add;
+pub mod recurrence;
+pub mod reminders;
+pub mod search;
+pub mod statuses;
 pub mod tasks;
+pub mod templates;
+pub mod time_tracking;
+pub mod trash;
+pub mod views;
 
 use serde::Serialize;
 use utoipa::ToSchema;
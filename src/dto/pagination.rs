@@ -0,0 +1,29 @@
+use axum::http::header::LINK;
+use axum::http::Uri;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+use crate::services::pagination::Page;
+
+/// Ответ со страницей коллекции: тело — JSON-массив элементов, ссылка
+/// на следующую страницу — в заголовке `Link` (RFC 8288, `rel="next"`).
+///
+/// Ссылка повторяет исходный запрос `uri` (путь и фильтры) с новым `cursor`.
+pub fn paged<T: Serialize>(uri: &Uri, page: Page<T>) -> Response {
+    let Some(cursor) = page.next_cursor else {
+        return Json(page.items).into_response();
+    };
+
+    let mut query: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !pair.starts_with("cursor="))
+        .collect();
+    let cursor = format!("cursor={}", cursor);
+    query.push(&cursor);
+    let link = format!("<{}?{}>; rel=\"next\"", uri.path(), query.join("&"));
+
+    ([(LINK, link)], Json(page.items)).into_response()
+}
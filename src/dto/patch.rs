@@ -0,0 +1,103 @@
+use axum::body::Bytes;
+use axum::extract::{FromRequest, Request};
+use axum::http::header::CONTENT_TYPE;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+use crate::errors::AppError;
+
+/// Content-Type запроса в формате JSON Patch (RFC 6902).
+pub const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
+/// Поле разреженного PATCH-запроса.
+///
+/// Отличает отсутствующее поле (не менять) от явного `null` (очистить).
+/// В структурах запросов используется вместе с `#[serde(default)]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Patch<T> {
+    /// Поле не передано — значение не меняется.
+    #[default]
+    Absent,
+    /// Передан `null`.
+    Null,
+    /// Передано новое значение.
+    Value(T),
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Сюда попадаем, только если поле есть в JSON: отсутствие даёт `default`.
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+impl<T> Patch<T> {
+    /// Значение для поля, которое нельзя очистить: `null` → 422.
+    pub fn required(self, field: &str) -> Result<Option<T>, AppError> {
+        match self {
+            Patch::Absent => Ok(None),
+            Patch::Null => Err(AppError::Validation(format!("{} cannot be null", field))),
+            Patch::Value(value) => Ok(Some(value)),
+        }
+    }
+
+    /// Значение для поля, которое можно очистить:
+    /// `None` — не менять, `Some(None)` — очистить.
+    pub fn nullable(self) -> Option<Option<T>> {
+        match self {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Value(value) => Some(Some(value)),
+        }
+    }
+}
+
+/// Тело PATCH-запроса: разреженный JSON (`application/json`)
+/// или список операций JSON Patch (`application/json-patch+json`).
+///
+/// Операции JSON Patch применяются сервисом к текущему состоянию ресурса
+/// (см. `services::patch::resolve`), после чего обрабатываются так же,
+/// как разреженный JSON.
+#[derive(Debug)]
+pub enum PatchPayload<T> {
+    Sparse(T),
+    JsonPatch(json_patch::Patch),
+}
+
+impl<T, S> FromRequest<S> for PatchPayload<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let is_json_patch = req
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with(JSON_PATCH_CONTENT_TYPE));
+
+        if !is_json_patch {
+            let Json(body) = Json::<T>::from_request(req, state)
+                .await
+                .map_err(IntoResponse::into_response)?;
+            return Ok(PatchPayload::Sparse(body));
+        }
+
+        let bytes = Bytes::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        let patch = serde_json::from_slice(&bytes).map_err(|err| {
+            AppError::Validation(format!("Invalid JSON Patch document: {}", err)).into_response()
+        })?;
+
+        Ok(PatchPayload::JsonPatch(patch))
+    }
+}
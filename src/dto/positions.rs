@@ -0,0 +1,15 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на перемещение элемента (drag-and-drop).
+///
+/// Элемент встаёт сразу после `after_id` и/или сразу перед `before_id`;
+/// нужен хотя бы один из соседей.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PositionRequest {
+    /// Сосед сверху: элемент окажется после него.
+    pub after_id: Option<Uuid>,
+    /// Сосед снизу: элемент окажется перед ним.
+    pub before_id: Option<Uuid>,
+}
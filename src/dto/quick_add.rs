@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::dto::tasks::TaskResponse;
+
+/// Запрос на быстрое добавление задачи из свободного текста.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct QuickAddRequest {
+    /// Текст задачи: название, `@список`, `#статус`, дата и время
+    /// (по-английски или по-русски).
+    #[schema(example = "Buy milk tomorrow 9am @Покупки")]
+    pub text: String,
+    /// Список, если в тексте нет `@список`.
+    pub list_id: Option<Uuid>,
+    /// Смещение часового пояса пользователя от UTC в минутах
+    /// (от -840 до 840, по умолчанию 0) — от него считаются «завтра» и «9am».
+    #[schema(example = 180)]
+    pub utc_offset_minutes: Option<i32>,
+}
+
+/// Как был понят текст.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuickAddInterpretation {
+    #[schema(example = "Buy milk")]
+    pub title: String,
+    pub list_id: Uuid,
+    #[schema(example = "Покупки")]
+    pub list_title: String,
+    #[schema(example = "todo")]
+    pub status: String,
+    #[schema(example = "2026-03-08T06:00:00Z")]
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Распознанные фрагменты текста в порядке появления.
+    #[schema(example = json!(["tomorrow", "9am", "@Покупки"]))]
+    pub recognized: Vec<String>,
+}
+
+/// Ответ на быстрое добавление: разбор текста и созданная задача.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuickAddResponse {
+    pub parsed: QuickAddInterpretation,
+    pub task: TaskResponse,
+}
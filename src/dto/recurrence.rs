@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на настройку повторения задачи.
+///
+/// Режимы:
+/// - `schedule` — следующий срок считается по `rrule` от текущего срока;
+/// - `after_completion` — следующий срок = момент выполнения + `interval_days`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetRecurrenceRequest {
+    /// `schedule` (по умолчанию) или `after_completion`.
+    #[schema(example = "schedule")]
+    pub mode: Option<String>,
+    /// Правило RFC 5545 (обязательно для `schedule`), `INTERVAL` — не больше 1000.
+    #[schema(example = "FREQ=WEEKLY;BYDAY=SA")]
+    pub rrule: Option<String>,
+    /// Через сколько дней после выполнения создать повтор (для `after_completion`),
+    /// от 1 до 3650.
+    #[schema(example = 14)]
+    pub interval_days: Option<i32>,
+}
+
+/// Настройки повторения — встраиваются в `TaskResponse`.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RecurrenceResponse {
+    #[schema(example = "schedule")]
+    pub mode: String,
+    #[schema(example = "FREQ=WEEKLY;BYDAY=SA")]
+    pub rrule: Option<String>,
+    pub interval_days: Option<i32>,
+    /// Серия, к которой относятся все повторения этой задачи.
+    pub series_id: Uuid,
+}
+
+/// Одна запись истории выполнений серии.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompletionResponse {
+    /// Выполненное повторение (`null`, если его уже удалили).
+    pub task_id: Option<Uuid>,
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub completed_at: chrono::DateTime<chrono::Utc>,
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Запрос на создание напоминания.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateReminderRequest {
+    /// Когда напомнить (абсолютное время).
+    #[schema(example = "2026-03-07T08:30:00Z")]
+    pub remind_at: chrono::DateTime<chrono::Utc>,
+    /// Канал доставки: `email` или `webhook`.
+    #[schema(example = "email")]
+    pub channel: String,
+    /// Email-адрес или URL вебхука.
+    #[schema(example = "user@example.com")]
+    pub target: String,
+}
+
+/// Запрос на откладывание напоминания: либо на `minutes` минут от текущего
+/// момента (не больше года), либо до конкретного времени `until`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SnoozeReminderRequest {
+    #[schema(example = 15)]
+    pub minutes: Option<i64>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Ответ с напоминанием.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReminderResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub remind_at: chrono::DateTime<chrono::Utc>,
+    #[schema(example = "email")]
+    pub channel: String,
+    pub target: String,
+    /// `pending`, `sending`, `sent` или `failed`.
+    #[schema(example = "pending")]
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub sent_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
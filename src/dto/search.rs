@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Query-параметры поиска.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchQuery {
+    /// Что искать: слова ищутся по началу (`стомат` найдёт «стоматолог»),
+    /// названия с опечатками тоже находятся.
+    #[param(example = "стоматолог")]
+    pub q: String,
+    /// Размер страницы: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
+}
+
+/// Найденный список или задача.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchResultResponse {
+    /// `list` или `task`.
+    #[schema(example = "task")]
+    pub kind: String,
+    pub id: Uuid,
+    /// Список, в котором лежит задача (для списка — он сам).
+    pub list_id: Uuid,
+    #[schema(example = "Здоровье")]
+    pub list_title: String,
+    #[schema(example = "Записаться к стоматологу")]
+    pub title: String,
+    /// Название в HTML: текст экранирован, совпадения обёрнуты в `<mark>`.
+    #[schema(example = "Записаться к <mark>стоматологу</mark>")]
+    pub highlight: String,
+    /// Статус задачи (у списка — `null`).
+    #[schema(example = "todo")]
+    pub status: Option<String>,
+    /// Список или задача в архиве.
+    pub archived: bool,
+    /// Релевантность: результаты отсортированы по ней по убыванию.
+    pub rank: f64,
+}
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::dto::tasks::TaskResponse;
+
+/// Запрос на создание или обновление статуса списка (колонки доски).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct StatusRequest {
+    #[schema(example = "Review")]
+    pub name: String,
+    /// Категория: `not_started`, `active` или `done`. Задаёт статус
+    /// задач в колонке: `todo`, `in_progress` или `done` соответственно.
+    #[schema(example = "active")]
+    pub category: String,
+    /// Максимум задач в колонке (`null` — без ограничения).
+    #[schema(example = 3)]
+    pub wip_limit: Option<i32>,
+}
+
+/// Ответ со статусом списка — то, что видит клиент.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StatusResponse {
+    pub id: Uuid,
+    pub list_id: Uuid,
+    pub name: String,
+    #[schema(example = "active")]
+    pub category: String,
+    /// Ранг колонки на доске.
+    #[schema(example = "i")]
+    pub position: String,
+    pub wip_limit: Option<i32>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Колонка доски, встроенная в TaskResponse.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TaskColumnResponse {
+    pub id: Uuid,
+    #[schema(example = "Review")]
+    pub name: String,
+}
+
+/// Запрос на перемещение задачи в другую колонку доски.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct MoveToColumnRequest {
+    /// UUID статуса (колонки) этого же списка.
+    pub status_id: Uuid,
+    /// Разрешить перевод в работу, даже если задача заблокирована.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Колонка доски с задачами.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardColumnResponse {
+    pub status: StatusResponse,
+    /// Сколько задач в колонке.
+    pub task_count: usize,
+    /// `true`, если задач больше WIP-лимита (статус меняли не через доску
+    /// или лимит уменьшили).
+    pub over_limit: bool,
+    /// Задачи колонки в ручном порядке списка.
+    pub tasks: Vec<TaskResponse>,
+}
+
+/// Канбан-доска списка: колонки в порядке статусов.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BoardResponse {
+    pub list_id: Uuid,
+    pub columns: Vec<BoardColumnResponse>,
+}
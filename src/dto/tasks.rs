@@ -1,12 +1,24 @@
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
+use crate::dto::checklist::ChecklistProgressResponse;
+use crate::dto::labels::LabelResponse;
+use crate::dto::patch::Patch;
+use crate::dto::recurrence::RecurrenceResponse;
+use crate::dto::statuses::TaskColumnResponse;
+
 /// Запрос на создание задачи.
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateTaskRequest {
     #[schema(example = "Купить молоко")]
     pub title: String,
+    /// Автоматически завершать задачу, когда отмечены все пункты чек-листа.
+    #[serde(default)]
+    pub auto_complete: bool,
+    /// Срок выполнения (опционально).
+    #[schema(example = "2026-03-07T09:00:00Z")]
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 /// Запрос на обновление задачи (title + status).
@@ -15,8 +27,109 @@ pub struct UpdateTaskRequest {
     #[schema(example = "Купить молоко и хлеб")]
     pub title: String,
     /// Новый статус: `todo`, `in_progress` или `done`.
+    /// Из `done` статус не меняется — задачу нужно переоткрыть (POST .../reopen).
     #[schema(example = "in_progress")]
     pub status: String,
+    /// Если не указано — значение не меняется.
+    pub auto_complete: Option<bool>,
+    /// Новый срок. Если не указан — срок не меняется.
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Разрешить перевод в `in_progress`/`done`, даже если задача заблокирована.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Частичное обновление задачи (PATCH). Отсутствующие поля не меняются,
+/// `null` допустим только для `due_at` и `estimate_minutes`.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PatchTaskRequest {
+    #[serde(default)]
+    #[schema(value_type = Option<String>, example = "Купить молоко и хлеб")]
+    pub title: Patch<String>,
+    /// Новый статус: `todo`, `in_progress` или `done`.
+    #[serde(default)]
+    #[schema(value_type = Option<String>, example = "done")]
+    pub status: Patch<String>,
+    #[serde(default)]
+    #[schema(value_type = Option<bool>)]
+    pub auto_complete: Patch<bool>,
+    /// Новый срок; `null` — снять срок.
+    #[serde(default)]
+    #[schema(value_type = Option<chrono::DateTime<chrono::Utc>>)]
+    pub due_at: Patch<chrono::DateTime<chrono::Utc>>,
+    /// Оценка трудоёмкости в минутах; `null` — снять оценку.
+    #[serde(default)]
+    #[schema(value_type = Option<i32>, example = 90)]
+    pub estimate_minutes: Patch<i32>,
+    /// Разрешить перевод в `in_progress`/`done`, даже если задача заблокирована.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Запрос на смену только статуса задачи. Доступен и владельцу списка,
+/// и исполнителю задачи.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateTaskStatusRequest {
+    /// Новый статус: `todo`, `in_progress` или `done`.
+    #[schema(example = "done")]
+    pub status: String,
+    /// Разрешить перевод в `in_progress`/`done`, даже если задача заблокирована.
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// Запрос на назначение исполнителя (пользователь ищется по email).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AssignTaskRequest {
+    #[schema(example = "colleague@example.com")]
+    pub email: String,
+}
+
+/// Исполнитель задачи, встроенный в TaskResponse.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssigneeResponse {
+    pub id: Uuid,
+    #[schema(example = "colleague@example.com")]
+    pub email: String,
+}
+
+/// Запрос на перенос или копирование задачи в другой список.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TaskTargetRequest {
+    /// UUID списка назначения (должен принадлежать пользователю).
+    pub target_list_id: Uuid,
+}
+
+/// Запрос на добавление зависимости: задача не может начаться,
+/// пока не выполнена `depends_on_id`.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddDependencyRequest {
+    /// UUID блокирующей задачи (может быть из другого списка пользователя).
+    pub depends_on_id: Uuid,
+}
+
+/// Query-параметры для GET /lists/{list_id}/tasks.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaskListQuery {
+    /// Вернуть только задачи с этой меткой.
+    pub label_id: Option<Uuid>,
+    /// Включить архивные задачи (по умолчанию они скрыты).
+    #[serde(default)]
+    pub include_archived: bool,
+    /// Условия `поле:оператор:значение` через запятую (все должны выполняться).
+    /// Операторы: `eq`, `ne`, `lt`, `lte`, `gt`, `gte`, `in` (значения через `|`),
+    /// `contains`, `null` (`true`/`false`).
+    #[param(example = "status:in:todo|in_progress,due_at:lt:2026-04-01,title:contains:отчёт")]
+    pub filter: Option<String>,
+    /// Поля сортировки через запятую, `-` — по убыванию. По умолчанию — ручной порядок.
+    #[param(example = "-due_at,title")]
+    pub sort: Option<String>,
+    /// Размер страницы: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
 }
 
 /// Ответ с задачей — то, что видит клиент.
@@ -26,6 +139,39 @@ pub struct TaskResponse {
     pub list_id: Uuid,
     pub title: String,
     pub status: String,
+    /// Колонка доски (пользовательский статус списка).
+    pub column: Option<TaskColumnResponse>,
+    /// Ранг ручной сортировки внутри списка.
+    #[schema(example = "i")]
+    pub position: String,
+    /// Версия; она же отдаётся в заголовке `ETag`. Растёт и при изменении
+    /// вложенных данных: меток, чек-листа, зависимостей, записей времени, колонок доски.
+    #[schema(example = 1)]
+    pub version: i32,
+    /// Метки, навешенные на задачу.
+    pub labels: Vec<LabelResponse>,
+    /// Прогресс чек-листа (выполнено / всего).
+    pub checklist: ChecklistProgressResponse,
+    pub auto_complete: bool,
+    /// Задачи, от которых зависит эта задача.
+    pub depends_on: Vec<Uuid>,
+    /// `true`, если хотя бы одна из `depends_on` ещё не выполнена.
+    pub blocked: bool,
+    pub due_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Оценка трудоёмкости в минутах.
+    pub estimate_minutes: Option<i32>,
+    /// Сколько времени учтено по задаче (идущие таймеры — на момент ответа).
+    pub tracked_seconds: i64,
+    /// Когда задачу впервые взяли в работу.
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Когда задача выполнена (`null`, пока не в `done`).
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Когда задача отправлена в архив (`null` — не в архиве).
+    pub archived_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Настройки повторения (`null` — задача не повторяется).
+    pub recurrence: Option<RecurrenceResponse>,
+    /// Исполнитель (`null` — не назначен).
+    pub assignee: Option<AssigneeResponse>,
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Запрос на сохранение списка как шаблона.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct SaveTemplateRequest {
+    /// Название шаблона. Если не указано — название списка.
+    #[schema(example = "Чек-лист релиза")]
+    pub title: Option<String>,
+}
+
+/// Query-параметры для GET /templates.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TemplateQuery {
+    /// Сколько сохранённых шаблонов на странице: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
+}
+
+/// Запрос на создание списка из шаблона.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct InstantiateTemplateRequest {
+    /// Название нового списка. Если не указано — название шаблона.
+    #[schema(example = "Релиз 2.4")]
+    pub title: Option<String>,
+    /// От какого момента отсчитываются сроки задач. Если не указан — сейчас.
+    #[schema(example = "2026-03-09T09:00:00Z")]
+    pub starts_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Задача шаблона.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateTaskResponse {
+    #[schema(example = "Обновить CHANGELOG")]
+    pub title: String,
+    /// Срок — через столько минут после начала списка; `null` — без срока.
+    #[schema(example = 1440)]
+    pub due_offset_minutes: Option<i32>,
+}
+
+/// Шаблон списка — то, что видит клиент.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TemplateResponse {
+    /// UUID сохранённого шаблона или имя встроенного
+    /// (`release_checklist`, `weekly_review`, `trip`).
+    #[schema(example = "release_checklist")]
+    pub id: String,
+    #[schema(example = "Чек-лист релиза")]
+    pub title: String,
+    /// Встроенный шаблон: его нельзя удалить.
+    pub builtin: bool,
+    /// Задачи в порядке списка.
+    pub tasks: Vec<TemplateTaskResponse>,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
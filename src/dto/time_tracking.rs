@@ -0,0 +1,96 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Запрос на ручное добавление записи времени.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateTimeEntryRequest {
+    #[schema(example = "2026-03-13T09:00:00Z")]
+    pub started_at: DateTime<Utc>,
+    /// Конец отрезка: позже `started_at` и не в будущем.
+    #[schema(example = "2026-03-13T10:30:00Z")]
+    pub ended_at: DateTime<Utc>,
+    #[schema(example = "Созвон с клиентом")]
+    pub note: Option<String>,
+}
+
+/// Ответ с записью времени — то, что видит клиент.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeEntryResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// `null` — таймер ещё идёт.
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Длительность; для идущего таймера — на момент ответа.
+    #[schema(example = 5400)]
+    pub duration_seconds: i64,
+    pub note: Option<String>,
+}
+
+/// Время по одной задаче в сводке списка.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TaskTimeResponse {
+    pub task_id: Uuid,
+    pub title: String,
+    pub estimate_minutes: Option<i32>,
+    pub tracked_seconds: i64,
+}
+
+/// Сводка по времени списка: оценки и учтённое время по задачам и в сумме.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListTimeResponse {
+    pub list_id: Uuid,
+    /// Сумма оценок задач (неоценённые не учитываются).
+    pub estimate_minutes: i64,
+    pub tracked_seconds: i64,
+    pub tasks: Vec<TaskTimeResponse>,
+}
+
+/// Формат отчёта.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+/// Query-параметры для GET /reports/time.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimeReportQuery {
+    /// Первый день периода (UTC, включительно).
+    #[param(example = "2026-03-01")]
+    pub from: NaiveDate,
+    /// Последний день периода (UTC, включительно).
+    #[param(example = "2026-03-31")]
+    pub to: NaiveDate,
+    /// Только этот список.
+    pub list_id: Option<Uuid>,
+    /// `json` (по умолчанию) или `csv`.
+    #[serde(default)]
+    #[param(inline)]
+    pub format: ReportFormat,
+}
+
+/// Строка отчёта: время по списку за день.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeReportRowResponse {
+    pub list_id: Uuid,
+    pub list_title: String,
+    #[schema(example = "2026-03-13")]
+    pub date: NaiveDate,
+    pub seconds: i64,
+}
+
+/// Отчёт по учтённому времени в списках пользователя.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TimeReportResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub total_seconds: i64,
+    pub rows: Vec<TimeReportRowResponse>,
+}
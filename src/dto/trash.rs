@@ -0,0 +1,38 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Удалённый список в корзине.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedListResponse {
+    pub id: Uuid,
+    #[schema(example = "Покупки")]
+    pub title: String,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+    /// Сколько задач удалено вместе со списком (и вернётся при восстановлении).
+    #[schema(example = 3)]
+    pub task_count: i64,
+}
+
+/// Задача, удалённая отдельно от своего списка.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashedTaskResponse {
+    pub id: Uuid,
+    pub list_id: Uuid,
+    #[schema(example = "Покупки")]
+    pub list_title: String,
+    /// Список тоже в корзине — сначала нужно восстановить его.
+    pub list_deleted: bool,
+    #[schema(example = "Купить молоко")]
+    pub title: String,
+    #[schema(example = "todo")]
+    pub status: String,
+    pub deleted_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Содержимое корзины пользователя (сначала недавно удалённое).
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TrashResponse {
+    pub lists: Vec<TrashedListResponse>,
+    pub tasks: Vec<TrashedTaskResponse>,
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Запрос на создание представления.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateViewRequest {
+    #[schema(example = "Срочное на неделе")]
+    pub name: String,
+    /// Фильтр на языке запросов задач (как `filter` в GET /lists/{id}/tasks).
+    /// Если не указан — все задачи.
+    #[schema(example = "status:ne:done,due_at:lte:now+7d")]
+    pub filter: Option<String>,
+    /// Сортировка (как `sort` в GET /lists/{id}/tasks). Если не указана — ручной порядок.
+    #[schema(example = "due_at")]
+    pub sort: Option<String>,
+}
+
+/// Запрос на обновление представления (name + filter + sort).
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateViewRequest {
+    #[schema(example = "Просроченное")]
+    pub name: String,
+    #[schema(example = "status:ne:done,due_at:lt:now")]
+    pub filter: String,
+    #[schema(example = "due_at")]
+    pub sort: String,
+}
+
+/// Представление — то, что видит клиент.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ViewResponse {
+    /// UUID сохранённого представления или имя встроенного
+    /// (`open`, `in_progress`, `recently_completed`, `recently_created`).
+    #[schema(example = "open")]
+    pub id: String,
+    #[schema(example = "Все открытые")]
+    pub name: String,
+    #[schema(example = "status:in:todo|in_progress")]
+    pub filter: String,
+    #[schema(example = "due_at,created_at")]
+    pub sort: String,
+    /// Встроенное представление: его нельзя изменить или удалить.
+    pub builtin: bool,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Query-параметры для GET /views.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ViewQuery {
+    /// Сколько сохранённых представлений на странице: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
+}
+
+/// Query-параметры для GET /views/{id}/tasks.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ViewTasksQuery {
+    /// Размер страницы: от 1 до 500 (по умолчанию 100).
+    pub limit: Option<i64>,
+    /// Курсор следующей страницы из заголовка `Link` предыдущего ответа.
+    pub cursor: Option<String>,
+}
@@ -18,6 +18,19 @@ pub enum AppError {
     #[error("{0}")]
     Validation(String),
 
+    /// Доступ к ресурсу есть, но действие запрещено — например,
+    /// редактирование чужого комментария (403).
+    #[error("{0}")]
+    Forbidden(String),
+
+    /// Версия ресурса не совпала с If-Match — его изменил кто-то другой (412).
+    #[error("{0}")]
+    PreconditionFailed(String),
+
+    /// Слишком большой файл или превышена квота хранилища (413).
+    #[error("{0}")]
+    PayloadTooLarge(String),
+
     /// Неверные учётные данные — логин/пароль (401).
     #[error("Invalid credentials")]
     Unauthorized,
@@ -25,17 +38,31 @@ pub enum AppError {
     /// Внутренняя ошибка сервера (500).
     #[error("Internal server error")]
     Internal(#[from] sqlx::Error),
+
+    /// Ошибка хранилища файлов (500).
+    #[error("Internal server error")]
+    Storage(#[from] crate::storage::StorageError),
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> Response {
-        let status = match &self {
+impl AppError {
+    /// HTTP-статус, которым ошибка отдаётся клиенту.
+    pub fn status_code(&self) -> StatusCode {
+        match self {
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
             AppError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
             AppError::Unauthorized => StatusCode::UNAUTHORIZED,
-            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
-        };
+            AppError::Internal(_) | AppError::Storage(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
 
         // Для Internal ошибок НЕ показываем детали клиенту (безопасность).
         // Но логируем их на сервере для отладки.
@@ -44,6 +71,10 @@ impl IntoResponse for AppError {
                 tracing::error!("Internal error: {:?}", err);
                 "Internal server error".to_string()
             }
+            AppError::Storage(err) => {
+                tracing::error!("Storage error: {:?}", err);
+                "Internal server error".to_string()
+            }
             other => other.to_string(),
         };
 
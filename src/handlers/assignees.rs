@@ -0,0 +1,93 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::tasks::{AssignTaskRequest, TaskResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// PUT /lists/{list_id}/tasks/{task_id}/assignee — назначить исполнителя.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/assignee",
+    tag = "Assignees",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = AssignTaskRequest,
+    responses(
+        (status = 200, description = "Задача с исполнителем", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или пользователь не найдены", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn assign(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<AssignTaskRequest>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::assignees::assign_task(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok(Json(task))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/assignee — снять исполнителя.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/assignee",
+    tag = "Assignees",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 204, description = "Исполнитель снят"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена или без исполнителя", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn unassign(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::assignees::unassign_task(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /me/assigned-tasks — задачи, назначенные текущему пользователю.
+#[utoipa::path(
+    get,
+    path = "/me/assigned-tasks",
+    tag = "Assignees",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Задачи из любых списков", body = Vec<TaskResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn assigned_to_me(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<Vec<TaskResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let tasks = services::assignees::get_assigned_tasks(&state.db, user_id).await?;
+
+    Ok(Json(tasks))
+}
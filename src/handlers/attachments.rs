@@ -0,0 +1,226 @@
+use axum::body::Body;
+use axum::extract::multipart::MultipartError;
+use axum::extract::{Multipart, Path, State};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use bytes::BytesMut;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use uuid::Uuid;
+
+use crate::dto::attachments::{AttachmentResponse, StorageUsageResponse, UploadAttachmentForm};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::services::attachments::{UploadedFile, MAX_ATTACHMENT_SIZE};
+use crate::state::AppState;
+
+/// Ошибка разбора multipart → AppError (превышение лимита тела → 413).
+fn multipart_error(err: MultipartError) -> AppError {
+    match err.status() {
+        StatusCode::PAYLOAD_TOO_LARGE => AppError::PayloadTooLarge(err.body_text()),
+        _ => AppError::Validation(err.body_text()),
+    }
+}
+
+/// Читает поле `file` из multipart-запроса, не превышая MAX_ATTACHMENT_SIZE.
+async fn read_file(mut multipart: Multipart) -> Result<UploadedFile, AppError> {
+    while let Some(mut field) = multipart.next_field().await.map_err(multipart_error)? {
+        if field.name() != Some("file") {
+            continue;
+        }
+
+        let filename = field.file_name().unwrap_or("file").to_string();
+        let content_type = field
+            .content_type()
+            .unwrap_or("application/octet-stream")
+            .to_string();
+
+        let mut data = BytesMut::new();
+        while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+            if data.len() + chunk.len() > MAX_ATTACHMENT_SIZE {
+                return Err(AppError::PayloadTooLarge(format!(
+                    "File exceeds the limit of {MAX_ATTACHMENT_SIZE} bytes"
+                )));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        return Ok(UploadedFile { filename, content_type, data: data.freeze() });
+    }
+
+    Err(AppError::Validation("Multipart field 'file' is required".to_string()))
+}
+
+/// `Content-Disposition` с ASCII-именем для старых клиентов и `filename*` (RFC 5987)
+/// с исходным UTF-8 именем.
+fn content_disposition(filename: &str) -> HeaderValue {
+    let ascii: String = filename
+        .chars()
+        .map(|c| if c.is_ascii() && !c.is_ascii_control() { c } else { '_' })
+        .collect();
+    let encoded = utf8_percent_encode(filename, NON_ALPHANUMERIC);
+
+    HeaderValue::from_str(&format!("attachment; filename=\"{ascii}\"; filename*=UTF-8''{encoded}"))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/attachments — загрузить файл.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/attachments",
+    tag = "Attachments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body(content = UploadAttachmentForm, content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Файл загружен", body = AttachmentResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 413, description = "Файл слишком большой или превышена квота", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Недопустимый тип файла", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn upload(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    multipart: Multipart,
+) -> Result<(StatusCode, Json<AttachmentResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let file = read_file(multipart).await?;
+    let attachment = services::attachments::upload_attachment(
+        &state.db, state.blobs.as_ref(), list_id, user_id, task_id, file,
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(attachment)))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/attachments — вложения задачи.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/attachments",
+    tag = "Attachments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Массив вложений", body = Vec<AttachmentResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<AttachmentResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let attachments = services::attachments::get_all_attachments(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(attachments))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/attachments/{attachment_id} — скачать файл (потоком).
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/attachments/{attachment_id}",
+    tag = "Attachments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("attachment_id" = Uuid, Path, description = "UUID вложения")
+    ),
+    responses(
+        (status = 200, description = "Содержимое файла", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Вложение не найдено", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn download(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, attachment_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let (attachment, stream) = services::attachments::open_attachment(
+        &state.db, state.blobs.as_ref(), list_id, user_id, task_id, attachment_id,
+    ).await?;
+
+    let content_type = HeaderValue::from_str(&attachment.content_type)
+        .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream"));
+    let headers = [
+        (header::CONTENT_TYPE, content_type),
+        (header::CONTENT_LENGTH, HeaderValue::from(attachment.size_bytes)),
+        (header::CONTENT_DISPOSITION, content_disposition(&attachment.filename)),
+    ];
+
+    Ok((headers, Body::from_stream(stream)).into_response())
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/attachments/{attachment_id} — удалить вложение.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/attachments/{attachment_id}",
+    tag = "Attachments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("attachment_id" = Uuid, Path, description = "UUID вложения")
+    ),
+    responses(
+        (status = 204, description = "Вложение удалено"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Вложение не найдено", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, attachment_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::attachments::delete_attachment(
+        &state.db, state.blobs.as_ref(), list_id, user_id, task_id, attachment_id,
+    ).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /me/storage — сколько места занимают мои вложения.
+#[utoipa::path(
+    get,
+    path = "/me/storage",
+    tag = "Attachments",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Использование хранилища", body = StorageUsageResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn storage_usage(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<StorageUsageResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let usage = services::attachments::get_storage_usage(&state.db, user_id).await?;
+
+    Ok(Json(usage))
+}
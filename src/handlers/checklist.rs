@@ -0,0 +1,140 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::checklist::{
+    ChecklistItemResponse, CreateChecklistItemRequest, UpdateChecklistItemRequest,
+};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/tasks/{task_id}/items — добавить пункт чек-листа.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/items",
+    tag = "Checklist",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = CreateChecklistItemRequest,
+    responses(
+        (status = 201, description = "Пункт создан", body = ChecklistItemResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные данные", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CreateChecklistItemRequest>,
+) -> Result<(StatusCode, Json<ChecklistItemResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let item = services::checklist::create_item(
+        &state.db, list_id, user_id, task_id, &body.title,
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(item)))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/items — все пункты чек-листа.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/items",
+    tag = "Checklist",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Массив пунктов", body = Vec<ChecklistItemResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<ChecklistItemResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let items = services::checklist::get_all_items(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(items))
+}
+
+/// PUT /lists/{list_id}/tasks/{task_id}/items/{item_id} — обновить пункт.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/items/{item_id}",
+    tag = "Checklist",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("item_id" = Uuid, Path, description = "UUID пункта")
+    ),
+    request_body = UpdateChecklistItemRequest,
+    responses(
+        (status = 200, description = "Обновлённый пункт", body = ChecklistItemResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Пункт не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные данные", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, item_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<UpdateChecklistItemRequest>,
+) -> Result<Json<ChecklistItemResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let item = services::checklist::update_item(
+        &state.db, list_id, user_id, task_id, item_id, &body.title, body.done,
+    ).await?;
+
+    Ok(Json(item))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/items/{item_id} — удалить пункт.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/items/{item_id}",
+    tag = "Checklist",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("item_id" = Uuid, Path, description = "UUID пункта")
+    ),
+    responses(
+        (status = 204, description = "Пункт удалён"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Пункт не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, item_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::checklist::delete_item(&state.db, list_id, user_id, task_id, item_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
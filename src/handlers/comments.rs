@@ -0,0 +1,138 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::comments::{CommentRequest, CommentResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/tasks/{task_id}/comments — добавить комментарий.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/comments",
+    tag = "Comments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = CommentRequest,
+    responses(
+        (status = 201, description = "Комментарий добавлен", body = CommentResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустой или слишком длинный текст", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CommentRequest>,
+) -> Result<(StatusCode, Json<CommentResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let comment = services::comments::create_comment(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(comment)))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/comments — все комментарии задачи.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/comments",
+    tag = "Comments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Массив комментариев", body = Vec<CommentResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<CommentResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let comments = services::comments::get_all_comments(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(comments))
+}
+
+/// PUT /lists/{list_id}/tasks/{task_id}/comments/{comment_id} — отредактировать комментарий.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/comments/{comment_id}",
+    tag = "Comments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("comment_id" = Uuid, Path, description = "UUID комментария")
+    ),
+    request_body = CommentRequest,
+    responses(
+        (status = 200, description = "Отредактированный комментарий", body = CommentResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 403, description = "Комментарий чужой", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Комментарий не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустой или слишком длинный текст", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, comment_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<CommentRequest>,
+) -> Result<Json<CommentResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let comment = services::comments::update_comment(
+        &state.db, list_id, user_id, task_id, comment_id, &body,
+    ).await?;
+
+    Ok(Json(comment))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/comments/{comment_id} — удалить комментарий.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/comments/{comment_id}",
+    tag = "Comments",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("comment_id" = Uuid, Path, description = "UUID комментария")
+    ),
+    responses(
+        (status = 204, description = "Комментарий удалён"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 403, description = "Комментарий чужой", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Комментарий не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, comment_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::comments::delete_comment(&state.db, list_id, user_id, task_id, comment_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
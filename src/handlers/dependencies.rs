@@ -0,0 +1,77 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::tasks::{AddDependencyRequest, TaskResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/tasks/{task_id}/dependencies — добавить зависимость.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/dependencies",
+    tag = "Dependencies",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = AddDependencyRequest,
+    responses(
+        (status = 200, description = "Задача с обновлёнными зависимостями", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Зависимость создаёт цикл", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Задача не может зависеть от себя", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn add(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<AddDependencyRequest>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::dependencies::add_dependency(
+        &state.db, list_id, user_id, task_id, body.depends_on_id,
+    ).await?;
+
+    Ok(Json(task))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/dependencies/{depends_on_id} — удалить зависимость.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/dependencies/{depends_on_id}",
+    tag = "Dependencies",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("depends_on_id" = Uuid, Path, description = "UUID блокирующей задачи")
+    ),
+    responses(
+        (status = 204, description = "Зависимость удалена"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Зависимость не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn remove(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, depends_on_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::dependencies::remove_dependency(
+        &state.db, list_id, user_id, task_id, depends_on_id,
+    ).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
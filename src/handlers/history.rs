@@ -0,0 +1,72 @@
+use axum::extract::{Path, Query, State};
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::history::{HistoryPageResponse, HistoryQuery};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// GET /lists/{list_id}/history — журнал изменений списка и его задач.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/history",
+    tag = "History",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        HistoryQuery
+    ),
+    responses(
+        (status = 200, description = "Страница журнала (от новых событий к старым)", body = HistoryPageResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Некорректный limit", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn list_history(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryPageResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::history::get_list_history(&state.db, list_id, user_id, &query).await?;
+
+    Ok(Json(page))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/history — журнал изменений задачи.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/history",
+    tag = "History",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        HistoryQuery
+    ),
+    responses(
+        (status = 200, description = "Страница журнала (от новых событий к старым)", body = HistoryPageResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Некорректный limit", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn task_history(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Query(query): Query<HistoryQuery>,
+) -> Result<Json<HistoryPageResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::history::get_task_history(&state.db, list_id, user_id, task_id, &query).await?;
+
+    Ok(Json(page))
+}
@@ -0,0 +1,152 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::labels::{CreateLabelRequest, LabelResponse, UpdateLabelRequest};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /labels — создать метку.
+#[utoipa::path(
+    post,
+    path = "/labels",
+    tag = "Labels",
+    request_body = CreateLabelRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Метка создана", body = LabelResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Метка с таким именем уже есть", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные данные", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(body): Json<CreateLabelRequest>,
+) -> Result<(StatusCode, Json<LabelResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let label = services::labels::create_label(
+        &state.db, user_id, &body.name, body.color.as_deref(),
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(label)))
+}
+
+/// GET /labels — все метки текущего пользователя.
+#[utoipa::path(
+    get,
+    path = "/labels",
+    tag = "Labels",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Массив меток", body = Vec<LabelResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<Vec<LabelResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let labels = services::labels::get_all_labels(&state.db, user_id).await?;
+
+    Ok(Json(labels))
+}
+
+/// GET /labels/{id} — одна метка по ID.
+#[utoipa::path(
+    get,
+    path = "/labels/{id}",
+    tag = "Labels",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID метки")
+    ),
+    responses(
+        (status = 200, description = "Найденная метка", body = LabelResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Метка не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_one(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(label_id): Path<Uuid>,
+) -> Result<Json<LabelResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let label = services::labels::get_label(&state.db, label_id, user_id).await?;
+
+    Ok(Json(label))
+}
+
+/// PUT /labels/{id} — обновить метку.
+#[utoipa::path(
+    put,
+    path = "/labels/{id}",
+    tag = "Labels",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID метки")
+    ),
+    request_body = UpdateLabelRequest,
+    responses(
+        (status = 200, description = "Обновлённая метка", body = LabelResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Метка не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Метка с таким именем уже есть", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные данные", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(label_id): Path<Uuid>,
+    Json(body): Json<UpdateLabelRequest>,
+) -> Result<Json<LabelResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let label = services::labels::update_label(
+        &state.db, label_id, user_id, &body.name, &body.color,
+    ).await?;
+
+    Ok(Json(label))
+}
+
+/// DELETE /labels/{id} — удалить метку.
+#[utoipa::path(
+    delete,
+    path = "/labels/{id}",
+    tag = "Labels",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID метки")
+    ),
+    responses(
+        (status = 204, description = "Метка удалена"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Метка не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(label_id): Path<Uuid>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::labels::delete_label(&state.db, label_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
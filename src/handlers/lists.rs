@@ -1,11 +1,16 @@
-use axum::extract::{Path, State};
+use axum::extract::{OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
+use axum::response::Response;
 use axum::Json;
 use uuid::Uuid;
 
-use crate::dto::lists::{CreateListRequest, ListResponse, UpdateListRequest};
+use crate::dto::lists::{CreateListRequest, ListQuery, ListResponse, PatchListRequest, UpdateListRequest};
+use crate::dto::pagination::paged;
+use crate::dto::patch::PatchPayload;
+use crate::dto::positions::PositionRequest;
 use crate::errors::AppError;
 use crate::middleware::auth::AuthUser;
+use crate::middleware::preconditions::{tagged, Preconditions};
 use crate::services;
 use crate::state::AppState;
 
@@ -34,27 +39,31 @@ pub async fn create(
     Ok((StatusCode::CREATED, Json(list)))
 }
 
-/// GET /lists — получить все списки текущего пользователя.
+/// GET /lists — получить списки текущего пользователя (постранично).
 #[utoipa::path(
     get,
     path = "/lists",
     tag = "Lists",
     security(("bearer_auth" = [])),
+    params(ListQuery),
     responses(
-        (status = 200, description = "Массив списков", body = Vec<ListResponse>),
+        (status = 200, description = "Страница списков", body = Vec<ListResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
+        (status = 422, description = "Неверный limit или cursor", body = crate::dto::ErrorResponse),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn get_all(
     State(state): State<AppState>,
     user: AuthUser,
-) -> Result<Json<Vec<ListResponse>>, AppError> {
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ListQuery>,
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    let lists = services::lists::get_all_lists(&state.db, user_id).await?;
+    let page = services::lists::get_all_lists(&state.db, user_id, &query).await?;
 
-    Ok(Json(lists))
+    Ok(paged(&uri, page))
 }
 
 /// GET /lists/{id} — получить один список по ID.
@@ -64,10 +73,12 @@ pub async fn get_all(
     tag = "Lists",
     security(("bearer_auth" = [])),
     params(
-        ("id" = Uuid, Path, description = "UUID списка")
+        ("id" = Uuid, Path, description = "UUID списка"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag из кеша клиента: при совпадении — 304")
     ),
     responses(
-        (status = 200, description = "Найденный список", body = ListResponse),
+        (status = 200, description = "Найденный список", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 304, description = "Не изменилась с версии из If-None-Match"),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
         (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
     )
@@ -76,13 +87,14 @@ pub async fn get_one(
     State(state): State<AppState>,
     user: AuthUser,
     Path(list_id): Path<Uuid>,
-) -> Result<Json<ListResponse>, AppError> {
+    preconditions: Preconditions,
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
     let list = services::lists::get_list(&state.db, list_id, user_id).await?;
 
-    Ok(Json(list))
+    Ok(preconditions.conditional_get(list.version, Json(list)))
 }
 
 /// PUT /lists/{id} — обновить название списка.
@@ -92,54 +104,190 @@ pub async fn get_one(
     tag = "Lists",
     security(("bearer_auth" = [])),
     params(
-        ("id" = Uuid, Path, description = "UUID списка")
+        ("id" = Uuid, Path, description = "UUID списка"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
     ),
     request_body = UpdateListRequest,
     responses(
-        (status = 200, description = "Обновлённый список", body = ListResponse),
+        (status = 200, description = "Обновлённый список", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
-        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn update(
     State(state): State<AppState>,
     user: AuthUser,
     Path(list_id): Path<Uuid>,
+    preconditions: Preconditions,
     Json(body): Json<UpdateListRequest>,
-) -> Result<Json<ListResponse>, AppError> {
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    let list = services::lists::update_list(&state.db, list_id, user_id, &body.title).await?;
+    let list = services::lists::update_list(&state.db, list_id, user_id, &body.title, &preconditions).await?;
 
-    Ok(Json(list))
+    Ok(tagged(list.version, Json(list)))
 }
 
-/// DELETE /lists/{id} — удалить список.
+/// PATCH /lists/{id} — частично обновить список.
+///
+/// Принимает разреженный JSON (`application/json`) или JSON Patch
+/// (`application/json-patch+json`, RFC 6902).
+#[utoipa::path(
+    patch,
+    path = "/lists/{id}",
+    tag = "Lists",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID списка"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
+    ),
+    request_body(content(
+        (PatchListRequest = "application/json"),
+        (Vec<serde_json::Value> = "application/json-patch+json")
+    )),
+    responses(
+        (status = 200, description = "Обновлённый список", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Не прошла операция test", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные поля или JSON Patch", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn patch(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    preconditions: Preconditions,
+    payload: PatchPayload<PatchListRequest>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::lists::patch_list(&state.db, list_id, user_id, payload, &preconditions).await?;
+
+    Ok(tagged(list.version, Json(list)))
+}
+
+/// DELETE /lists/{id} — удалить список (в корзину, вместе с задачами).
 #[utoipa::path(
     delete,
     path = "/lists/{id}",
     tag = "Lists",
     security(("bearer_auth" = [])),
     params(
-        ("id" = Uuid, Path, description = "UUID списка")
+        ("id" = Uuid, Path, description = "UUID списка"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
     ),
     responses(
-        (status = 204, description = "Список удалён"),
+        (status = 204, description = "Список перенесён в корзину"),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
-        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn delete(
     State(state): State<AppState>,
     user: AuthUser,
     Path(list_id): Path<Uuid>,
+    preconditions: Preconditions,
 ) -> Result<StatusCode, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    services::lists::delete_list(&state.db, list_id, user_id).await?;
+    services::lists::delete_list(&state.db, list_id, user_id, &preconditions).await?;
 
     // 204 No Content — стандартный ответ при успешном удалении.
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// PATCH /lists/{id}/position — переставить список (drag-and-drop).
+#[utoipa::path(
+    patch,
+    path = "/lists/{id}/position",
+    tag = "Lists",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID списка")
+    ),
+    request_body = PositionRequest,
+    responses(
+        (status = 200, description = "Список на новом месте", body = ListResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список или соседний список не найдены", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Не указаны или перепутаны соседи", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn reposition(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Json(body): Json<PositionRequest>,
+) -> Result<Json<ListResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::lists::reposition_list(&state.db, list_id, user_id, &body).await?;
+
+    Ok(Json(list))
+}
+
+/// POST /lists/{id}/archive — отправить список в архив.
+#[utoipa::path(
+    post,
+    path = "/lists/{id}/archive",
+    tag = "Lists",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Список в архиве и доступен только для чтения", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Список уже в архиве", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn archive(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::lists::archive_list(&state.db, list_id, user_id).await?;
+
+    Ok(tagged(list.version, Json(list)))
+}
+
+/// POST /lists/{id}/unarchive — вернуть список из архива.
+#[utoipa::path(
+    post,
+    path = "/lists/{id}/unarchive",
+    tag = "Lists",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Список снова доступен для изменений", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Список не в архиве", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn unarchive(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::lists::unarchive_list(&state.db, list_id, user_id).await?;
+
+    Ok(tagged(list.version, Json(list)))
+}
@@ -0,0 +1,42 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::quick_add::{QuickAddRequest, QuickAddResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /tasks/quick-add — создать задачу из свободного текста.
+///
+/// Из текста извлекаются список (`@Покупки`), статус (`#done`, `#в_работе`),
+/// дата и время (`tomorrow 9am`, `в пятницу вечером`, `через 2 часа`);
+/// остальное становится названием. В ответе — разбор и созданная задача.
+#[utoipa::path(
+    post,
+    path = "/tasks/quick-add",
+    tag = "Tasks",
+    request_body = QuickAddRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Задача создана", body = QuickAddResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Несколько списков с таким названием или список в архиве", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое название, не указан список или неверный часовой пояс", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn quick_add(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(body): Json<QuickAddRequest>,
+) -> Result<(StatusCode, Json<QuickAddResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let response = services::quick_add::quick_add(&state.db, user_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(response)))
+}
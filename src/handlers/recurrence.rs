@@ -0,0 +1,101 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::recurrence::{CompletionResponse, SetRecurrenceRequest};
+use crate::dto::tasks::TaskResponse;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// PUT /lists/{list_id}/tasks/{task_id}/recurrence — настроить повторение задачи.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/recurrence",
+    tag = "Recurrence",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = SetRecurrenceRequest,
+    responses(
+        (status = 200, description = "Задача с настроенным повторением", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидное правило повторения", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn set(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<SetRecurrenceRequest>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::recurrence::set_recurrence(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok(Json(task))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/recurrence — выключить повторение.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/recurrence",
+    tag = "Recurrence",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 204, description = "Повторение выключено"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена или не повторяется", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn clear(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::recurrence::clear_recurrence(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/recurrence/history — история выполнений серии.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/recurrence/history",
+    tag = "Recurrence",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "История выполнений (сначала последние)", body = Vec<CompletionResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn history(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<CompletionResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let history = services::recurrence::get_history(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(history))
+}
@@ -0,0 +1,136 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::reminders::{CreateReminderRequest, ReminderResponse, SnoozeReminderRequest};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/tasks/{task_id}/reminders — создать напоминание.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/reminders",
+    tag = "Reminders",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = CreateReminderRequest,
+    responses(
+        (status = 201, description = "Напоминание создано", body = ReminderResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидный канал или адрес", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CreateReminderRequest>,
+) -> Result<(StatusCode, Json<ReminderResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let reminder = services::reminders::create_reminder(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(reminder)))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/reminders — напоминания задачи.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/reminders",
+    tag = "Reminders",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Массив напоминаний", body = Vec<ReminderResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<ReminderResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let reminders = services::reminders::get_all_reminders(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(reminders))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/reminders/{reminder_id}/snooze — отложить напоминание.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/reminders/{reminder_id}/snooze",
+    tag = "Reminders",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("reminder_id" = Uuid, Path, description = "UUID напоминания")
+    ),
+    request_body = SnoozeReminderRequest,
+    responses(
+        (status = 200, description = "Отложенное напоминание", body = ReminderResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Напоминание не найдено", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидное время", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn snooze(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, reminder_id)): Path<(Uuid, Uuid, Uuid)>,
+    Json(body): Json<SnoozeReminderRequest>,
+) -> Result<Json<ReminderResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let reminder = services::reminders::snooze_reminder(
+        &state.db, list_id, user_id, task_id, reminder_id, &body,
+    ).await?;
+
+    Ok(Json(reminder))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/reminders/{reminder_id} — удалить напоминание.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/reminders/{reminder_id}",
+    tag = "Reminders",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("reminder_id" = Uuid, Path, description = "UUID напоминания")
+    ),
+    responses(
+        (status = 204, description = "Напоминание удалено"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Напоминание не найдено", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, reminder_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::reminders::delete_reminder(&state.db, list_id, user_id, task_id, reminder_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,37 @@
+use axum::extract::{OriginalUri, Query, State};
+use axum::response::Response;
+use uuid::Uuid;
+
+use crate::dto::pagination::paged;
+use crate::dto::search::{SearchQuery, SearchResultResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// GET /search?q= — полнотекстовый поиск по спискам и задачам пользователя.
+#[utoipa::path(
+    get,
+    path = "/search",
+    tag = "Search",
+    security(("bearer_auth" = [])),
+    params(SearchQuery),
+    responses(
+        (status = 200, description = "Страница результатов от самых релевантных", body = Vec<SearchResultResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустой запрос, неверный limit или cursor", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn search(
+    State(state): State<AppState>,
+    user: AuthUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<SearchQuery>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::search::search(&state.db, user_id, &query).await?;
+
+    Ok(paged(&uri, page))
+}
@@ -0,0 +1,232 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::positions::PositionRequest;
+use crate::dto::statuses::{BoardResponse, MoveToColumnRequest, StatusRequest, StatusResponse};
+use crate::dto::tasks::TaskResponse;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::middleware::preconditions::{tagged, Preconditions};
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/statuses — добавить статус (колонку доски).
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/statuses",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    request_body = StatusRequest,
+    responses(
+        (status = 201, description = "Статус создан в конце доски", body = StatusResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Статус с таким именем уже есть", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое имя, неизвестная категория или WIP-лимит меньше 1", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Json(body): Json<StatusRequest>,
+) -> Result<(StatusCode, Json<StatusResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let status = services::statuses::create_status(&state.db, list_id, user_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(status)))
+}
+
+/// GET /lists/{list_id}/statuses — статусы списка в порядке колонок.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/statuses",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Массив статусов", body = Vec<StatusResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Json<Vec<StatusResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let statuses = services::statuses::get_all_statuses(&state.db, list_id, user_id).await?;
+
+    Ok(Json(statuses))
+}
+
+/// PUT /lists/{list_id}/statuses/{status_id} — обновить статус.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/statuses/{status_id}",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("status_id" = Uuid, Path, description = "UUID статуса")
+    ),
+    request_body = StatusRequest,
+    responses(
+        (status = 200, description = "Обновлённый статус", body = StatusResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список или статус не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Имя занято или это последний статус категории", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое имя, неизвестная категория или WIP-лимит меньше 1", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, status_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<StatusRequest>,
+) -> Result<Json<StatusResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let status = services::statuses::update_status(&state.db, list_id, user_id, status_id, &body).await?;
+
+    Ok(Json(status))
+}
+
+/// DELETE /lists/{list_id}/statuses/{status_id} — удалить статус.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/statuses/{status_id}",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("status_id" = Uuid, Path, description = "UUID статуса")
+    ),
+    responses(
+        (status = 204, description = "Статус удалён, его задачи — в первой колонке той же категории"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список или статус не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Последний статус категории", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, status_id)): Path<(Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::statuses::delete_status(&state.db, list_id, user_id, status_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PATCH /lists/{list_id}/statuses/{status_id}/position — переставить колонку.
+#[utoipa::path(
+    patch,
+    path = "/lists/{list_id}/statuses/{status_id}/position",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("status_id" = Uuid, Path, description = "UUID статуса")
+    ),
+    request_body = PositionRequest,
+    responses(
+        (status = 200, description = "Статус на новом месте", body = StatusResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список, статус или соседний статус не найдены", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Не указаны или перепутаны соседи", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn reposition(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, status_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<PositionRequest>,
+) -> Result<Json<StatusResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let status = services::statuses::reposition_status(&state.db, list_id, user_id, status_id, &body).await?;
+
+    Ok(Json(status))
+}
+
+/// GET /lists/{list_id}/board — канбан-доска списка.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/board",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Колонки доски с задачами", body = BoardResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn board(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Json<BoardResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let board = services::statuses::get_board(&state.db, list_id, user_id).await?;
+
+    Ok(Json(board))
+}
+
+/// PUT /lists/{list_id}/tasks/{task_id}/column — переместить задачу в колонку доски.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/column",
+    tag = "Board",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
+    ),
+    request_body = MoveToColumnRequest,
+    responses(
+        (status = 200, description = "Задача в новой колонке", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или статус не найдены", body = crate::dto::ErrorResponse),
+        (status = 409, description = "WIP-лимит колонки исчерпан, задача заблокирована или переход статуса запрещён", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn move_to_column(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
+    Json(body): Json<MoveToColumnRequest>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::statuses::move_to_column(&state.db, list_id, user_id, task_id, &body, &preconditions).await?;
+
+    Ok(tagged(task.version, Json(task)))
+}
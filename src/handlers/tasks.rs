@@ -1,11 +1,20 @@
-use axum::extract::{Path, State};
+use axum::extract::{OriginalUri, Path, Query, State};
 use axum::http::StatusCode;
+use axum::response::Response;
 use axum::Json;
 use uuid::Uuid;
 
-use crate::dto::tasks::{CreateTaskRequest, TaskResponse, UpdateTaskRequest};
+use crate::dto::bulk::{BulkRequest, BulkResponse};
+use crate::dto::pagination::paged;
+use crate::dto::patch::PatchPayload;
+use crate::dto::positions::PositionRequest;
+use crate::dto::tasks::{
+    CreateTaskRequest, PatchTaskRequest, TaskListQuery, TaskResponse, TaskTargetRequest,
+    UpdateTaskRequest, UpdateTaskStatusRequest,
+};
 use crate::errors::AppError;
 use crate::middleware::auth::AuthUser;
+use crate::middleware::preconditions::{tagged, Preconditions};
 use crate::services;
 use crate::state::AppState;
 
@@ -34,37 +43,77 @@ pub async fn create(
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    let task = services::tasks::create_task(&state.db, list_id, user_id, &body.title).await?;
+    let task = services::tasks::create_task(&state.db, list_id, user_id, &body).await?;
 
     Ok((StatusCode::CREATED, Json(task)))
 }
 
-/// GET /lists/{list_id}/tasks — все задачи списка.
+/// GET /lists/{list_id}/tasks — задачи списка (постранично).
 #[utoipa::path(
     get,
     path = "/lists/{list_id}/tasks",
     tag = "Tasks",
     security(("bearer_auth" = [])),
     params(
-        ("list_id" = Uuid, Path, description = "UUID списка")
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        TaskListQuery
     ),
     responses(
-        (status = 200, description = "Массив задач", body = Vec<TaskResponse>),
+        (status = 200, description = "Страница задач", body = Vec<TaskResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
-        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Неверный limit или cursor", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn get_all(
     State(state): State<AppState>,
     user: AuthUser,
     Path(list_id): Path<Uuid>,
-) -> Result<Json<Vec<TaskResponse>>, AppError> {
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<TaskListQuery>,
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    let tasks = services::tasks::get_all_tasks(&state.db, list_id, user_id).await?;
+    let page = services::tasks::get_all_tasks(&state.db, list_id, user_id, &query).await?;
 
-    Ok(Json(tasks))
+    Ok(paged(&uri, page))
+}
+
+/// POST /lists/{list_id}/tasks/bulk — пакет операций над задачами списка.
+///
+/// Операции (create, update_status, move, label, delete) выполняются по порядку
+/// в одной транзакции. Для каждой возвращается статус, который вернул бы
+/// одиночный запрос.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/bulk",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    request_body = BulkRequest,
+    responses(
+        (status = 200, description = "Все операции выполнены", body = BulkResponse),
+        (status = 207, description = "best_effort: часть операций не выполнена", body = BulkResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустой или слишком большой пакет", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn bulk(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Json(body): Json<BulkRequest>,
+) -> Result<(StatusCode, Json<BulkResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let (status, response) = services::bulk::bulk_tasks(&state.db, list_id, user_id, &body).await?;
+
+    Ok((status, Json(response)))
 }
 
 /// GET /lists/{list_id}/tasks/{task_id} — одна задача.
@@ -75,10 +124,12 @@ pub async fn get_all(
     security(("bearer_auth" = [])),
     params(
         ("list_id" = Uuid, Path, description = "UUID списка"),
-        ("task_id" = Uuid, Path, description = "UUID задачи")
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag из кеша клиента: при совпадении — 304")
     ),
     responses(
-        (status = 200, description = "Найденная задача", body = TaskResponse),
+        (status = 200, description = "Найденная задача", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 304, description = "Не изменилась с версии из If-None-Match"),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
         (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
     )
@@ -87,13 +138,14 @@ pub async fn get_one(
     State(state): State<AppState>,
     user: AuthUser,
     Path((list_id, task_id)): Path<(Uuid, Uuid)>,
-) -> Result<Json<TaskResponse>, AppError> {
+    preconditions: Preconditions,
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
     let task = services::tasks::get_task(&state.db, list_id, user_id, task_id).await?;
 
-    Ok(Json(task))
+    Ok(preconditions.conditional_get(task.version, Json(task)))
 }
 
 /// PUT /lists/{list_id}/tasks/{task_id} — обновить задачу.
@@ -104,32 +156,145 @@ pub async fn get_one(
     security(("bearer_auth" = [])),
     params(
         ("list_id" = Uuid, Path, description = "UUID списка"),
-        ("task_id" = Uuid, Path, description = "UUID задачи")
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
     ),
     request_body = UpdateTaskRequest,
     responses(
-        (status = 200, description = "Обновлённая задача", body = TaskResponse),
+        (status = 200, description = "Обновлённая задача", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
-        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Задача заблокирована зависимостями или переход статуса запрещён", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn update(
     State(state): State<AppState>,
     user: AuthUser,
     Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
     Json(body): Json<UpdateTaskRequest>,
-) -> Result<Json<TaskResponse>, AppError> {
+) -> Result<Response, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    let task = services::tasks::update_task(
-        &state.db, list_id, user_id, task_id, &body.title, &body.status,
-    ).await?;
+    let task = services::tasks::update_task(&state.db, list_id, user_id, task_id, &body, &preconditions).await?;
 
-    Ok(Json(task))
+    Ok(tagged(task.version, Json(task)))
+}
+
+/// PATCH /lists/{list_id}/tasks/{task_id} — частично обновить задачу.
+///
+/// Принимает разреженный JSON (`application/json`: отсутствующие поля не меняются)
+/// или JSON Patch (`application/json-patch+json`, RFC 6902).
+#[utoipa::path(
+    patch,
+    path = "/lists/{list_id}/tasks/{task_id}",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
+    ),
+    request_body(content(
+        (PatchTaskRequest = "application/json"),
+        (Vec<serde_json::Value> = "application/json-patch+json")
+    )),
+    responses(
+        (status = 200, description = "Обновлённая задача", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Задача заблокирована или не прошла операция test", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидные поля или JSON Patch", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn patch(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
+    payload: PatchPayload<PatchTaskRequest>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::patch_task(&state.db, list_id, user_id, task_id, payload, &preconditions).await?;
+
+    Ok(tagged(task.version, Json(task)))
 }
 
-/// DELETE /lists/{list_id}/tasks/{task_id} — удалить задачу.
+/// PUT /lists/{list_id}/tasks/{task_id}/status — сменить только статус задачи.
+#[utoipa::path(
+    put,
+    path = "/lists/{list_id}/tasks/{task_id}/status",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
+    ),
+    request_body = UpdateTaskStatusRequest,
+    responses(
+        (status = 200, description = "Задача с новым статусом", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Задача заблокирована зависимостями или переход статуса запрещён", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Невалидный статус", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update_status(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
+    Json(body): Json<UpdateTaskStatusRequest>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::update_task_status(&state.db, list_id, user_id, task_id, &body, &preconditions).await?;
+
+    Ok(tagged(task.version, Json(task)))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/reopen — переоткрыть выполненную задачу.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/reopen",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
+    ),
+    responses(
+        (status = 200, description = "Задача снова в todo", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Задача не выполнена", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn reopen(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::reopen_task(&state.db, list_id, user_id, task_id, &preconditions).await?;
+
+    Ok(tagged(task.version, Json(task)))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id} — удалить задачу (в корзину).
 #[utoipa::path(
     delete,
     path = "/lists/{list_id}/tasks/{task_id}",
@@ -137,23 +302,180 @@ pub async fn update(
     security(("bearer_auth" = [])),
     params(
         ("list_id" = Uuid, Path, description = "UUID списка"),
-        ("task_id" = Uuid, Path, description = "UUID задачи")
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("If-Match" = Option<String>, Header, description = "ETag, с которым сверяется версия ресурса")
     ),
     responses(
-        (status = 204, description = "Задача удалена"),
+        (status = 204, description = "Задача перенесена в корзину"),
         (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
-        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 412, description = "Версия не совпала с If-Match", body = crate::dto::ErrorResponse)
     )
 )]
 pub async fn delete(
     State(state): State<AppState>,
     user: AuthUser,
     Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    preconditions: Preconditions,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::tasks::delete_task(&state.db, list_id, user_id, task_id, &preconditions).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// PATCH /lists/{list_id}/tasks/{task_id}/position — переставить задачу внутри списка.
+#[utoipa::path(
+    patch,
+    path = "/lists/{list_id}/tasks/{task_id}/position",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = PositionRequest,
+    responses(
+        (status = 200, description = "Задача на новом месте", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или соседняя задача не найдены", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Не указаны или перепутаны соседи", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn reposition(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<PositionRequest>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::reposition_task(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok(Json(task))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/move — перенести задачу в другой список.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/move",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID исходного списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = TaskTargetRequest,
+    responses(
+        (status = 200, description = "Задача в новом списке", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или список не найдены", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn move_task(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<TaskTargetRequest>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::move_task(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok(Json(task))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/copy — скопировать задачу в список.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/copy",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID исходного списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = TaskTargetRequest,
+    responses(
+        (status = 201, description = "Созданная копия", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или список не найдены", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn copy_task(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<TaskTargetRequest>,
+) -> Result<(StatusCode, Json<TaskResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::copy_task(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(task)))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/labels/{label_id} — навесить метку на задачу.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/labels/{label_id}",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("label_id" = Uuid, Path, description = "UUID метки")
+    ),
+    responses(
+        (status = 200, description = "Задача с обновлёнными метками", body = TaskResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача или метка не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn attach_label(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, label_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<Json<TaskResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::tasks::attach_label(&state.db, list_id, user_id, task_id, label_id).await?;
+
+    Ok(Json(task))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/labels/{label_id} — снять метку с задачи.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/labels/{label_id}",
+    tag = "Tasks",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("label_id" = Uuid, Path, description = "UUID метки")
+    ),
+    responses(
+        (status = 204, description = "Метка снята"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена или метка не навешена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn detach_label(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, label_id)): Path<(Uuid, Uuid, Uuid)>,
 ) -> Result<StatusCode, AppError> {
     let user_id: Uuid = user.user_id.parse()
         .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
 
-    services::tasks::delete_task(&state.db, list_id, user_id, task_id).await?;
+    services::tasks::detach_label(&state.db, list_id, user_id, task_id, label_id).await?;
 
     Ok(StatusCode::NO_CONTENT)
 }
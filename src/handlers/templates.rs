@@ -0,0 +1,164 @@
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::lists::ListResponse;
+use crate::dto::pagination::paged;
+use crate::dto::templates::{InstantiateTemplateRequest, SaveTemplateRequest, TemplateQuery, TemplateResponse};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{id}/template — сохранить список как шаблон.
+///
+/// В шаблон попадают название, задачи в ручном порядке и их сроки —
+/// смещениями от самого раннего срока в списке.
+#[utoipa::path(
+    post,
+    path = "/lists/{id}/template",
+    tag = "Templates",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = Uuid, Path, description = "UUID списка")
+    ),
+    request_body = SaveTemplateRequest,
+    responses(
+        (status = 201, description = "Шаблон создан", body = TemplateResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое название", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn save(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+    Json(body): Json<SaveTemplateRequest>,
+) -> Result<(StatusCode, Json<TemplateResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let template = services::lists::save_list_as_template(
+        &state.db, list_id, user_id, body.title.as_deref(),
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+/// GET /templates — встроенные и сохранённые шаблоны пользователя (постранично).
+#[utoipa::path(
+    get,
+    path = "/templates",
+    tag = "Templates",
+    security(("bearer_auth" = [])),
+    params(TemplateQuery),
+    responses(
+        (status = 200, description = "Встроенные (только на первой странице), затем сохранённые по названию", body = Vec<TemplateResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
+        (status = 422, description = "Неверный limit или cursor", body = crate::dto::ErrorResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<TemplateQuery>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::lists::get_all_templates(&state.db, user_id, &query).await?;
+
+    Ok(paged(&uri, page))
+}
+
+/// GET /templates/{id} — один шаблон с задачами.
+#[utoipa::path(
+    get,
+    path = "/templates/{id}",
+    tag = "Templates",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого или имя встроенного шаблона")
+    ),
+    responses(
+        (status = 200, description = "Найденный шаблон", body = TemplateResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Шаблон не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_one(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(template_id): Path<String>,
+) -> Result<Json<TemplateResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let template = services::lists::get_template(&state.db, &template_id, user_id).await?;
+
+    Ok(Json(template))
+}
+
+/// DELETE /templates/{id} — удалить сохранённый шаблон.
+#[utoipa::path(
+    delete,
+    path = "/templates/{id}",
+    tag = "Templates",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого шаблона")
+    ),
+    responses(
+        (status = 204, description = "Шаблон удалён"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Шаблон не найден", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Встроенный шаблон удалить нельзя", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(template_id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::lists::delete_template(&state.db, &template_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// POST /templates/{id}/instantiate — создать список из шаблона.
+#[utoipa::path(
+    post,
+    path = "/templates/{id}/instantiate",
+    tag = "Templates",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого или имя встроенного шаблона")
+    ),
+    request_body = InstantiateTemplateRequest,
+    responses(
+        (status = 201, description = "Список создан вместе с задачами шаблона", body = ListResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Шаблон не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое название", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn instantiate(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(template_id): Path<String>,
+    Json(body): Json<InstantiateTemplateRequest>,
+) -> Result<(StatusCode, Json<ListResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::lists::instantiate_template(&state.db, &template_id, user_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(list)))
+}
@@ -0,0 +1,259 @@
+use axum::extract::{Path, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::time_tracking::{
+    CreateTimeEntryRequest, ListTimeResponse, ReportFormat, TimeEntryResponse, TimeReportQuery,
+    TimeReportResponse,
+};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /lists/{list_id}/tasks/{task_id}/timer/start — запустить таймер на задаче.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/timer/start",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 201, description = "Таймер запущен", body = TimeEntryResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 409, description = "У пользователя уже идёт таймер", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn start_timer(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<(StatusCode, Json<TimeEntryResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let entry = services::time_tracking::start_timer(&state.db, list_id, user_id, task_id).await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/timer/stop — остановить таймер на задаче.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/timer/stop",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Завершённая запись", body = TimeEntryResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена или таймер на ней не идёт", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn stop_timer(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<TimeEntryResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let entry = services::time_tracking::stop_timer(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(entry))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/time-entries — добавить запись времени вручную.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/time-entries",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    request_body = CreateTimeEntryRequest,
+    responses(
+        (status = 201, description = "Запись добавлена", body = TimeEntryResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Конец раньше начала или в будущем", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create_entry(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<CreateTimeEntryRequest>,
+) -> Result<(StatusCode, Json<TimeEntryResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let entry = services::time_tracking::create_entry(&state.db, list_id, user_id, task_id, &body).await?;
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// GET /lists/{list_id}/tasks/{task_id}/time-entries — записи времени по задаче.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/tasks/{task_id}/time-entries",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Записи по времени начала", body = Vec<TimeEntryResponse>),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задача не найдена", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_entries(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<TimeEntryResponse>>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let entries = services::time_tracking::get_entries(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(Json(entries))
+}
+
+/// DELETE /lists/{list_id}/tasks/{task_id}/time-entries/{entry_id} — удалить свою запись.
+#[utoipa::path(
+    delete,
+    path = "/lists/{list_id}/tasks/{task_id}/time-entries/{entry_id}",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи"),
+        ("entry_id" = Uuid, Path, description = "UUID записи")
+    ),
+    responses(
+        (status = 204, description = "Запись удалена"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Запись не найдена или чужая", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete_entry(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id, entry_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::time_tracking::delete_entry(&state.db, list_id, user_id, task_id, entry_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /lists/{list_id}/time — оценки и учтённое время по задачам списка.
+#[utoipa::path(
+    get,
+    path = "/lists/{list_id}/time",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Сводка по времени списка", body = ListTimeResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn list_time(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Json<ListTimeResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let summary = services::time_tracking::get_list_time(&state.db, list_id, user_id).await?;
+
+    Ok(Json(summary))
+}
+
+/// GET /me/timer — мой идущий таймер.
+#[utoipa::path(
+    get,
+    path = "/me/timer",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Идущий таймер", body = TimeEntryResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Таймер не запущен", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn running_timer(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<TimeEntryResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let entry = services::time_tracking::get_running_timer(&state.db, user_id).await?;
+
+    Ok(Json(entry))
+}
+
+/// GET /reports/time — учтённое время по спискам и дням за период.
+///
+/// С `format=csv` отдаёт файл `text/csv` для выгрузки в таблицы.
+#[utoipa::path(
+    get,
+    path = "/reports/time",
+    tag = "Time tracking",
+    security(("bearer_auth" = [])),
+    params(TimeReportQuery),
+    responses(
+        (status = 200, description = "Отчёт (JSON или CSV)", content(
+            (TimeReportResponse = "application/json"),
+            (String = "text/csv")
+        )),
+        (status = 400, description = "Невалидные даты или формат", body = crate::dto::ErrorResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Список не найден", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Период перевёрнут или длиннее года", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn report(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(query): Query<TimeReportQuery>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let report = services::time_tracking::get_report(&state.db, user_id, &query).await?;
+
+    if query.format == ReportFormat::Json {
+        return Ok(Json(report).into_response());
+    }
+
+    let disposition = format!("attachment; filename=\"time-report-{}-{}.csv\"", report.from, report.to);
+    let headers = [
+        (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+        (header::CONTENT_DISPOSITION, disposition),
+    ];
+
+    Ok((headers, services::time_tracking::report_to_csv(&report)).into_response())
+}
@@ -0,0 +1,94 @@
+use axum::extract::{Path, State};
+use axum::response::Response;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::lists::ListResponse;
+use crate::dto::tasks::TaskResponse;
+use crate::dto::trash::TrashResponse;
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::middleware::preconditions::tagged;
+use crate::services;
+use crate::state::AppState;
+
+/// GET /trash — корзина: удалённые списки и задачи.
+#[utoipa::path(
+    get,
+    path = "/trash",
+    tag = "Trash",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Содержимое корзины", body = TrashResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_trash(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<TrashResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let trash = services::trash::get_trash(&state.db, user_id).await?;
+
+    Ok(Json(trash))
+}
+
+/// POST /lists/{list_id}/restore — восстановить список из корзины.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/restore",
+    tag = "Trash",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка")
+    ),
+    responses(
+        (status = 200, description = "Список и удалённые вместе с ним задачи восстановлены", body = ListResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Списка нет в корзине", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn restore_list(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(list_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let list = services::trash::restore_list(&state.db, list_id, user_id).await?;
+
+    Ok(tagged(list.version, Json(list)))
+}
+
+/// POST /lists/{list_id}/tasks/{task_id}/restore — восстановить задачу из корзины.
+#[utoipa::path(
+    post,
+    path = "/lists/{list_id}/tasks/{task_id}/restore",
+    tag = "Trash",
+    security(("bearer_auth" = [])),
+    params(
+        ("list_id" = Uuid, Path, description = "UUID списка"),
+        ("task_id" = Uuid, Path, description = "UUID задачи")
+    ),
+    responses(
+        (status = 200, description = "Задача восстановлена", body = TaskResponse, headers(("ETag" = String, description = "Версия ресурса"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Задачи нет в корзине", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Список задачи тоже в корзине", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn restore_task(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path((list_id, task_id)): Path<(Uuid, Uuid)>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let task = services::trash::restore_task(&state.db, list_id, user_id, task_id).await?;
+
+    Ok(tagged(task.version, Json(task)))
+}
@@ -0,0 +1,196 @@
+use axum::extract::{OriginalUri, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::Json;
+use uuid::Uuid;
+
+use crate::dto::pagination::paged;
+use crate::dto::tasks::TaskResponse;
+use crate::dto::views::{CreateViewRequest, UpdateViewRequest, ViewQuery, ViewResponse, ViewTasksQuery};
+use crate::errors::AppError;
+use crate::middleware::auth::AuthUser;
+use crate::services;
+use crate::state::AppState;
+
+/// POST /views — сохранить представление.
+#[utoipa::path(
+    post,
+    path = "/views",
+    tag = "Views",
+    request_body = CreateViewRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 201, description = "Представление создано", body = ViewResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Представление с таким именем уже есть", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое имя, неверный фильтр или сортировка", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(body): Json<CreateViewRequest>,
+) -> Result<(StatusCode, Json<ViewResponse>), AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let view = services::views::create_view(
+        &state.db, user_id, &body.name, body.filter.as_deref(), body.sort.as_deref(),
+    ).await?;
+
+    Ok((StatusCode::CREATED, Json(view)))
+}
+
+/// GET /views — встроенные и сохранённые представления пользователя (постранично).
+#[utoipa::path(
+    get,
+    path = "/views",
+    tag = "Views",
+    security(("bearer_auth" = [])),
+    params(ViewQuery),
+    responses(
+        (status = 200, description = "Встроенные (только на первой странице), затем сохранённые по имени", body = Vec<ViewResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
+        (status = 422, description = "Неверный limit или cursor", body = crate::dto::ErrorResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_all(
+    State(state): State<AppState>,
+    user: AuthUser,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ViewQuery>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::views::get_all_views(&state.db, user_id, &query).await?;
+
+    Ok(paged(&uri, page))
+}
+
+/// GET /views/{id} — одно представление.
+#[utoipa::path(
+    get,
+    path = "/views/{id}",
+    tag = "Views",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого или имя встроенного представления")
+    ),
+    responses(
+        (status = 200, description = "Найденное представление", body = ViewResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Представление не найдено", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn get_one(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(view_id): Path<String>,
+) -> Result<Json<ViewResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let view = services::views::get_view(&state.db, &view_id, user_id).await?;
+
+    Ok(Json(view))
+}
+
+/// PUT /views/{id} — обновить сохранённое представление.
+#[utoipa::path(
+    put,
+    path = "/views/{id}",
+    tag = "Views",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого представления")
+    ),
+    request_body = UpdateViewRequest,
+    responses(
+        (status = 200, description = "Обновлённое представление", body = ViewResponse),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Представление не найдено", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Имя занято или представление встроенное", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Пустое имя, неверный фильтр или сортировка", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(view_id): Path<String>,
+    Json(body): Json<UpdateViewRequest>,
+) -> Result<Json<ViewResponse>, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let view = services::views::update_view(
+        &state.db, &view_id, user_id, &body.name, &body.filter, &body.sort,
+    ).await?;
+
+    Ok(Json(view))
+}
+
+/// DELETE /views/{id} — удалить сохранённое представление.
+#[utoipa::path(
+    delete,
+    path = "/views/{id}",
+    tag = "Views",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого представления")
+    ),
+    responses(
+        (status = 204, description = "Представление удалено"),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Представление не найдено", body = crate::dto::ErrorResponse),
+        (status = 409, description = "Встроенное представление удалить нельзя", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn delete(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(view_id): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    services::views::delete_view(&state.db, &view_id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /views/{id}/tasks — задачи представления из всех списков (постранично).
+///
+/// Фильтр и сортировка разбираются заново при каждом запросе, поэтому
+/// относительные даты (`now-7d`) всегда отсчитываются от текущего момента.
+/// Архивные списки и задачи не попадают в выдачу.
+#[utoipa::path(
+    get,
+    path = "/views/{id}/tasks",
+    tag = "Views",
+    security(("bearer_auth" = [])),
+    params(
+        ("id" = String, Path, description = "UUID сохранённого или имя встроенного представления"),
+        ViewTasksQuery
+    ),
+    responses(
+        (status = 200, description = "Страница задач", body = Vec<TaskResponse>, headers(("Link" = String, description = "Ссылка на следующую страницу (rel=\"next\"), если она есть"))),
+        (status = 401, description = "Не авторизован", body = crate::dto::ErrorResponse),
+        (status = 404, description = "Представление не найдено", body = crate::dto::ErrorResponse),
+        (status = 422, description = "Неверный limit или cursor", body = crate::dto::ErrorResponse)
+    )
+)]
+pub async fn tasks(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(view_id): Path<String>,
+    OriginalUri(uri): OriginalUri,
+    Query(query): Query<ViewTasksQuery>,
+) -> Result<Response, AppError> {
+    let user_id: Uuid = user.user_id.parse()
+        .map_err(|_| AppError::Validation("Invalid user ID in token".to_string()))?;
+
+    let page = services::views::get_view_tasks(&state.db, &view_id, user_id, &query).await?;
+
+    Ok(paged(&uri, page))
+}
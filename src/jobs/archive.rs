@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+use crate::repo::task_repo;
+
+/// Запускает автоархивацию в фоне: каждые `interval` архивирует выполненные
+/// задачи списков с правилом `auto_archive_days`.
+pub fn spawn(pool: PgPool, interval: Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match run_once(&pool).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Auto-archived {} tasks", count),
+                Err(err) => tracing::error!("Auto-archive error: {:?}", err),
+            }
+        }
+    })
+}
+
+/// Один проход: архивирует задачи, выполненные больше `auto_archive_days`
+/// дней назад. Архивные и удалённые списки пропускаются; повторный запуск
+/// на другой реплике просто ничего не найдёт.
+///
+/// Возвращает количество заархивированных задач.
+pub async fn run_once(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    task_repo::archive_completed(pool).await
+}
@@ -0,0 +1,5 @@
+//! Фоновые задачи, которые запускаются из `main.rs` рядом с HTTP-сервером.
+
+pub mod archive;
+pub mod reminders;
+pub mod trash;
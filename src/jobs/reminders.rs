@@ -0,0 +1,76 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::PgPool;
+
+use crate::notifications::{Dispatcher, Notification};
+use crate::repo::reminder_repo;
+
+/// Сколько напоминаний захватывается за один проход.
+const BATCH_SIZE: i64 = 50;
+/// На сколько секунд воркер арендует захваченные напоминания. Должно с запасом
+/// покрывать доставку всей пачки (таймаут вебхука — 10 секунд на штуку).
+const LEASE_SECS: i64 = 600;
+/// Сколько раз пытаемся доставить напоминание, прежде чем пометить его "failed".
+const MAX_ATTEMPTS: i32 = 3;
+
+/// Запускает воркер напоминаний в фоне: каждые `interval` проверяет
+/// сработавшие напоминания и рассылает их.
+pub fn spawn(pool: PgPool, dispatcher: Arc<Dispatcher>, interval: Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match run_once(&pool, &dispatcher).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Processed {} reminders", count),
+                Err(err) => tracing::error!("Reminder worker error: {:?}", err),
+            }
+        }
+    })
+}
+
+/// Один проход воркера: захватывает пачку сработавших напоминаний коротким
+/// отдельным запросом, рассылает их вне транзакции и отмечает результат
+/// каждого напоминания своим запросом.
+///
+/// Пока идёт доставка, строки не заблокированы, но находятся в статусе
+/// "sending" с арендой — другой воркер их не возьмёт. Ошибка отметки одного
+/// напоминания не откатывает остальные. Если воркер упал между отправкой и
+/// отметкой, напоминание будет отправлено повторно после истечения аренды:
+/// доставка "хотя бы один раз", а не "ровно один".
+///
+/// Возвращает количество обработанных напоминаний.
+pub async fn run_once(pool: &PgPool, dispatcher: &Dispatcher) -> Result<usize, sqlx::Error> {
+    let due = reminder_repo::claim_due(pool, BATCH_SIZE, LEASE_SECS).await?;
+
+    for item in &due {
+        let reminder = &item.reminder;
+        let notification = Notification {
+            reminder_id: reminder.id,
+            task_id: reminder.task_id,
+            list_id: item.list_id,
+            task_title: item.task_title.clone(),
+            task_due_at: item.task_due_at,
+            remind_at: reminder.remind_at,
+        };
+
+        let marked = match dispatcher.dispatch(&reminder.channel, &reminder.target, &notification).await {
+            Ok(()) => reminder_repo::mark_sent(pool, reminder.id).await,
+            Err(err) => {
+                tracing::warn!("Reminder {} delivery failed: {}", reminder.id, err);
+                let attempts = reminder.attempts + 1;
+                // Линейная задержка между попытками: 1, 2, ... минуты.
+                let retry_at = (attempts < MAX_ATTEMPTS)
+                    .then(|| Utc::now() + chrono::Duration::minutes(i64::from(attempts)));
+                reminder_repo::mark_failed(pool, reminder.id, &err, retry_at).await
+            }
+        };
+        if let Err(err) = marked {
+            tracing::error!("Failed to record reminder {} result: {:?}", reminder.id, err);
+        }
+    }
+
+    Ok(due.len())
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use sqlx::PgPool;
+
+use crate::repo::{attachment_repo, list_repo, task_repo};
+use crate::storage::BlobStore;
+
+/// Запускает очистку корзины в фоне: каждые `interval` окончательно
+/// удаляет списки и задачи, пролежавшие в корзине дольше `retention`,
+/// вместе с содержимым их вложений в `blobs`.
+pub fn spawn(
+    pool: PgPool,
+    blobs: Arc<dyn BlobStore>,
+    retention: chrono::Duration,
+    interval: Duration,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match run_once(&pool, blobs.as_ref(), retention).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Purged {} items from trash", count),
+                Err(err) => tracing::error!("Trash purge error: {:?}", err),
+            }
+        }
+    })
+}
+
+/// Один проход очистки. Задачи удалённого списка уходят вместе с ним
+/// (каскад), задачи, удалённые по отдельности, — по своей отметке.
+/// Повторный запуск на другой реплике просто ничего не найдёт.
+///
+/// Строки вложений удаляются каскадом, поэтому ключи их содержимого
+/// выбираются заранее, в той же транзакции, и после коммита удаляются
+/// из хранилища. Ошибка хранилища только логируется: строки уже удалены.
+///
+/// Возвращает количество окончательно удалённых списков и задач.
+pub async fn run_once(pool: &PgPool, blobs: &dyn BlobStore, retention: chrono::Duration) -> Result<u64, sqlx::Error> {
+    let before = Utc::now() - retention;
+
+    let mut tx = pool.begin().await?;
+    let keys = attachment_repo::find_keys_in_trash(&mut *tx, before).await?;
+    let lists = list_repo::purge_deleted(&mut *tx, before).await?;
+    let tasks = task_repo::purge_deleted(&mut *tx, before).await?;
+    tx.commit().await?;
+
+    for key in keys {
+        if let Err(err) = blobs.delete(&key).await {
+            tracing::warn!("Failed to delete blob {} of purged attachment: {}", key, err);
+        }
+    }
+
+    Ok(lists + tasks)
+}
@@ -1,12 +1,15 @@
-/// Библиотечный крейт приложения.
+//! Библиотечный крейт приложения.
 
 pub mod app;
 pub mod dto;
 pub mod errors;
 pub mod handlers;
+pub mod jobs;
 pub mod middleware;
 pub mod models;
+pub mod notifications;
 pub mod repo;
 pub mod routes;
 pub mod services;
 pub mod state;
+pub mod storage;
@@ -1,9 +1,14 @@
 use sqlx::postgres::PgPoolOptions;
 use std::env;
+use std::sync::Arc;
+use std::time::Duration;
 use tracing_subscriber::filter::EnvFilter;
 
 use todo_api::app;
+use todo_api::jobs;
+use todo_api::notifications::Dispatcher;
 use todo_api::state::AppState;
+use todo_api::storage;
 
 #[tokio::main]
 async fn main() {
@@ -38,11 +43,49 @@ async fn main() {
 
     tracing::info!("Database migrations applied successfully.");
 
+    // Фоновый воркер напоминаний. Безопасен при нескольких репликах API:
+    // напоминания захватываются через FOR UPDATE SKIP LOCKED.
+    let reminder_interval = env::var("REMINDER_POLL_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30);
+    jobs::reminders::spawn(
+        pool.clone(),
+        Arc::new(Dispatcher::from_env()),
+        Duration::from_secs(reminder_interval),
+    );
+
+    // Очистка корзины: списки и задачи удаляются окончательно через
+    // TRASH_RETENTION_DAYS дней после попадания в корзину.
+    let trash_retention_days = env::var("TRASH_RETENTION_DAYS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(30);
+    let trash_purge_interval = env::var("TRASH_PURGE_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(3600);
+    let blobs = storage::from_env();
+    jobs::trash::spawn(
+        pool.clone(),
+        blobs.clone(),
+        chrono::Duration::days(trash_retention_days),
+        Duration::from_secs(trash_purge_interval),
+    );
+
+    // Автоархивация выполненных задач по правилу списка (`auto_archive_days`).
+    let auto_archive_interval = env::var("AUTO_ARCHIVE_INTERVAL_SECS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(3600);
+    jobs::archive::spawn(pool.clone(), Duration::from_secs(auto_archive_interval));
+
     let jwt_secret = env::var("JWT_SECRET").unwrap_or_else(|_| "dev-secret-key".to_string());
 
     let app_state = AppState {
         db: pool,
         jwt_secret,
+        blobs,
     };
 
     // Создаём роутер и передаём ему state.
@@ -0,0 +1,101 @@
+use std::convert::Infallible;
+
+use axum::extract::FromRequestParts;
+use axum::http::header::{ETAG, IF_MATCH, IF_NONE_MATCH};
+use axum::http::request::Parts;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+use crate::errors::AppError;
+
+/// ETag ресурса с версией `version`.
+pub fn etag(version: i32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Ответ с телом и заголовком `ETag`.
+pub fn tagged(version: i32, body: impl IntoResponse) -> Response {
+    ([(ETAG, etag(version))], body).into_response()
+}
+
+/// Условные заголовки запроса (RFC 9110): `If-Match` и `If-None-Match`.
+///
+/// ETag ресурса — его версия (колонка `version`), поэтому сравнение
+/// сводится к сравнению чисел. Extractor никогда не отклоняет запрос:
+/// проверки делают сервисы (`check`) и хендлеры (`conditional_get`).
+#[derive(Debug, Clone, Default)]
+pub struct Preconditions {
+    if_match: Option<String>,
+    if_none_match: Option<String>,
+}
+
+impl Preconditions {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value: &axum::http::HeaderValue| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Preconditions {
+            if_match: header(IF_MATCH),
+            if_none_match: header(IF_NONE_MATCH),
+        }
+    }
+
+    /// Проверяет `If-Match` перед изменением: без заголовка проходит всегда,
+    /// иначе версия должна совпасть (`*` — любая) → иначе 412.
+    pub fn check(&self, version: i32) -> Result<(), AppError> {
+        match &self.if_match {
+            Some(header) if !matches(header, version, false) => Err(AppError::PreconditionFailed(
+                "Resource has been modified".to_string(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Версия, которую запись должна застать в БД: защищает от гонки
+    /// между `check` и UPDATE. `None` — `If-Match` не передан.
+    pub fn expected_version(&self, current: i32) -> Option<i32> {
+        self.if_match.as_ref().map(|_| current)
+    }
+
+    /// Ошибка для записи, не затронувшей ни одной строки: с `If-Match`
+    /// это значит, что ресурс успели изменить (412), без него — 404.
+    pub fn row_missing(&self, not_found: &str) -> AppError {
+        match self.if_match {
+            Some(_) => AppError::PreconditionFailed("Resource has been modified".to_string()),
+            None => AppError::NotFound(not_found.to_string()),
+        }
+    }
+
+    /// Ответ на GET: 304 без тела, если `If-None-Match` совпал
+    /// с текущей версией, иначе тело с `ETag`.
+    pub fn conditional_get(&self, version: i32, body: impl IntoResponse) -> Response {
+        match &self.if_none_match {
+            Some(header) if matches(header, version, true) => {
+                (StatusCode::NOT_MODIFIED, [(ETAG, etag(version))]).into_response()
+            }
+            _ => tagged(version, body),
+        }
+    }
+}
+
+/// Совпадает ли список ETag из заголовка с версией. `If-None-Match`
+/// использует слабое сравнение (префикс `W/` игнорируется), `If-Match` — строгое.
+fn matches(header: &str, version: i32, weak: bool) -> bool {
+    let current = etag(version);
+    header.split(',').map(str::trim).any(|tag| {
+        let tag = if weak { tag.strip_prefix("W/").unwrap_or(tag) } else { tag };
+        tag == "*" || tag == current
+    })
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Preconditions {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(Preconditions::from_headers(&parts.headers))
+    }
+}
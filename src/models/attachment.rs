@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Метаданные вложения. Содержимое хранится в BlobStore под `storage_key`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub uploader_id: Uuid,
+    /// Исходное имя файла (очищенное от путей и управляющих символов).
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
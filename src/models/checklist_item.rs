@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Доменная модель пункта чек-листа внутри задачи.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub title: String,
+    pub done: bool,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Прогресс чек-листа одной задачи (выполнено / всего).
+#[derive(Debug, Clone, FromRow)]
+pub struct ChecklistProgress {
+    pub task_id: Uuid,
+    pub done: i64,
+    pub total: i64,
+}
@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Комментарий к задаче вместе с email автора (JOIN с users).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Comment {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub author_id: Uuid,
+    pub author_email: String,
+    /// Текст в Markdown.
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    /// Время последнего редактирования (None — не редактировался).
+    pub edited_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Что произошло с ресурсом.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HistoryAction {
+    Created,
+    Updated,
+    Deleted,
+    /// Восстановлен из корзины.
+    Restored,
+}
+
+/// Строка, не являющаяся действием журнала.
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown history action '{0}'")]
+pub struct UnknownHistoryAction(pub String);
+
+impl HistoryAction {
+    /// Значение в БД и в API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HistoryAction::Created => "created",
+            HistoryAction::Updated => "updated",
+            HistoryAction::Deleted => "deleted",
+            HistoryAction::Restored => "restored",
+        }
+    }
+}
+
+impl fmt::Display for HistoryAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for HistoryAction {
+    type Err = UnknownHistoryAction;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "created" => Ok(HistoryAction::Created),
+            "updated" => Ok(HistoryAction::Updated),
+            "deleted" => Ok(HistoryAction::Deleted),
+            "restored" => Ok(HistoryAction::Restored),
+            other => Err(UnknownHistoryAction(other.to_string())),
+        }
+    }
+}
+
+/// Для `#[sqlx(try_from = "String")]` в модели HistoryEvent.
+impl TryFrom<String> for HistoryAction {
+    type Error = UnknownHistoryAction;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Запись журнала изменений списка или задачи.
+#[derive(Debug, Clone, FromRow)]
+pub struct HistoryEvent {
+    pub id: i64,
+    pub list_id: Uuid,
+    /// `None` — событие самого списка.
+    pub task_id: Option<Uuid>,
+    /// `None` — автор удалён.
+    pub actor_id: Option<Uuid>,
+    /// Email автора (подтягивается JOIN-ом при чтении).
+    pub actor_email: Option<String>,
+    #[sqlx(try_from = "String")]
+    pub action: HistoryAction,
+    /// `{"поле": {"old": ..., "new": ...}}`.
+    pub changes: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Доменная модель метки (тега).
+///
+/// Метки принадлежат пользователю и могут навешиваться на задачи
+/// из любых его списков (связь many-to-many через `task_labels`).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Label {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    /// Цвет в формате `#RRGGBB`.
+    pub color: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Метка вместе с ID задачи, к которой она привязана.
+/// Используется для пакетной загрузки меток сразу для нескольких задач.
+#[derive(Debug, Clone, FromRow)]
+pub struct TaskLabel {
+    pub task_id: Uuid,
+    #[sqlx(flatten)]
+    pub label: Label,
+}
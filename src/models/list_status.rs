@@ -0,0 +1,115 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::task::Task;
+use crate::models::task_status::TaskStatus;
+
+/// Категория пользовательского статуса. Задаёт статус задачи
+/// (`TaskStatus`), а значит и правила переходов между колонками.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCategory {
+    NotStarted,
+    Active,
+    Done,
+}
+
+/// Строка, не являющаяся категорией статуса.
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown category '{0}': expected 'not_started', 'active' or 'done'")]
+pub struct UnknownStatusCategory(pub String);
+
+impl StatusCategory {
+    /// Значение в БД и в API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            StatusCategory::NotStarted => "not_started",
+            StatusCategory::Active => "active",
+            StatusCategory::Done => "done",
+        }
+    }
+
+    /// Статус задачи, стоящей в колонке этой категории.
+    pub fn task_status(self) -> TaskStatus {
+        match self {
+            StatusCategory::NotStarted => TaskStatus::Todo,
+            StatusCategory::Active => TaskStatus::InProgress,
+            StatusCategory::Done => TaskStatus::Done,
+        }
+    }
+}
+
+impl From<TaskStatus> for StatusCategory {
+    fn from(status: TaskStatus) -> Self {
+        match status {
+            TaskStatus::Todo => StatusCategory::NotStarted,
+            TaskStatus::InProgress => StatusCategory::Active,
+            TaskStatus::Done => StatusCategory::Done,
+        }
+    }
+}
+
+impl fmt::Display for StatusCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StatusCategory {
+    type Err = UnknownStatusCategory;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "not_started" => Ok(StatusCategory::NotStarted),
+            "active" => Ok(StatusCategory::Active),
+            "done" => Ok(StatusCategory::Done),
+            other => Err(UnknownStatusCategory(other.to_string())),
+        }
+    }
+}
+
+/// Для `#[sqlx(try_from = "String")]` в модели ListStatus.
+impl TryFrom<String> for StatusCategory {
+    type Error = UnknownStatusCategory;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Пользовательский статус списка — колонка канбан-доски.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ListStatus {
+    pub id: Uuid,
+    pub list_id: Uuid,
+    pub name: String,
+    #[sqlx(try_from = "String")]
+    pub category: StatusCategory,
+    /// Ранг колонки на доске (см. models::rank).
+    pub position: String,
+    /// Сколько задач может одновременно стоять в колонке (`None` — без ограничения).
+    pub wip_limit: Option<i32>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Колонка, в которой задача стоит на доске.
+///
+/// `statuses` — статусы списка задачи в порядке колонок. Если `status_id`
+/// задачи не указан, удалён или другой категории (статус сменили не через
+/// доску), задача стоит в первой колонке категории своего статуса.
+pub fn column_of<'a>(statuses: &'a [ListStatus], task: &Task) -> Option<&'a ListStatus> {
+    let category = StatusCategory::from(task.status);
+    let in_category = |status: &&ListStatus| status.list_id == task.list_id && status.category == category;
+
+    statuses
+        .iter()
+        .filter(in_category)
+        .find(|status| Some(status.id) == task.status_id)
+        .or_else(|| statuses.iter().find(in_category))
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Сохранённый шаблон списка: из него одним запросом создаётся новый список
+/// с теми же задачами.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ListTemplate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Задача шаблона.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TemplateTask {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    /// Порядковый номер задачи в списке (с нуля).
+    pub position: i32,
+    pub title: String,
+    /// Срок — через столько минут после начала списка (`starts_at` нового
+    /// списка; в сохранённом шаблоне 0 — самый ранний срок); `None` — без срока.
+    pub due_offset_minutes: Option<i32>,
+}
+
+/// Задача встроенного шаблона.
+#[derive(Debug, Clone, Copy)]
+pub struct BuiltinTemplateTask {
+    pub title: &'static str,
+    pub due_offset_minutes: Option<i32>,
+}
+
+/// Встроенный шаблон: есть у каждого пользователя, не меняется.
+#[derive(Debug, Clone, Copy)]
+pub struct BuiltinTemplate {
+    /// Идентификатор в API (`/templates/release_checklist`).
+    pub slug: &'static str,
+    pub title: &'static str,
+    pub tasks: &'static [BuiltinTemplateTask],
+}
+
+/// Задача со сроком через `days` дней после начала списка.
+const fn due_in_days(title: &'static str, days: i32) -> BuiltinTemplateTask {
+    BuiltinTemplateTask { title, due_offset_minutes: Some(days * 24 * 60) }
+}
+
+/// Задача без срока.
+const fn undated(title: &'static str) -> BuiltinTemplateTask {
+    BuiltinTemplateTask { title, due_offset_minutes: None }
+}
+
+/// Встроенные шаблоны в порядке показа.
+pub const BUILTIN_TEMPLATES: &[BuiltinTemplate] = &[
+    BuiltinTemplate {
+        slug: "release_checklist",
+        title: "Чек-лист релиза",
+        tasks: &[
+            due_in_days("Заморозить релизную ветку", 0),
+            due_in_days("Прогнать регрессионные тесты", 1),
+            due_in_days("Обновить CHANGELOG", 1),
+            due_in_days("Выкатить на staging", 2),
+            due_in_days("Выкатить в production", 3),
+            due_in_days("Объявить о релизе", 3),
+        ],
+    },
+    BuiltinTemplate {
+        slug: "weekly_review",
+        title: "Еженедельный обзор",
+        tasks: &[
+            undated("Разобрать входящие"),
+            undated("Закрыть выполненное и отложенное"),
+            undated("Проверить сроки на неделю"),
+            undated("Выбрать три главные задачи"),
+        ],
+    },
+    BuiltinTemplate {
+        slug: "trip",
+        title: "Сборы в поездку",
+        tasks: &[
+            due_in_days("Купить билеты", 0),
+            due_in_days("Забронировать жильё", 1),
+            due_in_days("Оформить страховку", 3),
+            due_in_days("Собрать вещи", 6),
+            due_in_days("Зарядить гаджеты", 6),
+        ],
+    },
+];
+
+/// Встроенный шаблон по идентификатору.
+pub fn builtin(slug: &str) -> Option<&'static BuiltinTemplate> {
+    BUILTIN_TEMPLATES.iter().find(|template| template.slug == slug)
+}
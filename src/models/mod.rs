@@ -1,3 +1,21 @@
+pub mod attachment;
+pub mod checklist_item;
+pub mod comment;
+pub mod history_event;
+pub mod label;
+pub mod list_template;
+pub mod quick_add;
+pub mod list_status;
+pub mod rank;
+pub mod recurrence;
+pub mod reminder;
+pub mod search;
 pub mod task;
+pub mod task_dependency;
+pub mod task_query;
+pub mod task_status;
+pub mod time_entry;
 pub mod todo_list;
+pub mod trash;
 pub mod user;
+pub mod view;
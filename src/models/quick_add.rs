@@ -0,0 +1,447 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc, Weekday};
+
+use crate::models::task_status::TaskStatus;
+
+/// Время, на которое ставится срок, если в тексте есть только дата.
+const DEFAULT_TIME: (u32, u32) = (9, 0);
+
+/// Годы, которые распознаются в сроке. Даты вне диапазона (`-5000-01-01`,
+/// `+262142-12-31`) остаются в названии задачи.
+const YEARS: std::ops::RangeInclusive<i32> = 1970..=9999;
+
+/// Знаки препинания, которые отбрасываются с краёв слова при разборе.
+const PUNCTUATION: &[char] = &[',', '.', ';', '!', '?', '(', ')'];
+
+/// Результат разбора строки быстрого добавления задачи.
+///
+/// Распознаются (по-английски и по-русски):
+/// - список: `@Покупки`, `@Список_дел` (`_` — пробел в названии, если
+///   списка с `_` в названии нет);
+/// - статус: `#done`, `#готово`, `#wip`, `#в_работе`, `#todo`, `[x]`;
+/// - дата: `today`/`сегодня`, `tomorrow`/`завтра`, `послезавтра`, дни недели
+///   (`friday`, `next monday`, `в пятницу`, `до среды`), `15.03`, `2026-03-15`,
+///   `15 march`, `march 15`, `15 марта`, `in 3 days`/`через неделю`;
+/// - время: `9am`, `9:30 pm`, `at 9`, `21:00`, `в 9`, `7 вечера`, `noon`,
+///   `в полдень`, `утром`, `вечером`, `tonight`, `in 2 hours`/`через полчаса`.
+///
+/// Всё, что не распознано, остаётся в названии задачи. Из повторяющихся
+/// указаний (две даты, два списка) учитывается первое.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAdd {
+    pub title: String,
+    /// Название списка из `@Список` как есть (с `_`).
+    pub list: Option<String>,
+    pub status: Option<TaskStatus>,
+    pub due_at: Option<DateTime<Utc>>,
+    /// Распознанные фрагменты исходного текста в порядке появления.
+    pub recognized: Vec<String>,
+}
+
+/// Указание на момент времени, найденное в тексте.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Moment {
+    Date(NaiveDate),
+    Time(NaiveTime),
+    /// Точный момент: `tonight`, `in 2 hours`.
+    DateTime(NaiveDateTime),
+}
+
+impl QuickAdd {
+    /// Разбирает `text`. Относительные даты отсчитываются от `now`
+    /// в часовом поясе пользователя `offset`.
+    pub fn parse(text: &str, now: DateTime<Utc>, offset: FixedOffset) -> Self {
+        let local_now = now.with_timezone(&offset).naive_local();
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let normalized: Vec<String> = words
+            .iter()
+            .map(|word| word.trim_matches(PUNCTUATION).to_lowercase())
+            .collect();
+
+        let mut title = Vec::new();
+        let mut list = None;
+        let mut status = None;
+        let mut date = None;
+        let mut time = None;
+        let mut recognized = Vec::new();
+
+        let mut index = 0;
+        while index < words.len() {
+            let word = &normalized[index];
+            let mut consumed = 0;
+
+            if let Some(name) = words[index].strip_prefix('@').map(|name| name.trim_matches(PUNCTUATION)) {
+                if list.is_none() && !name.is_empty() {
+                    list = Some(name.to_string());
+                    consumed = 1;
+                }
+            } else if let Some(marker) = status_marker(word) {
+                if status.is_none() {
+                    status = Some(marker);
+                    consumed = 1;
+                }
+            } else if let Some((length, moment)) = moment(&normalized[index..], local_now) {
+                let accepted = match moment {
+                    Moment::Date(day) if date.is_none() => {
+                        date = Some(day);
+                        true
+                    }
+                    Moment::Time(at) if time.is_none() => {
+                        time = Some(at);
+                        true
+                    }
+                    Moment::DateTime(at) if date.is_none() && time.is_none() => {
+                        date = Some(at.date());
+                        time = Some(at.time());
+                        true
+                    }
+                    _ => false,
+                };
+                if accepted {
+                    consumed = length;
+                }
+            }
+
+            if consumed == 0 {
+                title.push(words[index]);
+                index += 1;
+            } else {
+                recognized.push(words[index..index + consumed].join(" ").trim_matches(PUNCTUATION).to_string());
+                index += consumed;
+            }
+        }
+
+        let due_at = due_at(date, time, local_now)
+            .and_then(|at| offset.from_local_datetime(&at).single())
+            .map(|at| at.with_timezone(&Utc));
+
+        QuickAdd {
+            title: title.join(" ").trim_end_matches([',', ';', ':', '-', '—']).trim().to_string(),
+            list,
+            status,
+            due_at,
+            recognized,
+        }
+    }
+}
+
+/// Срок из найденных даты и времени. Только время — ближайшее такое
+/// (сегодня или завтра), только дата — `DEFAULT_TIME` в этот день.
+fn due_at(date: Option<NaiveDate>, time: Option<NaiveTime>, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let default_time = NaiveTime::from_hms_opt(DEFAULT_TIME.0, DEFAULT_TIME.1, 0).expect("valid default time");
+    match (date, time) {
+        (Some(date), time) => Some(date.and_time(time.unwrap_or(default_time))),
+        (None, Some(time)) if now.date().and_time(time) > now => Some(now.date().and_time(time)),
+        (None, Some(time)) => Some(now.date().succ_opt()?.and_time(time)),
+        (None, None) => None,
+    }
+}
+
+/// Статус из метки `#done`, `#в_работе`, `[x]` и т. п.
+fn status_marker(word: &str) -> Option<TaskStatus> {
+    if word == "[x]" {
+        return Some(TaskStatus::Done);
+    }
+    match word.strip_prefix('#')? {
+        "done" | "готово" | "сделано" => Some(TaskStatus::Done),
+        "wip" | "in_progress" | "started" | "в_работе" | "вработе" => Some(TaskStatus::InProgress),
+        "todo" | "сделать" => Some(TaskStatus::Todo),
+        _ => None,
+    }
+}
+
+/// Момент времени в начале `words`: сколько слов он занимает и что означает.
+/// Даты за пределами `YEARS` не считаются.
+fn moment(words: &[String], now: NaiveDateTime) -> Option<(usize, Moment)> {
+    bare_moment(words, now)
+        .or_else(|| with_preposition(words, now))
+        .filter(|(_, moment)| match moment {
+            Moment::Date(date) => YEARS.contains(&date.year()),
+            Moment::DateTime(at) => YEARS.contains(&at.year()),
+            Moment::Time(_) => true,
+        })
+}
+
+/// Момент без предлога: `завтра`, `friday`, `15.03`, `in 2 hours`, `9am`, `утром`.
+fn bare_moment(words: &[String], now: NaiveDateTime) -> Option<(usize, Moment)> {
+    let today = now.date();
+    let first = words.first()?.as_str();
+
+    if let Some(found) = relative(words, now) {
+        return Some(found);
+    }
+    if let Some((length, date)) = calendar_date(words, today) {
+        return Some((length, Moment::Date(date)));
+    }
+    if let Some(day) = weekday(first) {
+        return Some((1, Moment::Date(next_weekday(today, day))));
+    }
+    if let Some((length, time)) = clock(words) {
+        return Some((length, Moment::Time(time)));
+    }
+    if first == "tonight" {
+        return Some((1, Moment::DateTime(today.and_time(hm(20, 0)?))));
+    }
+    let time = match first {
+        "noon" | "полдень" => hm(12, 0),
+        "midnight" | "полночь" => hm(0, 0),
+        "утром" => hm(9, 0),
+        "днём" | "днем" => hm(13, 0),
+        "вечером" => hm(19, 0),
+        _ => None,
+    }?;
+    Some((1, Moment::Time(time)))
+}
+
+/// Момент с предлогом: `on friday`, `next monday`, `в пятницу`, `до 15.03`,
+/// `at 9`, `в 9:30`, `в полдень`.
+fn with_preposition(words: &[String], now: NaiveDateTime) -> Option<(usize, Moment)> {
+    let (preposition, rest) = words.split_first()?;
+    let next = rest.first()?;
+    let today = now.date();
+
+    if matches!(preposition.as_str(), "on" | "next" | "this" | "by" | "в" | "во" | "до" | "к" | "ко")
+        && let Some(day) = weekday(next)
+    {
+        return Some((2, Moment::Date(next_weekday(today, day))));
+    }
+    if matches!(preposition.as_str(), "on" | "by" | "до" | "к")
+        && let Some((length, date)) = calendar_date(rest, today)
+    {
+        return Some((length + 1, Moment::Date(date)));
+    }
+    if matches!(preposition.as_str(), "at" | "в") {
+        let time = clock(rest)
+            .or_else(|| hour_and_minute(next).map(|time| (1, time)))
+            .or_else(|| match next.as_str() {
+                "noon" | "полдень" => Some((1, hm(12, 0)?)),
+                "midnight" | "полночь" => Some((1, hm(0, 0)?)),
+                _ => None,
+            });
+        return time.map(|(length, time)| (length + 1, Moment::Time(time)));
+    }
+    None
+}
+
+/// `in 2 hours`, `in a week`, `через 3 дня`, `через час`, `через полчаса`.
+/// Дни и недели дают дату, минуты и часы — точный момент.
+fn relative(words: &[String], now: NaiveDateTime) -> Option<(usize, Moment)> {
+    let (first, rest) = words.split_first()?;
+    if !matches!(first.as_str(), "in" | "через") {
+        return None;
+    }
+    if first == "через" && rest.first()? == "полчаса" {
+        return Some((2, Moment::DateTime(now + Duration::minutes(30))));
+    }
+
+    let (amount, unit, length) = match rest.first()?.as_str() {
+        "a" | "an" => (1, rest.get(1)?, 3),
+        number => match number.parse::<i64>() {
+            Ok(amount) if (1..=1000).contains(&amount) => (amount, rest.get(1)?, 3),
+            Ok(_) => return None,
+            // «через час», «через неделю»: без числа — одна единица.
+            Err(_) if first == "через" => (1, rest.first()?, 2),
+            Err(_) => return None,
+        },
+    };
+
+    let moment = match unit.as_str() {
+        "minute" | "minutes" | "min" | "mins" => Moment::DateTime(now + Duration::minutes(amount)),
+        "hour" | "hours" => Moment::DateTime(now + Duration::hours(amount)),
+        "day" | "days" | "день" | "дня" | "дней" => Moment::Date(now.date() + Duration::days(amount)),
+        "week" | "weeks" => Moment::Date(now.date() + Duration::weeks(amount)),
+        unit if unit.starts_with("минут") => Moment::DateTime(now + Duration::minutes(amount)),
+        "час" | "часа" | "часов" => Moment::DateTime(now + Duration::hours(amount)),
+        unit if unit.starts_with("недел") => Moment::Date(now.date() + Duration::weeks(amount)),
+        _ => return None,
+    };
+    Some((length, moment))
+}
+
+/// Календарная дата: `today`, `завтра`, `послезавтра`, `(the) day after tomorrow`,
+/// `2026-03-15`, `15.03`, `15.03.2026`, `15 march`, `march 15th`, `15 марта`.
+/// Дата без года, уже прошедшая в этом году, — в следующем году.
+fn calendar_date(words: &[String], today: NaiveDate) -> Option<(usize, NaiveDate)> {
+    let first = words.first()?.as_str();
+    match first {
+        "today" | "сегодня" => return Some((1, today)),
+        "tomorrow" | "завтра" => return Some((1, today.succ_opt()?)),
+        "послезавтра" => return Some((1, today + Duration::days(2))),
+        "day" if words.get(1..3).is_some_and(|rest| rest == ["after", "tomorrow"]) => {
+            return Some((3, today + Duration::days(2)));
+        }
+        "the" if words.get(1..4).is_some_and(|rest| rest == ["day", "after", "tomorrow"]) => {
+            return Some((4, today + Duration::days(2)));
+        }
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(first, "%Y-%m-%d") {
+        return Some((1, date));
+    }
+    if let Some((day, month)) = first.split_once('.') {
+        let date = match month.split_once('.') {
+            Some((month, year)) if year.len() == 4 => {
+                NaiveDate::from_ymd_opt(year.parse().ok()?, two_digits(month)?, day_of_month(day)?)
+            }
+            Some(_) => None,
+            None => upcoming(today, two_digits(month)?, day_of_month(day)?),
+        };
+        return date.map(|date| (1, date));
+    }
+
+    let second = words.get(1)?;
+    if let (Some(day), Some(month)) = (day_of_month(first), month(second)) {
+        return upcoming(today, month, day).map(|date| (2, date));
+    }
+    if let (Some(month), Some(day)) = (month(first), day_of_month(second)) {
+        return upcoming(today, month, day).map(|date| (2, date));
+    }
+    None
+}
+
+/// Ближайшая (сегодня или позже) дата с таким днём и месяцем.
+fn upcoming(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    let date = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if date >= today {
+        Some(date)
+    } else {
+        NaiveDate::from_ymd_opt(today.year() + 1, month, day)
+    }
+}
+
+/// Время на часах: `9am`, `9:30pm`, `9 am`, `21:00`, `9 утра`, `7 вечера`, `2 ночи`.
+/// Одно число без уточнения временем не считается.
+fn clock(words: &[String]) -> Option<(usize, NaiveTime)> {
+    let first = words.first()?.as_str();
+    for suffix in ["am", "pm"] {
+        if let Some(time) = first.strip_suffix(suffix).filter(|time| !time.is_empty()) {
+            return twelve_hour(time, suffix).map(|time| (1, time));
+        }
+    }
+
+    if let Some(suffix) = words.get(1) {
+        let time = match suffix.as_str() {
+            "am" | "pm" => twelve_hour(first, suffix),
+            // «12 ночи» — полночь, как `12am`.
+            "утра" | "ночи" => twelve_hour(first, "am"),
+            "вечера" => twelve_hour(first, "pm"),
+            _ => None,
+        };
+        if let Some(time) = time {
+            return Some((2, time));
+        }
+    }
+
+    if first.contains(':') {
+        return hour_and_minute(first).map(|time| (1, time));
+    }
+    None
+}
+
+/// `9`, `9:30` с признаком `am`/`pm` (часы от 1 до 12).
+fn twelve_hour(time: &str, suffix: &str) -> Option<NaiveTime> {
+    let time = hour_and_minute(time)?;
+    let hour = time.hour();
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+    let hour = match suffix {
+        "am" => hour % 12,
+        _ => hour % 12 + 12,
+    };
+    hm(hour, time.minute())
+}
+
+/// `9`, `09`, `9:30`, `21:00` — часы от 0 до 23, минуты двумя цифрами.
+fn hour_and_minute(time: &str) -> Option<NaiveTime> {
+    let (hour, minute) = match time.split_once(':') {
+        Some((hour, minute)) => (hour, two_digits(minute).filter(|minute| *minute < 60)?),
+        None => (time, 0),
+    };
+    if hour.is_empty() || hour.len() > 2 || !hour.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    hm(hour.parse().ok()?, minute)
+}
+
+/// Ровно две цифры: месяц в `15.03`, минуты в `9:30`.
+fn two_digits(value: &str) -> Option<u32> {
+    (value.len() == 2 && value.bytes().all(|byte| byte.is_ascii_digit()))
+        .then(|| value.parse().ok())
+        .flatten()
+}
+
+/// День месяца: `15`, `1st`, `22nd`, `3rd`, `15th`.
+fn day_of_month(value: &str) -> Option<u32> {
+    let digits = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| value.strip_suffix(suffix))
+        .unwrap_or(value);
+    if digits.is_empty() || digits.len() > 2 || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+/// Месяц по названию: английскому (полному или сокращённому) или русскому
+/// в родительном падеже (`15 марта`).
+fn month(word: &str) -> Option<u32> {
+    let month = match word {
+        "january" | "jan" | "января" => 1,
+        "february" | "feb" | "февраля" => 2,
+        "march" | "mar" | "марта" => 3,
+        "april" | "apr" | "апреля" => 4,
+        "may" | "мая" => 5,
+        "june" | "jun" | "июня" => 6,
+        "july" | "jul" | "июля" => 7,
+        "august" | "aug" | "августа" => 8,
+        "september" | "sep" | "sept" | "сентября" => 9,
+        "october" | "oct" | "октября" => 10,
+        "november" | "nov" | "ноября" => 11,
+        "december" | "dec" | "декабря" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// Русские дни недели: основа и допустимые окончания падежей
+/// (`пятница`, `пятницу`, `пятницы`, `пятнице`).
+const WEEKDAYS_RU: [(&str, Weekday); 7] = [
+    ("понедельник", Weekday::Mon),
+    ("вторник", Weekday::Tue),
+    ("сред", Weekday::Wed),
+    ("четверг", Weekday::Thu),
+    ("пятниц", Weekday::Fri),
+    ("суббот", Weekday::Sat),
+    ("воскресень", Weekday::Sun),
+];
+
+/// День недели по названию (английскому или русскому в любом падеже).
+fn weekday(word: &str) -> Option<Weekday> {
+    let day = match word {
+        "monday" => Weekday::Mon,
+        "tuesday" => Weekday::Tue,
+        "wednesday" => Weekday::Wed,
+        "thursday" => Weekday::Thu,
+        "friday" => Weekday::Fri,
+        "saturday" => Weekday::Sat,
+        "sunday" => Weekday::Sun,
+        _ => {
+            return WEEKDAYS_RU.iter().find_map(|(stem, day)| {
+                let ending = word.strip_prefix(stem)?;
+                (ending.is_empty() || (ending.chars().count() == 1 && "аеуыюя".contains(ending))).then_some(*day)
+            });
+        }
+    };
+    Some(day)
+}
+
+/// Ближайший такой день недели после сегодняшнего (сегодняшний — через неделю).
+fn next_weekday(today: NaiveDate, day: Weekday) -> NaiveDate {
+    let ahead = (7 + day.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(if ahead == 0 { 7 } else { i64::from(ahead) })
+}
+
+fn hm(hour: u32, minute: u32) -> Option<NaiveTime> {
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
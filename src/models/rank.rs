@@ -0,0 +1,117 @@
+//! Дробные лексикографические ранги для ручной сортировки.
+//!
+//! Ранг — строка из цифр base36 (`0-9a-z`), которая читается как дробь
+//! `0.xxx`. Между любыми двумя рангами всегда есть ещё один, поэтому
+//! перестановка элемента меняет одну строку в БД. Ранги никогда не
+//! заканчиваются на `0` — тогда побайтовое сравнение (`COLLATE "C"`)
+//! совпадает с порядком дробей.
+//!
+//! Ранг у края (в начале или в конце) получается сдвигом первой цифры,
+//! которую можно сдвинуть, а не делением пополам: при добавлении подряд
+//! в конец ранг удлиняется на символ лишь раз в ~35 вставок.
+
+const DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+const BASE: u32 = DIGITS.len() as u32;
+
+/// Длина ранга, после которой список стоит перебалансировать.
+pub const MAX_RANK_LEN: usize = 24;
+
+fn digit_value(rank: &[u8], index: usize) -> u32 {
+    rank.get(index)
+        .and_then(|byte| DIGITS.iter().position(|digit| digit == byte))
+        .map_or(0, |value| value as u32)
+}
+
+/// Ранг строго между `before` и `after` (`None` — край списка).
+///
+/// Возвращает `None`, если `before >= after` — например, у соседей
+/// одинаковый ранг; тогда диапазон нужно перебалансировать.
+pub fn between(before: Option<&str>, after: Option<&str>) -> Option<String> {
+    let rank = match (before, after) {
+        (Some(low), Some(high)) if low >= high => return None,
+        (Some(low), None) => next(low.as_bytes()),
+        (None, Some(high)) => previous(high.as_bytes())?,
+        _ => midpoint(before.unwrap_or("").as_bytes(), after.map(str::as_bytes)),
+    };
+
+    Some(String::from_utf8(rank).expect("rank digits are ASCII"))
+}
+
+/// Короткий ранг после `low`: первая цифра, отличная от `z`, растёт на единицу,
+/// хвост отбрасывается. Ранг из одних `z` удлиняется.
+fn next(low: &[u8]) -> Vec<u8> {
+    match low.iter().position(|&digit| digit != DIGITS[BASE as usize - 1]) {
+        Some(index) => {
+            let mut rank = low[..index].to_vec();
+            rank.push(DIGITS[digit_value(low, index) as usize + 1]);
+            rank
+        }
+        None => [low, &DIGITS[1..2]].concat(),
+    }
+}
+
+/// Короткий ранг перед `high`: первая ненулевая цифра уменьшается на единицу,
+/// а если она `1` — заменяется на `0z` (ранг не может кончаться на `0`).
+/// `None` — перед `high` места нет (пустой ранг или одни нули).
+fn previous(high: &[u8]) -> Option<Vec<u8>> {
+    let index = high.iter().position(|&digit| digit != DIGITS[0])?;
+    let mut rank = high[..index].to_vec();
+    match digit_value(high, index) {
+        1 => rank.extend([DIGITS[0], DIGITS[BASE as usize - 1]]),
+        value => rank.push(DIGITS[value as usize - 1]),
+    }
+    Some(rank)
+}
+
+/// Ранг посередине между `low` и `high` (`None` — без верхней границы).
+fn midpoint(low: &[u8], high: Option<&[u8]>) -> Vec<u8> {
+
+    let mut result = Vec::new();
+    // Пока общий префикс с `after` совпадает, верхняя граница — его цифра;
+    // после расхождения — BASE (то есть "без ограничения").
+    let mut bounded = high.is_some();
+
+    for index in 0.. {
+        let lo = digit_value(low, index);
+        let hi = match high {
+            Some(high) if bounded => digit_value(high, index),
+            _ => BASE,
+        };
+
+        if hi > lo + 1 {
+            result.push(DIGITS[((lo + hi) / 2) as usize]);
+            break;
+        }
+        if hi == lo + 1 {
+            bounded = false;
+        }
+        result.push(DIGITS[lo as usize]);
+    }
+
+    result
+}
+
+/// `count` равномерно распределённых рангов (для перебалансировки).
+pub fn evenly_spaced(count: usize) -> Vec<String> {
+    let mut width = 1;
+    while (BASE as u128).pow(width) <= count as u128 + 1 {
+        width += 1;
+    }
+    let space = (BASE as u128).pow(width);
+    let step = space / (count as u128 + 1);
+
+    (1..=count as u128)
+        .map(|index| {
+            let mut value = step * index;
+            let mut digits = vec![b'0'; width as usize];
+            for slot in digits.iter_mut().rev() {
+                *slot = DIGITS[(value % BASE as u128) as usize];
+                value /= BASE as u128;
+            }
+            while digits.last() == Some(&b'0') {
+                digits.pop();
+            }
+            String::from_utf8(digits).expect("rank digits are ASCII")
+        })
+        .collect()
+}
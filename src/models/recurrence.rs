@@ -0,0 +1,198 @@
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveDateTime, TimeDelta, TimeZone, Utc, Weekday};
+
+/// Наибольший допустимый `INTERVAL`: больше — бессмысленно и грозит
+/// переполнением дат.
+pub const MAX_INTERVAL: u32 = 1000;
+
+/// Частота повторения (`FREQ` из RFC 5545).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Разобранное правило повторения RRULE.
+///
+/// Поддерживается подмножество RFC 5545, которого хватает для
+/// бытовых повторов: `FREQ`, `INTERVAL`, `BYDAY` (только для `WEEKLY`,
+/// без числовых префиксов), `COUNT` и `UNTIL`. Неделя начинается
+/// с понедельника (`WKST=MO`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<Weekday>,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl RecurrenceRule {
+    /// Разбирает строку вида `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH`.
+    /// Префикс `RRULE:` допускается. Возвращает текст ошибки для клиента.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim();
+        let input = input.strip_prefix("RRULE:").unwrap_or(input);
+
+        let mut freq = None;
+        let mut interval = 1;
+        let mut by_day = Vec::new();
+        let mut count = None;
+        let mut until = None;
+
+        for part in input.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("Invalid RRULE part: {part}"))?;
+
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(format!("Unsupported FREQ: {other}")),
+                    });
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse::<u32>()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| format!("Invalid INTERVAL: {value}"))?;
+                    if interval > MAX_INTERVAL {
+                        return Err(format!("INTERVAL must be at most {MAX_INTERVAL}"));
+                    }
+                }
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        by_day.push(parse_weekday(day)?);
+                    }
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse::<u32>()
+                            .ok()
+                            .filter(|count| *count > 0)
+                            .ok_or_else(|| format!("Invalid COUNT: {value}"))?,
+                    );
+                }
+                "UNTIL" => until = Some(parse_until(value)?),
+                other => return Err(format!("Unsupported RRULE part: {other}")),
+            }
+        }
+
+        let freq = freq.ok_or_else(|| "RRULE must contain FREQ".to_string())?;
+
+        if !by_day.is_empty() && freq != Frequency::Weekly {
+            return Err("BYDAY is supported only with FREQ=WEEKLY".to_string());
+        }
+        if count.is_some() && until.is_some() {
+            return Err("COUNT and UNTIL must not be used together".to_string());
+        }
+
+        Ok(Self { freq, interval, by_day, count, until })
+    }
+
+    /// Следующее повторение строго после `after` (время суток сохраняется).
+    ///
+    /// Возвращает `None`, если правило закончилось по `UNTIL` или следующая
+    /// дата не представима (серия заканчивается, а не падает).
+    /// Ограничение по `COUNT` проверяет вызывающий код — ему известно,
+    /// сколько повторений серии уже было.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let next = match self.freq {
+            Frequency::Daily => TimeDelta::try_days(i64::from(self.interval))
+                .and_then(|step| after.checked_add_signed(step)),
+            Frequency::Weekly if self.by_day.is_empty() => TimeDelta::try_weeks(i64::from(self.interval))
+                .and_then(|step| after.checked_add_signed(step)),
+            Frequency::Weekly => self.next_weekday(after),
+            Frequency::Monthly => self.next_month(after),
+            Frequency::Yearly => self.next_year(after),
+        }?;
+
+        match self.until {
+            Some(until) if next > until => None,
+            _ => Some(next),
+        }
+    }
+
+    /// WEEKLY + BYDAY: ближайший подходящий день недели в "активной" неделе
+    /// (каждая `INTERVAL`-я неделя, считая от недели `after`).
+    fn next_weekday(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let anchor_week = week_start(after.date_naive())?;
+        let horizon = 7 * i64::from(self.interval) + 7;
+
+        (1..=horizon)
+            .map_while(|offset| TimeDelta::try_days(offset).and_then(|step| after.checked_add_signed(step)))
+            .find(|candidate| {
+                let Some(week) = week_start(candidate.date_naive()) else {
+                    return false;
+                };
+                (week - anchor_week).num_weeks() % i64::from(self.interval) == 0
+                    && self.by_day.contains(&candidate.weekday())
+            })
+    }
+
+    /// MONTHLY: то же число через `INTERVAL` месяцев.
+    /// Несуществующие даты (31 февраля) пропускаются, как требует RFC 5545.
+    fn next_month(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let months_from_zero = after.year() * 12 + after.month0() as i32;
+
+        (1..=48).find_map(|step: i32| {
+            let total = step
+                .checked_mul(i32::try_from(self.interval).ok()?)?
+                .checked_add(months_from_zero)?;
+            let date = NaiveDate::from_ymd_opt(total / 12, (total % 12) as u32 + 1, after.day())?;
+            Some(with_time_of(date, after))
+        })
+    }
+
+    /// YEARLY: та же дата через `INTERVAL` лет (29 февраля — только в високосные).
+    fn next_year(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        (1..=8).find_map(|step: i32| {
+            let year = step
+                .checked_mul(i32::try_from(self.interval).ok()?)?
+                .checked_add(after.year())?;
+            let date = NaiveDate::from_ymd_opt(year, after.month(), after.day())?;
+            Some(with_time_of(date, after))
+        })
+    }
+}
+
+/// Понедельник недели, в которую попадает `date` (`None` у самой ранней даты chrono).
+fn week_start(date: NaiveDate) -> Option<NaiveDate> {
+    date.checked_sub_days(Days::new(u64::from(date.weekday().num_days_from_monday())))
+}
+
+/// Дата `date` со временем суток из `time_of`.
+fn with_time_of(date: NaiveDate, time_of: DateTime<Utc>) -> DateTime<Utc> {
+    Utc.from_utc_datetime(&date.and_time(time_of.time()))
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, String> {
+    match value.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(format!("Unsupported BYDAY value: {other}")),
+    }
+}
+
+/// `UNTIL` в форме `YYYYMMDD` или `YYYYMMDDTHHMMSSZ` (UTC).
+fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+    let value = value.trim_end_matches('Z');
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        return Ok(Utc.from_utc_datetime(&datetime));
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map(|date| Utc.from_utc_datetime(&date.and_hms_opt(23, 59, 59).unwrap_or_default()))
+        .map_err(|_| format!("Invalid UNTIL: {value}"))
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Доменная модель напоминания по задаче.
+///
+/// Статусы: "pending" → "sent" (или "failed", если попытки доставки исчерпаны).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub remind_at: DateTime<Utc>,
+    /// Канал доставки: "email" или "webhook".
+    pub channel: String,
+    /// Email-адрес или URL вебхука.
+    pub target: String,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Сработавшее напоминание вместе с данными задачи — то, что уходит в канал доставки.
+#[derive(Debug, Clone, FromRow)]
+pub struct DueReminder {
+    #[sqlx(flatten)]
+    pub reminder: Reminder,
+    pub list_id: Uuid,
+    pub task_title: String,
+    pub task_due_at: Option<DateTime<Utc>>,
+}
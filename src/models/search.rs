@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Найденный список или задача.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SearchHit {
+    /// `list` или `task`.
+    pub kind: String,
+    pub id: Uuid,
+    /// Список, в котором лежит задача (для списка — он сам).
+    pub list_id: Uuid,
+    pub list_title: String,
+    pub title: String,
+    /// Статус задачи; у списка — `None`.
+    pub status: Option<String>,
+    /// Список или задача в архиве.
+    pub archived: bool,
+    /// Название, где совпавшие слова обрамлены `HIGHLIGHT_START` и `HIGHLIGHT_STOP`.
+    pub highlight: String,
+    /// Релевантность: чем больше, тем выше в выдаче.
+    pub rank: f64,
+}
+
+/// Маркеры совпадений в `SearchHit::highlight`. Управляющие символы
+/// не встречаются в названиях, поэтому их можно безопасно заменить
+/// разметкой после экранирования текста.
+pub const HIGHLIGHT_START: char = '\u{2}';
+pub const HIGHLIGHT_STOP: char = '\u{3}';
@@ -3,16 +3,58 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use crate::models::task_status::TaskStatus;
+
 /// Доменная модель задачи внутри TODO-листа.
 ///
-/// Статусы: "todo" → "in_progress" → "done".
-/// CHECK-constraint в БД гарантирует валидность значений.
+/// Статусы и допустимые переходы — см. `TaskStatus`.
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Task {
     pub id: Uuid,
     pub list_id: Uuid,
     pub title: String,
-    pub status: String,
+    #[sqlx(try_from = "String")]
+    pub status: TaskStatus,
+    /// Колонка доски (пользовательский статус списка), см. `list_status::column_of`.
+    pub status_id: Option<Uuid>,
+    /// Автоматически завершать задачу, когда отмечены все пункты чек-листа.
+    pub auto_complete: bool,
+    /// Срок выполнения.
+    pub due_at: Option<DateTime<Utc>>,
+    /// Правило повторения RFC 5545 (режим "schedule").
+    pub rrule: Option<String>,
+    /// "schedule" | "after_completion" | None (задача не повторяется).
+    pub recurrence_mode: Option<String>,
+    /// Через сколько дней после выполнения создать повтор (режим "after_completion").
+    pub recurrence_interval_days: Option<i32>,
+    /// Серия повторений, к которой относится задача.
+    pub series_id: Option<Uuid>,
+    /// Исполнитель задачи (может быть не владельцем списка).
+    pub assignee_id: Option<Uuid>,
+    /// Ранг для ручной сортировки внутри списка (см. models::rank).
+    pub position: String,
+    /// Оценка трудоёмкости в минутах.
+    pub estimate_minutes: Option<i32>,
+    /// Когда задачу впервые взяли в работу (`in_progress`).
+    pub started_at: Option<DateTime<Utc>>,
+    /// Когда задача перешла в `done`; `None`, пока она не выполнена.
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Версия строки (ETag); увеличивается при каждом изменении.
+    pub version: i32,
+    /// Когда задача отправлена в архив (правилом списка); `None` — не в архиве.
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Когда задача попала в корзину (сама или вместе со списком); `None` — не удалена.
+    pub deleted_at: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
+
+/// Запись о выполнении одного повторения серии.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TaskCompletion {
+    pub id: Uuid,
+    pub series_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub completed_at: DateTime<Utc>,
+}
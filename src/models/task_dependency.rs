@@ -0,0 +1,12 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Зависимость задачи вместе с текущим состоянием блокирующей задачи.
+/// Используется для вычисления флага `blocked` в `TaskResponse`.
+#[derive(Debug, Clone, FromRow)]
+pub struct DependencyState {
+    pub task_id: Uuid,
+    pub depends_on_id: Uuid,
+    /// Выполнена ли блокирующая задача (`status = 'done'`).
+    pub done: bool,
+}
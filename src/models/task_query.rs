@@ -0,0 +1,527 @@
+//! Язык фильтрации и сортировки задач (`GET /lists/{list_id}/tasks`).
+//!
+//! Фильтр — условия через запятую, объединённые по «И»:
+//! `поле:оператор:значение`, например
+//! `status:in:todo|in_progress,due_at:lt:2026-04-01,title:contains:отчёт`.
+//! Значения `in` разделяются `|`; `\` экранирует `,`, `|` и сам `\`.
+//! Даты — RFC 3339, `YYYY-MM-DD` или относительно текущего момента:
+//! `now`, `now-7d`, `now-12h` (единицы `m`, `h`, `d`, `w`).
+//!
+//! Сортировка — поля через запятую, `-` перед полем — по убыванию:
+//! `-due_at,title`. Пустые значения (`null`) при сортировке по
+//! возрастанию идут в конце, по убыванию — в начале.
+//!
+//! Строки разбираются в типизированное дерево (`Filter`, `Sort`);
+//! параметризованный SQL из него собирает `task_repo`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
+use uuid::Uuid;
+
+use crate::models::task::Task;
+use crate::models::task_status::TaskStatus;
+
+/// Ошибка разбора фильтра, сортировки или курсора.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct InvalidTaskQuery(pub String);
+
+/// Поле задачи, доступное в фильтре и (или) сортировке.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Title,
+    Status,
+    ListId,
+    StatusId,
+    AssigneeId,
+    AutoComplete,
+    DueAt,
+    StartedAt,
+    CompletedAt,
+    CreatedAt,
+    UpdatedAt,
+    EstimateMinutes,
+    Position,
+}
+
+/// Тип значения поля.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Text,
+    Status,
+    Uuid,
+    Bool,
+    DateTime,
+    Int,
+}
+
+impl Field {
+    /// Имя поля в API.
+    pub fn name(self) -> &'static str {
+        match self {
+            Field::Title => "title",
+            Field::Status => "status",
+            Field::ListId => "list_id",
+            Field::StatusId => "status_id",
+            Field::AssigneeId => "assignee_id",
+            Field::AutoComplete => "auto_complete",
+            Field::DueAt => "due_at",
+            Field::StartedAt => "started_at",
+            Field::CompletedAt => "completed_at",
+            Field::CreatedAt => "created_at",
+            Field::UpdatedAt => "updated_at",
+            Field::EstimateMinutes => "estimate_minutes",
+            Field::Position => "position",
+        }
+    }
+
+    /// Колонка в таблице `tasks`.
+    pub fn column(self) -> &'static str {
+        // Имена полей API совпадают с колонками.
+        self.name()
+    }
+
+    /// Выражение для сортировки. Статусы сортируются по ходу работы
+    /// (`todo` → `in_progress` → `done`), а не по алфавиту.
+    pub fn sort_expr(self) -> &'static str {
+        match self {
+            Field::Status => "(CASE status WHEN 'todo' THEN 0 WHEN 'in_progress' THEN 1 ELSE 2 END)",
+            other => other.column(),
+        }
+    }
+
+    /// Тип значения поля в фильтре.
+    pub fn kind(self) -> Kind {
+        match self {
+            Field::Title | Field::Position => Kind::Text,
+            Field::Status => Kind::Status,
+            Field::ListId | Field::StatusId | Field::AssigneeId => Kind::Uuid,
+            Field::AutoComplete => Kind::Bool,
+            Field::DueAt | Field::StartedAt | Field::CompletedAt | Field::CreatedAt | Field::UpdatedAt => {
+                Kind::DateTime
+            }
+            Field::EstimateMinutes => Kind::Int,
+        }
+    }
+
+    /// Тип значения `sort_expr` (для ключа страницы).
+    fn sort_kind(self) -> Kind {
+        match self {
+            Field::Status => Kind::Int,
+            other => other.kind(),
+        }
+    }
+
+    /// Может ли поле быть пустым.
+    pub fn nullable(self) -> bool {
+        matches!(
+            self,
+            Field::StatusId
+                | Field::AssigneeId
+                | Field::DueAt
+                | Field::StartedAt
+                | Field::CompletedAt
+                | Field::EstimateMinutes
+        )
+    }
+
+    fn filterable(self) -> bool {
+        self != Field::Position
+    }
+
+    fn sortable(self) -> bool {
+        !matches!(self, Field::ListId | Field::StatusId | Field::AssigneeId | Field::AutoComplete)
+    }
+
+    /// Значение `sort_expr` для задачи.
+    fn sort_value(self, task: &Task) -> Option<Value> {
+        match self {
+            Field::Title => Some(Value::Text(task.title.clone())),
+            Field::Status => Some(Value::Int(match task.status {
+                TaskStatus::Todo => 0,
+                TaskStatus::InProgress => 1,
+                TaskStatus::Done => 2,
+            })),
+            Field::ListId => Some(Value::Uuid(task.list_id)),
+            Field::StatusId => task.status_id.map(Value::Uuid),
+            Field::AssigneeId => task.assignee_id.map(Value::Uuid),
+            Field::AutoComplete => Some(Value::Bool(task.auto_complete)),
+            Field::DueAt => task.due_at.map(Value::DateTime),
+            Field::StartedAt => task.started_at.map(Value::DateTime),
+            Field::CompletedAt => task.completed_at.map(Value::DateTime),
+            Field::CreatedAt => task.created_at.map(Value::DateTime),
+            Field::UpdatedAt => task.updated_at.map(Value::DateTime),
+            Field::EstimateMinutes => task.estimate_minutes.map(Value::Int),
+            Field::Position => Some(Value::Text(task.position.clone())),
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = InvalidTaskQuery;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        const FIELDS: &[Field] = &[
+            Field::Title,
+            Field::Status,
+            Field::ListId,
+            Field::StatusId,
+            Field::AssigneeId,
+            Field::AutoComplete,
+            Field::DueAt,
+            Field::StartedAt,
+            Field::CompletedAt,
+            Field::CreatedAt,
+            Field::UpdatedAt,
+            Field::EstimateMinutes,
+            Field::Position,
+        ];
+        FIELDS
+            .iter()
+            .copied()
+            .find(|field| field.name() == value)
+            .ok_or_else(|| InvalidTaskQuery(format!("Unknown field '{}'", value)))
+    }
+}
+
+/// Типизированное значение в условии или ключе страницы.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Text(String),
+    Status(TaskStatus),
+    Uuid(Uuid),
+    Bool(bool),
+    DateTime(DateTime<Utc>),
+    Int(i32),
+}
+
+impl Value {
+    /// Разбирает значение типа `kind`. Даты — RFC 3339, `YYYY-MM-DD`
+    /// (полночь UTC) или `now[±N(m|h|d|w)]` относительно текущего момента.
+    fn parse(kind: Kind, field: &str, raw: &str) -> Result<Value, InvalidTaskQuery> {
+        let invalid = || InvalidTaskQuery(format!("Invalid value '{}' for field '{}'", raw, field));
+        let value = match kind {
+            Kind::Text => Value::Text(raw.to_string()),
+            Kind::Status => Value::Status(raw.parse().map_err(|_| invalid())?),
+            Kind::Uuid => Value::Uuid(raw.parse().map_err(|_| invalid())?),
+            Kind::Bool => Value::Bool(raw.parse().map_err(|_| invalid())?),
+            Kind::Int => Value::Int(raw.parse().map_err(|_| invalid())?),
+            Kind::DateTime if raw.starts_with("now") => {
+                let offset = relative_offset(&raw["now".len()..]).ok_or_else(invalid)?;
+                Value::DateTime(Utc::now().checked_add_signed(offset).ok_or_else(invalid)?)
+            }
+            Kind::DateTime => Value::DateTime(match DateTime::parse_from_rfc3339(raw) {
+                Ok(at) => at.with_timezone(&Utc),
+                Err(_) => NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+                    .map_err(|_| invalid())?
+                    .and_hms_opt(0, 0, 0)
+                    .expect("midnight is a valid time")
+                    .and_utc(),
+            }),
+        };
+        Ok(value)
+    }
+}
+
+/// Сдвиг относительной даты: пусто, `-7d`, `+12h`, `-30m`, `-2w`.
+fn relative_offset(offset: &str) -> Option<Duration> {
+    if offset.is_empty() {
+        return Some(Duration::zero());
+    }
+
+    let (sign, rest) = if let Some(rest) = offset.strip_prefix('-') {
+        (-1, rest)
+    } else if let Some(rest) = offset.strip_prefix('+') {
+        (1, rest)
+    } else {
+        return None;
+    };
+    let unit = rest.chars().last()?;
+    let amount: i64 = rest[..rest.len() - unit.len_utf8()].parse().ok()?;
+    let amount = amount.checked_mul(sign)?;
+    match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Text(text) => f.write_str(text),
+            Value::Status(status) => f.write_str(status.as_str()),
+            Value::Uuid(id) => write!(f, "{}", id),
+            Value::Bool(flag) => write!(f, "{}", flag),
+            Value::DateTime(at) => f.write_str(&at.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+            Value::Int(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+/// Оператор сравнения.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Eq,
+    Ne,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl Comparison {
+    /// Оператор SQL. `ne` не отбрасывает пустые значения.
+    pub fn sql(self) -> &'static str {
+        match self {
+            Comparison::Eq => "=",
+            Comparison::Ne => "IS DISTINCT FROM",
+            Comparison::Lt => "<",
+            Comparison::Lte => "<=",
+            Comparison::Gt => ">",
+            Comparison::Gte => ">=",
+        }
+    }
+}
+
+/// Одно условие фильтра.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    /// `поле:eq|ne|lt|lte|gt|gte:значение`
+    Compare { field: Field, op: Comparison, value: Value },
+    /// `поле:in:a|b|c`
+    In { field: Field, values: Vec<Value> },
+    /// `поле:contains:текст` — подстрока без учёта регистра.
+    Contains { field: Field, text: String },
+    /// `поле:null:true|false`
+    Null { field: Field, is_null: bool },
+}
+
+/// Фильтр: все условия должны выполняться.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Filter {
+    pub conditions: Vec<Condition>,
+}
+
+impl FromStr for Filter {
+    type Err = InvalidTaskQuery;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let conditions = split_escaped(input, ',')
+            .into_iter()
+            .filter(|term| !term.is_empty())
+            .map(parse_condition)
+            .collect::<Result<_, _>>()?;
+        Ok(Filter { conditions })
+    }
+}
+
+fn parse_condition(term: &str) -> Result<Condition, InvalidTaskQuery> {
+    let mut parts = term.splitn(3, ':');
+    let (Some(field), Some(op), Some(raw)) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(InvalidTaskQuery(format!(
+            "Filter condition '{}' must look like field:operator:value",
+            term
+        )));
+    };
+
+    let field: Field = field.parse()?;
+    if !field.filterable() {
+        return Err(InvalidTaskQuery(format!("Field '{}' cannot be filtered", field.name())));
+    }
+    let kind = field.kind();
+    let unsupported = || {
+        InvalidTaskQuery(format!(
+            "Operator '{}' is not supported for field '{}'",
+            op,
+            field.name()
+        ))
+    };
+    let parse = |raw: &str| Value::parse(kind, field.name(), &unescape(raw));
+
+    let comparison = match op {
+        "eq" => Some(Comparison::Eq),
+        "ne" => Some(Comparison::Ne),
+        "lt" => Some(Comparison::Lt),
+        "lte" => Some(Comparison::Lte),
+        "gt" => Some(Comparison::Gt),
+        "gte" => Some(Comparison::Gte),
+        _ => None,
+    };
+    if let Some(op) = comparison {
+        let ordered = !matches!(op, Comparison::Eq | Comparison::Ne);
+        if ordered && !matches!(kind, Kind::DateTime | Kind::Int) {
+            return Err(unsupported());
+        }
+        return Ok(Condition::Compare { field, op, value: parse(raw)? });
+    }
+
+    match op {
+        "in" if !matches!(kind, Kind::Bool | Kind::DateTime) => {
+            let values = split_escaped(raw, '|')
+                .into_iter()
+                .map(parse)
+                .collect::<Result<_, _>>()?;
+            Ok(Condition::In { field, values })
+        }
+        "contains" if kind == Kind::Text => Ok(Condition::Contains { field, text: unescape(raw) }),
+        "null" if field.nullable() => match raw {
+            "true" => Ok(Condition::Null { field, is_null: true }),
+            "false" => Ok(Condition::Null { field, is_null: false }),
+            _ => Err(InvalidTaskQuery(format!("Invalid value '{}' for operator 'null'", raw))),
+        },
+        "in" | "contains" | "null" => Err(unsupported()),
+        _ => Err(InvalidTaskQuery(format!("Unknown operator '{}'", op))),
+    }
+}
+
+/// Делит строку по `separator`, пропуская экранированные (`\,`) разделители.
+/// Экранирование сохраняется — его снимает `unescape`.
+fn split_escaped(input: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (index, ch) in input.char_indices() {
+        match ch {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if ch == separator => {
+                parts.push(&input[start..index]);
+                start = index + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+/// Снимает экранирование: `\x` → `x`.
+fn unescape(raw: &str) -> String {
+    let mut result = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => result.extend(chars.next()),
+            other => result.push(other),
+        }
+    }
+    result
+}
+
+/// Ключ сортировки.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: Field,
+    pub descending: bool,
+}
+
+/// Сортировка: ключи по порядку, последний неявный ключ — ID задачи.
+/// По умолчанию — ручной порядок (`position`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort {
+    pub keys: Vec<SortKey>,
+}
+
+impl Default for Sort {
+    fn default() -> Self {
+        Sort {
+            keys: vec![SortKey { field: Field::Position, descending: false }],
+        }
+    }
+}
+
+impl FromStr for Sort {
+    type Err = InvalidTaskQuery;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut keys: Vec<SortKey> = Vec::new();
+        for part in input.split(',').filter(|part| !part.is_empty()) {
+            let (name, descending) = match part.strip_prefix('-') {
+                Some(name) => (name, true),
+                None => (part, false),
+            };
+            let field: Field = name.parse()?;
+            if !field.sortable() {
+                return Err(InvalidTaskQuery(format!("Field '{}' cannot be sorted", name)));
+            }
+            if keys.iter().any(|key| key.field == field) {
+                return Err(InvalidTaskQuery(format!("Field '{}' is sorted twice", name)));
+            }
+            keys.push(SortKey { field, descending });
+        }
+
+        if keys.is_empty() {
+            return Ok(Sort::default());
+        }
+        Ok(Sort { keys })
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, key) in self.keys.iter().enumerate() {
+            if index > 0 {
+                f.write_str(",")?;
+            }
+            if key.descending {
+                f.write_str("-")?;
+            }
+            f.write_str(key.field.name())?;
+        }
+        Ok(())
+    }
+}
+
+/// Место в сортировке, после которого начинается страница:
+/// значения ключей сортировки и ID последней задачи предыдущей страницы.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyset {
+    pub values: Vec<Option<Value>>,
+    pub id: Uuid,
+}
+
+impl Sort {
+    /// Ключ страницы, заканчивающейся задачей `task`.
+    pub fn keyset_of(&self, task: &Task) -> Keyset {
+        Keyset {
+            values: self.keys.iter().map(|key| key.field.sort_value(task)).collect(),
+            id: task.id,
+        }
+    }
+
+    /// Восстанавливает ключ страницы из текстовых значений (из курсора).
+    pub fn keyset(&self, values: &[Option<String>], id: Uuid) -> Result<Keyset, InvalidTaskQuery> {
+        if values.len() != self.keys.len() {
+            return Err(InvalidTaskQuery("Cursor does not match sort".to_string()));
+        }
+
+        let values = self
+            .keys
+            .iter()
+            .zip(values)
+            .map(|(key, raw)| {
+                raw.as_deref()
+                    .map(|raw| Value::parse(key.field.sort_kind(), key.field.name(), raw))
+                    .transpose()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Keyset { values, id })
+    }
+}
+
+/// Полный запрос к задачам списка.
+#[derive(Debug, Clone, Default)]
+pub struct TaskQuery {
+    /// Только задачи с этой меткой.
+    pub label_id: Option<Uuid>,
+    /// Включать архивные задачи.
+    pub include_archived: bool,
+    pub filter: Filter,
+    pub sort: Sort,
+}
+
@@ -0,0 +1,72 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Статус задачи.
+///
+/// Допустимые переходы: `todo ⇄ in_progress`, `todo → done`, `in_progress → done`.
+/// Из `done` статус меняется только явным переоткрытием задачи (reopen → `todo`).
+/// В БД хранится строкой; CHECK-constraint дублирует набор значений.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskStatus {
+    Todo,
+    InProgress,
+    Done,
+}
+
+/// Строка, не являющаяся статусом задачи.
+#[derive(Debug, thiserror::Error)]
+#[error("Unknown status '{0}': expected 'todo', 'in_progress' or 'done'")]
+pub struct UnknownTaskStatus(pub String);
+
+impl TaskStatus {
+    /// Значение в БД и в API.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskStatus::Todo => "todo",
+            TaskStatus::InProgress => "in_progress",
+            TaskStatus::Done => "done",
+        }
+    }
+
+    /// Можно ли обычной сменой статуса перейти из `self` в `next`.
+    /// Переход в тот же статус разрешён всегда.
+    pub fn can_transition_to(self, next: TaskStatus) -> bool {
+        self == next || self != TaskStatus::Done
+    }
+
+    /// `true` для статусов, в которых над задачей уже работают.
+    pub fn starts_work(self) -> bool {
+        matches!(self, TaskStatus::InProgress | TaskStatus::Done)
+    }
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TaskStatus {
+    type Err = UnknownTaskStatus;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "todo" => Ok(TaskStatus::Todo),
+            "in_progress" => Ok(TaskStatus::InProgress),
+            "done" => Ok(TaskStatus::Done),
+            other => Err(UnknownTaskStatus(other.to_string())),
+        }
+    }
+}
+
+/// Для `#[sqlx(try_from = "String")]` в модели Task.
+impl TryFrom<String> for TaskStatus {
+    type Error = UnknownTaskStatus;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
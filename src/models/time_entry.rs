@@ -0,0 +1,40 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Запись учёта времени по задаче: отрезок работы одного пользователя.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// `None` — таймер ещё идёт.
+    pub ended_at: Option<DateTime<Utc>>,
+    pub note: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl TimeEntry {
+    /// Длительность в секундах; для идущего таймера — до `now`.
+    pub fn duration_seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.ended_at.unwrap_or(now) - self.started_at).num_seconds().max(0)
+    }
+}
+
+/// Суммарное время по одной задаче (идущие таймеры — до текущего момента).
+#[derive(Debug, Clone, FromRow)]
+pub struct TrackedTime {
+    pub task_id: Uuid,
+    pub seconds: i64,
+}
+
+/// Строка отчёта: время по списку за один день (UTC).
+#[derive(Debug, Clone, FromRow)]
+pub struct TimeReportRow {
+    pub list_id: Uuid,
+    pub list_title: String,
+    pub day: NaiveDate,
+    pub seconds: i64,
+}
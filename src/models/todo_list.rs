@@ -9,6 +9,16 @@ pub struct TodoList {
     pub id: Uuid,
     pub user_id: Uuid,
     pub title: String,
+    /// Ранг для ручной сортировки (см. models::rank).
+    pub position: String,
+    /// Версия строки (ETag); увеличивается при каждом изменении.
+    pub version: i32,
+    /// Когда список отправлен в архив; `None` — список активен.
+    pub archived_at: Option<DateTime<Utc>>,
+    /// Через сколько дней после `done` задачи архивируются автоматически (`None` — никогда).
+    pub auto_archive_days: Option<i32>,
+    /// Когда список попал в корзину; `None` — список не удалён.
+    pub deleted_at: Option<DateTime<Utc>>,
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
 }
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::models::task_status::TaskStatus;
+
+/// Удалённый список в корзине.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TrashedList {
+    pub id: Uuid,
+    pub title: String,
+    pub deleted_at: DateTime<Utc>,
+    /// Сколько задач удалено вместе со списком (и вернётся при восстановлении).
+    pub task_count: i64,
+}
+
+/// Задача, удалённая отдельно от своего списка.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TrashedTask {
+    pub id: Uuid,
+    pub list_id: Uuid,
+    pub list_title: String,
+    /// Список тоже в корзине — задачу можно вернуть только после него.
+    pub list_deleted: bool,
+    pub title: String,
+    #[sqlx(try_from = "String")]
+    pub status: TaskStatus,
+    pub deleted_at: DateTime<Utc>,
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Сохранённое представление: именованный фильтр и сортировка задач
+/// по всем спискам пользователя (язык запросов — см. `models::task_query`).
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SavedView {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub filter: String,
+    pub sort: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Встроенное представление: есть у каждого пользователя, не меняется.
+#[derive(Debug, Clone, Copy)]
+pub struct BuiltinView {
+    /// Идентификатор в API (`/views/open/tasks`).
+    pub slug: &'static str,
+    pub name: &'static str,
+    pub filter: &'static str,
+    pub sort: &'static str,
+}
+
+/// Встроенные представления в порядке показа.
+pub const BUILTIN_VIEWS: &[BuiltinView] = &[
+    BuiltinView {
+        slug: "open",
+        name: "Все открытые",
+        filter: "status:in:todo|in_progress",
+        sort: "due_at,created_at",
+    },
+    BuiltinView {
+        slug: "in_progress",
+        name: "В работе",
+        filter: "status:eq:in_progress",
+        sort: "-started_at",
+    },
+    BuiltinView {
+        slug: "recently_completed",
+        name: "Недавно выполненные",
+        filter: "status:eq:done,completed_at:gte:now-7d",
+        sort: "-completed_at",
+    },
+    BuiltinView {
+        slug: "recently_created",
+        name: "Недавно созданные",
+        filter: "created_at:gte:now-7d",
+        sort: "-created_at",
+    },
+];
+
+/// Встроенное представление по идентификатору.
+pub fn builtin(slug: &str) -> Option<&'static BuiltinView> {
+    BUILTIN_VIEWS.iter().find(|view| view.slug == slug)
+}
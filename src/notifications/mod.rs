@@ -0,0 +1,86 @@
+//! Доставка уведомлений (напоминаний) по внешним каналам.
+//!
+//! Каждый канал реализует трейт [`Notifier`]; [`Dispatcher`] выбирает
+//! реализацию по имени канала, сохранённому в напоминании.
+
+pub mod email;
+pub mod webhook;
+
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Содержимое уведомления — одинаковое для всех каналов.
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub reminder_id: Uuid,
+    pub task_id: Uuid,
+    pub list_id: Uuid,
+    pub task_title: String,
+    pub task_due_at: Option<DateTime<Utc>>,
+    pub remind_at: DateTime<Utc>,
+}
+
+/// Канал доставки уведомлений.
+#[async_trait::async_trait]
+pub trait Notifier: Send + Sync {
+    /// Отправляет уведомление получателю `target` (email, URL и т.п.).
+    /// Ошибка — человекочитаемый текст, он сохраняется в `last_error`.
+    async fn send(&self, target: &str, notification: &Notification) -> Result<(), String>;
+}
+
+/// Реестр каналов доставки: имя канала → реализация.
+#[derive(Clone, Default)]
+pub struct Dispatcher {
+    channels: HashMap<String, Arc<dyn Notifier>>,
+}
+
+impl Dispatcher {
+    /// Пустой реестр без каналов.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Регистрирует (или заменяет) канал.
+    pub fn with_channel(mut self, name: &str, notifier: Arc<dyn Notifier>) -> Self {
+        self.channels.insert(name.to_string(), notifier);
+        self
+    }
+
+    /// Собирает каналы из переменных окружения.
+    ///
+    /// - `webhook` доступен всегда;
+    /// - `email` — только если заданы `SMTP_URL` и `SMTP_FROM`.
+    pub fn from_env() -> Self {
+        let mut dispatcher = Self::new().with_channel("webhook", Arc::new(webhook::WebhookNotifier::new()));
+
+        match (env::var("SMTP_URL"), env::var("SMTP_FROM")) {
+            (Ok(url), Ok(from)) => match email::EmailNotifier::new(&url, &from) {
+                Ok(notifier) => dispatcher = dispatcher.with_channel("email", Arc::new(notifier)),
+                Err(err) => tracing::error!("Email notifications disabled: {}", err),
+            },
+            _ => tracing::warn!("SMTP_URL/SMTP_FROM not set — email reminders will fail"),
+        }
+
+        dispatcher
+    }
+
+    /// Отправляет уведомление через канал `channel`.
+    pub async fn dispatch(
+        &self,
+        channel: &str,
+        target: &str,
+        notification: &Notification,
+    ) -> Result<(), String> {
+        let notifier = self
+            .channels
+            .get(channel)
+            .ok_or_else(|| format!("Channel '{channel}' is not configured"))?;
+
+        notifier.send(target, notification).await
+    }
+}
@@ -0,0 +1,157 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::Url;
+
+use super::{Notification, Notifier};
+
+/// Канал "webhook": POST с JSON-телом уведомления на URL получателя.
+///
+/// URL задаёт пользователь, поэтому запросы уходят только на публичные
+/// адреса: имя хоста резолвится через `PublicResolver`, IP-литералы
+/// проверяются перед отправкой, редиректы не выполняются.
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+}
+
+impl WebhookNotifier {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicResolver))
+            .build()
+            .unwrap_or_default();
+
+        Self { client }
+    }
+}
+
+impl Default for WebhookNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for WebhookNotifier {
+    async fn send(&self, target: &str, notification: &Notification) -> Result<(), String> {
+        // Адрес проверялся при создании напоминания, но DNS мог измениться.
+        let url = parse_url(target)?;
+        if let Some(ip) = literal_ip(&url) {
+            ensure_public(ip)?;
+        }
+
+        self.client
+            .post(url)
+            .json(notification)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|err| format!("Webhook delivery failed: {err}"))?;
+
+        Ok(())
+    }
+}
+
+/// Проверяет, что на URL можно слать вебхуки: схема http(s), хост
+/// резолвится и все его адреса публичные.
+pub async fn check_url(target: &str) -> Result<(), String> {
+    let url = parse_url(target)?;
+    if let Some(ip) = literal_ip(&url) {
+        return ensure_public(ip);
+    }
+
+    let host = url.host_str().ok_or_else(|| "URL must contain a host".to_string())?;
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| format!("Cannot resolve host '{host}'"))?
+        .collect();
+    if addrs.is_empty() {
+        return Err(format!("Cannot resolve host '{host}'"));
+    }
+
+    addrs.into_iter().try_for_each(|addr| ensure_public(addr.ip()))
+}
+
+fn parse_url(target: &str) -> Result<Url, String> {
+    let url = Url::parse(target).map_err(|_| "target must be an http(s) URL".to_string())?;
+    if !matches!(url.scheme(), "http" | "https") || url.host().is_none() {
+        return Err("target must be an http(s) URL".to_string());
+    }
+    Ok(url)
+}
+
+/// IP-адрес, если хост URL задан литералом (`https://10.0.0.1/`, `http://[::1]/`).
+fn literal_ip(url: &Url) -> Option<IpAddr> {
+    let host = url.host_str()?;
+    host.trim_start_matches('[').trim_end_matches(']').parse().ok()
+}
+
+fn ensure_public(ip: IpAddr) -> Result<(), String> {
+    if is_public(ip) {
+        Ok(())
+    } else {
+        Err(format!("Webhook URL points to a non-public address {ip}"))
+    }
+}
+
+/// Публичный ли адрес: не loopback, не частная сеть, не link-local
+/// (в т.ч. 169.254.169.254 — метаданные облака) и т.п.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_public_v4(v4),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        || a == 0
+        // 100.64.0.0/10 — CGNAT
+        || (a == 100 && (64..128).contains(&b))
+        // 198.18.0.0/15 — сети для бенчмарков
+        || (a == 198 && (b == 18 || b == 19)))
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // fc00::/7 — unique local
+        || (first & 0xfe00) == 0xfc00
+        // fe80::/10 — link-local
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// DNS-резолвер для вебхуков: отказывает, если имя указывает хотя бы на один
+/// непубличный адрес. Проверка при соединении закрывает DNS rebinding —
+/// между валидацией URL и отправкой запись могла смениться.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+            if let Some(addr) = addrs.iter().find(|addr| !is_public(addr.ip())) {
+                return Err(format!("{} resolves to a non-public address {}", name.as_str(), addr.ip()).into());
+            }
+            let addrs: Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
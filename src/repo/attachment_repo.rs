@@ -0,0 +1,128 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::attachment::Attachment;
+
+/// Данные нового вложения (содержимое уже лежит в хранилище).
+pub struct NewAttachment<'a> {
+    pub task_id: Uuid,
+    pub uploader_id: Uuid,
+    pub filename: &'a str,
+    pub content_type: &'a str,
+    pub size_bytes: i64,
+    pub storage_key: &'a str,
+}
+
+/// Сохраняет метаданные вложения, если после этого пользователь
+/// не выйдет за квоту `quota_bytes`. Возвращает `None`, если квота превышена.
+///
+/// Подсчёт и вставка идут под advisory-lock на пользователя — параллельные
+/// загрузки не могут вместе превысить квоту.
+pub async fn create_within_quota(
+    pool: &PgPool,
+    new: &NewAttachment<'_>,
+    quota_bytes: i64,
+) -> Result<Option<Attachment>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('attachments:' || $1::text))")
+        .bind(new.uploader_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let used: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(size_bytes), 0)::BIGINT FROM attachments WHERE uploader_id = $1",
+    )
+    .bind(new.uploader_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if used + new.size_bytes > quota_bytes {
+        return Ok(None);
+    }
+
+    let attachment = sqlx::query_as::<_, Attachment>(
+        "INSERT INTO attachments (task_id, uploader_id, filename, content_type, size_bytes, storage_key) \
+         VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+    )
+    .bind(new.task_id)
+    .bind(new.uploader_id)
+    .bind(new.filename)
+    .bind(new.content_type)
+    .bind(new.size_bytes)
+    .bind(new.storage_key)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Some(attachment))
+}
+
+/// Возвращает все вложения задачи (в порядке загрузки).
+pub async fn find_all_by_task(pool: &PgPool, task_id: Uuid) -> Result<Vec<Attachment>, sqlx::Error> {
+    let attachments = sqlx::query_as::<_, Attachment>(
+        "SELECT * FROM attachments WHERE task_id = $1 ORDER BY created_at ASC",
+    )
+    .bind(task_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(attachments)
+}
+
+/// Возвращает вложение по ID внутри задачи.
+pub async fn find_by_id(
+    pool: &PgPool,
+    task_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<Option<Attachment>, sqlx::Error> {
+    let attachment = sqlx::query_as::<_, Attachment>(
+        "SELECT * FROM attachments WHERE id = $1 AND task_id = $2",
+    )
+    .bind(attachment_id)
+    .bind(task_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(attachment)
+}
+
+/// Сколько байт занимают вложения, загруженные пользователем.
+pub async fn used_bytes(pool: &PgPool, uploader_id: Uuid) -> Result<i64, sqlx::Error> {
+    let used = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(size_bytes), 0)::BIGINT FROM attachments WHERE uploader_id = $1",
+    )
+    .bind(uploader_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(used)
+}
+
+/// Удаляет метаданные вложения.
+pub async fn delete(pool: &PgPool, attachment_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM attachments WHERE id = $1")
+        .bind(attachment_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Ключи содержимого вложений задач, которые очистка корзины удалит
+/// окончательно: задача или её список в корзине дольше `before`.
+pub async fn find_keys_in_trash(db: impl PgExecutor<'_>, before: DateTime<Utc>) -> Result<Vec<String>, sqlx::Error> {
+    let keys = sqlx::query_scalar(
+        "SELECT a.storage_key FROM attachments a \
+         JOIN tasks t ON t.id = a.task_id \
+         JOIN todo_lists l ON l.id = t.list_id \
+         WHERE t.deleted_at < $1 OR l.deleted_at < $1",
+    )
+    .bind(before)
+    .fetch_all(db)
+    .await?;
+
+    Ok(keys)
+}
@@ -0,0 +1,95 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::checklist_item::{ChecklistItem, ChecklistProgress};
+
+/// Добавляет пункт в чек-лист задачи. Все изменения чек-листа увеличивают
+/// версию задачи: прогресс входит в её ETag.
+pub async fn create(pool: &PgPool, task_id: Uuid, title: &str) -> Result<ChecklistItem, sqlx::Error> {
+    let item = sqlx::query_as::<_, ChecklistItem>(
+        "WITH item AS ( \
+             INSERT INTO checklist_items (task_id, title) VALUES ($1, $2) RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 WHERE id = $1 \
+         ) \
+         SELECT * FROM item",
+    )
+    .bind(task_id)
+    .bind(title)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(item)
+}
+
+/// Возвращает все пункты чек-листа задачи (в порядке добавления).
+pub async fn find_all_by_task(pool: &PgPool, task_id: Uuid) -> Result<Vec<ChecklistItem>, sqlx::Error> {
+    let items = sqlx::query_as::<_, ChecklistItem>(
+        "SELECT * FROM checklist_items WHERE task_id = $1 ORDER BY created_at ASC",
+    )
+    .bind(task_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(items)
+}
+
+/// Обновляет пункт (title + done). Возвращает обновлённый пункт.
+pub async fn update(
+    pool: &PgPool,
+    task_id: Uuid,
+    item_id: Uuid,
+    title: &str,
+    done: bool,
+) -> Result<Option<ChecklistItem>, sqlx::Error> {
+    let item = sqlx::query_as::<_, ChecklistItem>(
+        "WITH item AS ( \
+             UPDATE checklist_items SET title = $1, done = $2, updated_at = now() \
+             WHERE id = $3 AND task_id = $4 RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM item) \
+         ) \
+         SELECT * FROM item",
+    )
+    .bind(title)
+    .bind(done)
+    .bind(item_id)
+    .bind(task_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(item)
+}
+
+/// Удаляет пункт чек-листа.
+pub async fn delete(pool: &PgPool, task_id: Uuid, item_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "WITH deleted AS ( \
+             DELETE FROM checklist_items WHERE id = $1 AND task_id = $2 RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM deleted)",
+    )
+    .bind(item_id)
+    .bind(task_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Считает прогресс чек-листов сразу для нескольких задач.
+/// Задачи без пунктов в результат не попадают.
+pub async fn progress_by_tasks(
+    db: impl PgExecutor<'_>,
+    task_ids: &[Uuid],
+) -> Result<Vec<ChecklistProgress>, sqlx::Error> {
+    let progress = sqlx::query_as::<_, ChecklistProgress>(
+        "SELECT task_id, COUNT(*) FILTER (WHERE done) AS done, COUNT(*) AS total \
+         FROM checklist_items WHERE task_id = ANY($1) GROUP BY task_id",
+    )
+    .bind(task_ids)
+    .fetch_all(db)
+    .await?;
+
+    Ok(progress)
+}
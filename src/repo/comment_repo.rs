@@ -0,0 +1,76 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::comment::Comment;
+
+/// Добавляет комментарий к задаче.
+pub async fn create(pool: &PgPool, task_id: Uuid, author_id: Uuid, body: &str) -> Result<Comment, sqlx::Error> {
+    let comment = sqlx::query_as::<_, Comment>(
+        "WITH c AS ( \
+             INSERT INTO task_comments (task_id, author_id, body) VALUES ($1, $2, $3) RETURNING * \
+         ) \
+         SELECT c.*, u.email AS author_email FROM c JOIN users u ON u.id = c.author_id",
+    )
+    .bind(task_id)
+    .bind(author_id)
+    .bind(body)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(comment)
+}
+
+/// Возвращает все комментарии задачи (старые сверху).
+pub async fn find_all_by_task(pool: &PgPool, task_id: Uuid) -> Result<Vec<Comment>, sqlx::Error> {
+    let comments = sqlx::query_as::<_, Comment>(
+        "SELECT c.*, u.email AS author_email FROM task_comments c \
+         JOIN users u ON u.id = c.author_id \
+         WHERE c.task_id = $1 ORDER BY c.created_at ASC",
+    )
+    .bind(task_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(comments)
+}
+
+/// Возвращает комментарий по ID внутри задачи.
+pub async fn find_by_id(pool: &PgPool, task_id: Uuid, comment_id: Uuid) -> Result<Option<Comment>, sqlx::Error> {
+    let comment = sqlx::query_as::<_, Comment>(
+        "SELECT c.*, u.email AS author_email FROM task_comments c \
+         JOIN users u ON u.id = c.author_id \
+         WHERE c.id = $1 AND c.task_id = $2",
+    )
+    .bind(comment_id)
+    .bind(task_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(comment)
+}
+
+/// Меняет текст комментария и проставляет `edited_at`.
+pub async fn update(pool: &PgPool, comment_id: Uuid, body: &str) -> Result<Comment, sqlx::Error> {
+    let comment = sqlx::query_as::<_, Comment>(
+        "WITH c AS ( \
+             UPDATE task_comments SET body = $1, edited_at = now() WHERE id = $2 RETURNING * \
+         ) \
+         SELECT c.*, u.email AS author_email FROM c JOIN users u ON u.id = c.author_id",
+    )
+    .bind(body)
+    .bind(comment_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(comment)
+}
+
+/// Удаляет комментарий.
+pub async fn delete(pool: &PgPool, comment_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM task_comments WHERE id = $1")
+        .bind(comment_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -0,0 +1,112 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::task_dependency::DependencyState;
+
+/// Результат попытки добавить зависимость.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AddOutcome {
+    Added,
+    /// Зависимость замкнула бы цикл (A → … → A).
+    Cycle,
+}
+
+/// Добавляет зависимость `task_id → depends_on_id` с проверкой на цикл.
+///
+/// Проверка и вставка выполняются в одной транзакции под advisory-lock,
+/// чтобы два параллельных запроса не создали цикл "крест-накрест".
+pub async fn add(
+    pool: &PgPool,
+    task_id: Uuid,
+    depends_on_id: Uuid,
+) -> Result<AddOutcome, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('task_dependencies'))")
+        .execute(&mut *tx)
+        .await?;
+
+    // Цикл возникнет, если depends_on_id уже (транзитивно) зависит от task_id.
+    let creates_cycle: bool = sqlx::query_scalar(
+        "WITH RECURSIVE chain AS ( \
+             SELECT depends_on_id FROM task_dependencies WHERE task_id = $1 \
+             UNION \
+             SELECT d.depends_on_id FROM task_dependencies d \
+             JOIN chain c ON d.task_id = c.depends_on_id \
+         ) \
+         SELECT EXISTS (SELECT 1 FROM chain WHERE depends_on_id = $2)",
+    )
+    .bind(depends_on_id)
+    .bind(task_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if creates_cycle {
+        return Ok(AddOutcome::Cycle);
+    }
+
+    sqlx::query(
+        "WITH added AS ( \
+             INSERT INTO task_dependencies (task_id, depends_on_id) VALUES ($1, $2) \
+             ON CONFLICT DO NOTHING RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM added)",
+    )
+    .bind(task_id)
+    .bind(depends_on_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(AddOutcome::Added)
+}
+
+/// Удаляет зависимость (и увеличивает версию задачи). Возвращает `false`, если её не было.
+pub async fn remove(pool: &PgPool, task_id: Uuid, depends_on_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "WITH removed AS ( \
+             DELETE FROM task_dependencies WHERE task_id = $1 AND depends_on_id = $2 RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM removed)",
+    )
+    .bind(task_id)
+    .bind(depends_on_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Возвращает зависимости сразу для нескольких задач вместе с тем,
+/// выполнены ли блокирующие задачи. Блокирующие задачи в корзине не учитываются.
+pub async fn find_by_tasks(
+    pool: &PgPool,
+    task_ids: &[Uuid],
+) -> Result<Vec<DependencyState>, sqlx::Error> {
+    let deps = sqlx::query_as::<_, DependencyState>(
+        "SELECT d.task_id, d.depends_on_id, t.status = 'done' AS done \
+         FROM task_dependencies d JOIN tasks t ON t.id = d.depends_on_id \
+         WHERE d.task_id = ANY($1) AND t.deleted_at IS NULL ORDER BY d.created_at ASC",
+    )
+    .bind(task_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(deps)
+}
+
+/// Есть ли у задачи невыполненные зависимости (задачи в корзине не блокируют).
+pub async fn is_blocked(db: impl PgExecutor<'_>, task_id: Uuid) -> Result<bool, sqlx::Error> {
+    let blocked: bool = sqlx::query_scalar(
+        "SELECT EXISTS ( \
+             SELECT 1 FROM task_dependencies d JOIN tasks t ON t.id = d.depends_on_id \
+             WHERE d.task_id = $1 AND t.status <> 'done' AND t.deleted_at IS NULL \
+         )",
+    )
+    .bind(task_id)
+    .fetch_one(db)
+    .await?;
+
+    Ok(blocked)
+}
@@ -0,0 +1,74 @@
+use serde_json::{Map, Value};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::history_event::{HistoryAction, HistoryEvent};
+
+/// Дописывает событие в журнал. Других операций записи у журнала нет.
+pub async fn record(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    task_id: Option<Uuid>,
+    actor_id: Uuid,
+    action: HistoryAction,
+    changes: Map<String, Value>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO history_events (list_id, task_id, actor_id, action, changes) \
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(list_id)
+    .bind(task_id)
+    .bind(actor_id)
+    .bind(action.as_str())
+    .bind(Value::Object(changes))
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// События списка и всех его задач, от новых к старым.
+/// `before` — вернуть только события с меньшим ID (следующая страница).
+pub async fn find_by_list(
+    pool: &PgPool,
+    list_id: Uuid,
+    before: Option<i64>,
+    limit: i64,
+) -> Result<Vec<HistoryEvent>, sqlx::Error> {
+    let events = sqlx::query_as::<_, HistoryEvent>(
+        "SELECT h.*, u.email AS actor_email FROM history_events h \
+         LEFT JOIN users u ON u.id = h.actor_id \
+         WHERE h.list_id = $1 AND ($2::bigint IS NULL OR h.id < $2) \
+         ORDER BY h.id DESC LIMIT $3",
+    )
+    .bind(list_id)
+    .bind(before)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(events)
+}
+
+/// События задачи (в том числе из списков, где она была раньше), от новых к старым.
+pub async fn find_by_task(
+    pool: &PgPool,
+    task_id: Uuid,
+    before: Option<i64>,
+    limit: i64,
+) -> Result<Vec<HistoryEvent>, sqlx::Error> {
+    let events = sqlx::query_as::<_, HistoryEvent>(
+        "SELECT h.*, u.email AS actor_email FROM history_events h \
+         LEFT JOIN users u ON u.id = h.actor_id \
+         WHERE h.task_id = $1 AND ($2::bigint IS NULL OR h.id < $2) \
+         ORDER BY h.id DESC LIMIT $3",
+    )
+    .bind(task_id)
+    .bind(before)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(events)
+}
@@ -0,0 +1,164 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::label::{Label, TaskLabel};
+
+/// Создаёт новую метку пользователя.
+pub async fn create(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    color: &str,
+) -> Result<Label, sqlx::Error> {
+    let label = sqlx::query_as::<_, Label>(
+        "INSERT INTO labels (user_id, name, color) VALUES ($1, $2, $3) RETURNING *",
+    )
+    .bind(user_id)
+    .bind(name)
+    .bind(color)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(label)
+}
+
+/// Возвращает все метки пользователя (по алфавиту).
+pub async fn find_all_by_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<Label>, sqlx::Error> {
+    let labels = sqlx::query_as::<_, Label>(
+        "SELECT * FROM labels WHERE user_id = $1 ORDER BY name ASC",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(labels)
+}
+
+/// Возвращает одну метку по ID, только если она принадлежит пользователю.
+pub async fn find_by_id(
+    pool: &PgPool,
+    label_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Label>, sqlx::Error> {
+    let label = sqlx::query_as::<_, Label>(
+        "SELECT * FROM labels WHERE id = $1 AND user_id = $2",
+    )
+    .bind(label_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(label)
+}
+
+/// Ищет метку пользователя по имени.
+pub async fn find_by_name(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+) -> Result<Option<Label>, sqlx::Error> {
+    let label = sqlx::query_as::<_, Label>(
+        "SELECT * FROM labels WHERE user_id = $1 AND name = $2",
+    )
+    .bind(user_id)
+    .bind(name)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(label)
+}
+
+/// Обновляет метку (name + color). Возвращает обновлённую метку.
+/// Версии задач с этой меткой увеличиваются: метка входит в их ETag.
+pub async fn update(
+    pool: &PgPool,
+    label_id: Uuid,
+    user_id: Uuid,
+    name: &str,
+    color: &str,
+) -> Result<Option<Label>, sqlx::Error> {
+    let label = sqlx::query_as::<_, Label>(
+        "WITH label AS ( \
+             UPDATE labels SET name = $1, color = $2, updated_at = now() \
+             WHERE id = $3 AND user_id = $4 RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 \
+             WHERE id IN (SELECT tl.task_id FROM task_labels tl JOIN label ON label.id = tl.label_id) \
+         ) \
+         SELECT * FROM label",
+    )
+    .bind(name)
+    .bind(color)
+    .bind(label_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(label)
+}
+
+/// Удаляет метку. Связи с задачами удаляются каскадно, версии задач увеличиваются.
+pub async fn delete(pool: &PgPool, label_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let deleted: i64 = sqlx::query_scalar(
+        "WITH deleted AS ( \
+             DELETE FROM labels WHERE id = $1 AND user_id = $2 RETURNING id \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 \
+             WHERE id IN (SELECT tl.task_id FROM task_labels tl JOIN deleted ON deleted.id = tl.label_id) \
+         ) \
+         SELECT COUNT(*) FROM deleted",
+    )
+    .bind(label_id)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(deleted > 0)
+}
+
+/// Навешивает метку на задачу (и увеличивает её версию). Повторное навешивание — не ошибка.
+pub async fn attach(db: impl PgExecutor<'_>, task_id: Uuid, label_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "WITH attached AS ( \
+             INSERT INTO task_labels (task_id, label_id) VALUES ($1, $2) ON CONFLICT DO NOTHING RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM attached)",
+    )
+    .bind(task_id)
+    .bind(label_id)
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Снимает метку с задачи (и увеличивает её версию).
+/// Возвращает `false`, если метка не была навешена.
+pub async fn detach(pool: &PgPool, task_id: Uuid, label_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "WITH detached AS ( \
+             DELETE FROM task_labels WHERE task_id = $1 AND label_id = $2 RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM detached)",
+    )
+    .bind(task_id)
+    .bind(label_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Возвращает метки сразу для нескольких задач (одним запросом, без N+1).
+pub async fn find_by_tasks(pool: &PgPool, task_ids: &[Uuid]) -> Result<Vec<TaskLabel>, sqlx::Error> {
+    let labels = sqlx::query_as::<_, TaskLabel>(
+        "SELECT tl.task_id, l.* FROM task_labels tl \
+         JOIN labels l ON l.id = tl.label_id \
+         WHERE tl.task_id = ANY($1) ORDER BY l.name ASC",
+    )
+    .bind(task_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(labels)
+}
@@ -1,27 +1,73 @@
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, PgConnection, PgExecutor, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 use crate::models::todo_list::TodoList;
+use crate::models::trash::TrashedList;
+use crate::repo::position_repo::{self, Scope};
+use crate::repo::{status_repo, task_repo};
+
+/// Изменения списка для частичного обновления: `None` — поле не меняется.
+#[derive(Debug, Default)]
+pub struct ListChanges<'a> {
+    pub title: Option<&'a str>,
+    /// `Some(None)` — выключить автоархивацию.
+    pub auto_archive_days: Option<Option<i32>>,
+}
+
+impl ListChanges<'_> {
+    /// `true`, если менять нечего.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none() && self.auto_archive_days.is_none()
+    }
+}
+
+/// Создаёт новый TODO-лист в БД. Новый список встаёт первым
+/// и сразу получает статусы (колонки доски) по умолчанию.
+pub async fn create(conn: &mut PgConnection, user_id: Uuid, title: &str) -> Result<TodoList, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::UserLists(user_id)).await?;
+    let position = position_repo::first_position(&mut tx, Scope::UserLists(user_id)).await?;
 
-/// Создаёт новый TODO-лист в БД.
-pub async fn create(pool: &PgPool, user_id: Uuid, title: &str) -> Result<TodoList, sqlx::Error> {
     let list = sqlx::query_as::<_, TodoList>(
-        "INSERT INTO todo_lists (user_id, title) VALUES ($1, $2) RETURNING *",
+        "INSERT INTO todo_lists (user_id, title, position) VALUES ($1, $2, $3) RETURNING *",
     )
     .bind(user_id)
     .bind(title)
-    .fetch_one(pool)
+    .bind(position)
+    .fetch_one(&mut *tx)
     .await?;
 
+    status_repo::create_defaults(&mut tx, list.id).await?;
+
+    tx.commit().await?;
+
     Ok(list)
 }
 
-/// Возвращает все списки конкретного пользователя.
-pub async fn find_all_by_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<TodoList>, sqlx::Error> {
+/// Возвращает страницу списков пользователя (в порядке, заданном пользователем):
+/// до `limit` списков после ключа `after` — позиции и ID последнего списка
+/// предыдущей страницы. Списки в корзине не возвращаются — ни здесь, ни
+/// в остальных выборках; архивные — только с `include_archived`.
+pub async fn find_page_by_user(
+    pool: &PgPool,
+    user_id: Uuid,
+    include_archived: bool,
+    after: Option<(&str, Uuid)>,
+    limit: i64,
+) -> Result<Vec<TodoList>, sqlx::Error> {
+    let (after_position, after_id) = after.unzip();
     let lists = sqlx::query_as::<_, TodoList>(
-        "SELECT * FROM todo_lists WHERE user_id = $1 ORDER BY created_at DESC",
+        "SELECT * FROM todo_lists WHERE user_id = $1 AND deleted_at IS NULL \
+         AND ($2 OR archived_at IS NULL) \
+         AND ($3::text IS NULL OR (position, id) > ($3, $4)) \
+         ORDER BY position, id LIMIT $5",
     )
     .bind(user_id)
+    .bind(include_archived)
+    .bind(after_position)
+    .bind(after_id)
+    .bind(limit)
     .fetch_all(pool)
     .await?;
 
@@ -30,48 +76,264 @@ pub async fn find_all_by_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<TodoLi
 
 /// Возвращает один список по ID, только если он принадлежит пользователю.
 pub async fn find_by_id(
-    pool: &PgPool,
+    db: impl PgExecutor<'_>,
     list_id: Uuid,
     user_id: Uuid,
 ) -> Result<Option<TodoList>, sqlx::Error> {
     let list = sqlx::query_as::<_, TodoList>(
-        "SELECT * FROM todo_lists WHERE id = $1 AND user_id = $2",
+        "SELECT * FROM todo_lists WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL",
     )
     .bind(list_id)
     .bind(user_id)
-    .fetch_optional(pool)
+    .fetch_optional(db)
     .await?;
 
     Ok(list)
 }
 
+/// Блокирует список пользователя до конца транзакции (`FOR UPDATE`)
+/// и возвращает его текущее состояние.
+pub async fn lock(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<TodoList>, sqlx::Error> {
+    let list = sqlx::query_as::<_, TodoList>(
+        "SELECT * FROM todo_lists WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(list_id)
+    .bind(user_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(list)
+}
+
+/// Неархивные списки пользователя с таким названием (без учёта регистра
+/// и пробелов по краям). Названия не уникальны — списков может быть несколько.
+pub async fn find_by_title(pool: &PgPool, user_id: Uuid, title: &str) -> Result<Vec<TodoList>, sqlx::Error> {
+    let lists = sqlx::query_as::<_, TodoList>(
+        "SELECT * FROM todo_lists WHERE user_id = $1 AND deleted_at IS NULL AND archived_at IS NULL \
+         AND lower(btrim(title)) = lower(btrim($2)) ORDER BY position, id",
+    )
+    .bind(user_id)
+    .bind(title)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(lists)
+}
+
+/// Находится ли список в архиве (`false` — списка нет).
+pub async fn is_archived(pool: &PgPool, list_id: Uuid) -> Result<bool, sqlx::Error> {
+    let archived: Option<bool> = sqlx::query_scalar("SELECT archived_at IS NOT NULL FROM todo_lists WHERE id = $1")
+        .bind(list_id)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(archived.unwrap_or(false))
+}
+
 /// Обновляет название списка. Возвращает обновлённый список.
+/// `expected_version` — обновить, только если версия строки совпадает.
 pub async fn update(
-    pool: &PgPool,
+    db: impl PgExecutor<'_>,
     list_id: Uuid,
     user_id: Uuid,
     title: &str,
+    expected_version: Option<i32>,
 ) -> Result<Option<TodoList>, sqlx::Error> {
     let list = sqlx::query_as::<_, TodoList>(
-        "UPDATE todo_lists SET title = $1, updated_at = now() WHERE id = $2 AND user_id = $3 RETURNING *",
+        "UPDATE todo_lists SET title = $1, version = version + 1, updated_at = now() \
+         WHERE id = $2 AND user_id = $3 AND deleted_at IS NULL AND ($4::int IS NULL OR version = $4) RETURNING *",
     )
     .bind(title)
     .bind(list_id)
     .bind(user_id)
-    .fetch_optional(pool)
+    .bind(expected_version)
+    .fetch_optional(db)
     .await?;
 
     Ok(list)
 }
 
-/// Удаляет список по ID (только если он принадлежит пользователю).
-pub async fn delete(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query("DELETE FROM todo_lists WHERE id = $1 AND user_id = $2")
-        .bind(list_id)
-        .bind(user_id)
-        .execute(pool)
+/// Частично обновляет список: в UPDATE попадают только переданные поля.
+/// Пустой набор изменений ничего не пишет и просто возвращает список.
+pub async fn patch(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    user_id: Uuid,
+    changes: &ListChanges<'_>,
+    expected_version: Option<i32>,
+) -> Result<Option<TodoList>, sqlx::Error> {
+    if changes.is_empty() {
+        return find_by_id(db, list_id, user_id).await;
+    }
+
+    let mut query = QueryBuilder::<Postgres>::new("UPDATE todo_lists SET ");
+    let mut set = query.separated(", ");
+    if let Some(title) = changes.title {
+        set.push("title = ").push_bind_unseparated(title);
+    }
+    if let Some(auto_archive_days) = changes.auto_archive_days {
+        set.push("auto_archive_days = ").push_bind_unseparated(auto_archive_days);
+    }
+    set.push("version = version + 1, updated_at = now()");
+
+    query
+        .push(" WHERE id = ")
+        .push_bind(list_id)
+        .push(" AND user_id = ")
+        .push_bind(user_id)
+        .push(" AND deleted_at IS NULL");
+    if let Some(version) = expected_version {
+        query.push(" AND version = ").push_bind(version);
+    }
+    query.push(" RETURNING *");
+
+    let list = query.build_query_as::<TodoList>().fetch_optional(db).await?;
+
+    Ok(list)
+}
+
+/// Отправляет список в архив (`archived = true`) или возвращает из него.
+/// `None` — списка нет или он уже в нужном состоянии.
+pub async fn set_archived(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    user_id: Uuid,
+    archived: bool,
+) -> Result<Option<TodoList>, sqlx::Error> {
+    let list = sqlx::query_as::<_, TodoList>(
+        "UPDATE todo_lists SET archived_at = CASE WHEN $3 THEN now() END, \
+             version = version + 1, updated_at = now() \
+         WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL AND (archived_at IS NULL) = $3 \
+         RETURNING *",
+    )
+    .bind(list_id)
+    .bind(user_id)
+    .bind(archived)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(list)
+}
+
+/// Переносит список в корзину вместе с его задачами (только если он
+/// принадлежит пользователю; с `expected_version` — только эту версию).
+/// Задачи получают ту же отметку `deleted_at`, что и список, запущенные
+/// по ним таймеры останавливаются.
+pub async fn delete(
+    conn: &mut PgConnection,
+    list_id: Uuid,
+    user_id: Uuid,
+    expected_version: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+
+    let deleted_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "UPDATE todo_lists SET deleted_at = now(), version = version + 1, updated_at = now() \
+         WHERE id = $1 AND user_id = $2 AND deleted_at IS NULL AND ($3::int IS NULL OR version = $3) \
+         RETURNING deleted_at",
+    )
+    .bind(list_id)
+    .bind(user_id)
+    .bind(expected_version)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(deleted_at) = deleted_at else {
+        return Ok(false);
+    };
+
+    let task_ids: Vec<Uuid> = sqlx::query_scalar(
+        "UPDATE tasks SET deleted_at = $2, version = version + 1, updated_at = now() \
+         WHERE list_id = $1 AND deleted_at IS NULL RETURNING id",
+    )
+    .bind(list_id)
+    .bind(deleted_at)
+    .fetch_all(&mut *tx)
+    .await?;
+    task_repo::touch_dependents(&mut *tx, &task_ids).await?;
+
+    sqlx::query(
+        "UPDATE time_entries SET ended_at = now() \
+         WHERE ended_at IS NULL AND task_id IN (SELECT id FROM tasks WHERE list_id = $1)",
+    )
+    .bind(list_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+/// Списки пользователя в корзине (сначала недавно удалённые).
+pub async fn find_deleted_by_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<TrashedList>, sqlx::Error> {
+    let lists = sqlx::query_as::<_, TrashedList>(
+        "SELECT l.id, l.title, l.deleted_at, \
+             (SELECT COUNT(*) FROM tasks t WHERE t.list_id = l.id AND t.deleted_at = l.deleted_at) AS task_count \
+         FROM todo_lists l \
+         WHERE l.user_id = $1 AND l.deleted_at IS NOT NULL \
+         ORDER BY l.deleted_at DESC, l.id",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(lists)
+}
+
+/// Возвращает список из корзины вместе с задачами, удалёнными вместе с ним.
+/// Задачи, удалённые раньше списка, остаются в корзине.
+/// `None` — такого списка у пользователя в корзине нет.
+pub async fn restore(conn: &mut PgConnection, list_id: Uuid, user_id: Uuid) -> Result<Option<TodoList>, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+
+    let deleted_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT deleted_at FROM todo_lists \
+         WHERE id = $1 AND user_id = $2 AND deleted_at IS NOT NULL FOR UPDATE",
+    )
+    .bind(list_id)
+    .bind(user_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(deleted_at) = deleted_at else {
+        return Ok(None);
+    };
+
+    let task_ids: Vec<Uuid> = sqlx::query_scalar(
+        "UPDATE tasks SET deleted_at = NULL, version = version + 1, updated_at = now() \
+         WHERE list_id = $1 AND deleted_at = $2 RETURNING id",
+    )
+    .bind(list_id)
+    .bind(deleted_at)
+    .fetch_all(&mut *tx)
+    .await?;
+    task_repo::touch_dependents(&mut *tx, &task_ids).await?;
+
+    let list = sqlx::query_as::<_, TodoList>(
+        "UPDATE todo_lists SET deleted_at = NULL, version = version + 1, updated_at = now() \
+         WHERE id = $1 RETURNING *",
+    )
+    .bind(list_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(Some(list))
+}
+
+/// Окончательно удаляет списки, попавшие в корзину раньше `before`
+/// (вместе со всеми их задачами). Возвращает количество удалённых списков.
+pub async fn purge_deleted(db: impl PgExecutor<'_>, before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM todo_lists WHERE deleted_at < $1")
+        .bind(before)
+        .execute(db)
         .await?;
 
-    // `rows_affected()` — сколько строк было удалено. 0 = не нашли.
-    Ok(result.rows_affected() > 0)
+    Ok(result.rows_affected())
 }
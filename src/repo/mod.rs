@@ -1,3 +1,17 @@
+pub mod attachment_repo;
+pub mod checklist_repo;
+pub mod comment_repo;
+pub mod dependency_repo;
+pub mod history_repo;
+pub mod label_repo;
 pub mod list_repo;
+pub mod position_repo;
+pub mod recurrence_repo;
+pub mod reminder_repo;
+pub mod search_repo;
+pub mod status_repo;
 pub mod task_repo;
+pub mod template_repo;
+pub mod time_entry_repo;
 pub mod user_repo;
+pub mod view_repo;
@@ -0,0 +1,266 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::models::rank::{self, MAX_RANK_LEN};
+use crate::repo::task_repo;
+
+/// Набор элементов с общей ручной сортировкой.
+#[derive(Debug, Clone, Copy)]
+pub enum Scope {
+    /// Задачи одного списка.
+    ListTasks(Uuid),
+    /// Списки одного пользователя.
+    UserLists(Uuid),
+    /// Статусы (колонки доски) одного списка.
+    ListStatuses(Uuid),
+}
+
+impl Scope {
+    fn table(self) -> &'static str {
+        match self {
+            Scope::ListTasks(_) => "tasks",
+            Scope::UserLists(_) => "todo_lists",
+            Scope::ListStatuses(_) => "list_statuses",
+        }
+    }
+
+    fn column(self) -> &'static str {
+        match self {
+            Scope::ListTasks(_) => "list_id",
+            Scope::UserLists(_) => "user_id",
+            Scope::ListStatuses(_) => "list_id",
+        }
+    }
+
+    /// Есть ли у строк версия (ETag), которую меняет перестановка.
+    fn versioned(self) -> bool {
+        !matches!(self, Scope::ListStatuses(_))
+    }
+
+    fn id(self) -> Uuid {
+        match self {
+            Scope::ListTasks(id) | Scope::UserLists(id) | Scope::ListStatuses(id) => id,
+        }
+    }
+}
+
+/// Результат перестановки.
+#[derive(Debug, PartialEq, Eq)]
+pub enum RepositionOutcome {
+    Moved,
+    /// Сам элемент не найден в области.
+    ItemNotFound,
+    /// Соседа нет в области.
+    AnchorNotFound,
+    /// `after_id` стоит не раньше `before_id`.
+    AnchorsOutOfOrder,
+}
+
+/// Сериализует изменения порядка внутри области до конца транзакции —
+/// так два параллельных запроса не получат одинаковый ранг.
+pub async fn lock_scope(conn: &mut PgConnection, scope: Scope) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('position:' || $2 || ':' || $1::text))")
+        .bind(scope.id())
+        .bind(scope.table())
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Ранг для нового элемента в конце области. Вызывается под `lock_scope`:
+/// если ранг вышел бы длиннее MAX_RANK_LEN, область перебалансируется.
+pub async fn last_position(conn: &mut PgConnection, scope: Scope) -> Result<String, sqlx::Error> {
+    let max = edge_position(&mut *conn, scope, "MAX").await?;
+    match rank::between(max.as_deref(), None) {
+        Some(position) if position.len() <= MAX_RANK_LEN => Ok(position),
+        _ => {
+            rebalance_scope(&mut *conn, scope).await?;
+            let max = edge_position(conn, scope, "MAX").await?;
+            Ok(rank::between(max.as_deref(), None).expect("no upper bound"))
+        }
+    }
+}
+
+/// Ранг для нового элемента в начале области. Вызывается под `lock_scope`:
+/// если ранг вышел бы длиннее MAX_RANK_LEN, область перебалансируется.
+pub async fn first_position(conn: &mut PgConnection, scope: Scope) -> Result<String, sqlx::Error> {
+    let min = edge_position(&mut *conn, scope, "MIN").await?;
+    match rank::between(None, min.as_deref()) {
+        Some(position) if position.len() <= MAX_RANK_LEN => Ok(position),
+        _ => {
+            // После перебалансировки ранги короткие и не из одних нулей — место перед min есть.
+            rebalance_scope(&mut *conn, scope).await?;
+            let min = edge_position(conn, scope, "MIN").await?;
+            Ok(rank::between(None, min.as_deref()).expect("rebalanced ranks leave room at the start"))
+        }
+    }
+}
+
+/// Крайний ранг области: `aggregate` — `MIN` или `MAX`.
+async fn edge_position(conn: &mut PgConnection, scope: Scope, aggregate: &str) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar(&format!(
+        "SELECT {}(position) FROM {} WHERE {} = $1",
+        aggregate,
+        scope.table(),
+        scope.column(),
+    ))
+    .bind(scope.id())
+    .fetch_one(conn)
+    .await
+}
+
+/// Ранг элемента области (`None` — такого элемента нет).
+async fn position_of(conn: &mut PgConnection, scope: Scope, id: Uuid) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar(&format!(
+        "SELECT position FROM {} WHERE id = $1 AND {} = $2",
+        scope.table(),
+        scope.column(),
+    ))
+    .bind(id)
+    .bind(scope.id())
+    .fetch_optional(conn)
+    .await
+}
+
+/// Ставит элемент `item_id` сразу после `after_id` и/или перед `before_id`.
+///
+/// Обычно меняется ровно одна строка. Если подходящий ранг получается
+/// длиннее MAX_RANK_LEN (или у соседей совпали ранги), вся область
+/// перебалансируется равномерными рангами в той же транзакции.
+pub async fn reposition(
+    pool: &PgPool,
+    scope: Scope,
+    item_id: Uuid,
+    after_id: Option<Uuid>,
+    before_id: Option<Uuid>,
+) -> Result<RepositionOutcome, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    lock_scope(&mut tx, scope).await?;
+
+    if position_of(&mut tx, scope, item_id).await?.is_none() {
+        return Ok(RepositionOutcome::ItemNotFound);
+    }
+
+    let after = match after_id {
+        Some(id) => match position_of(&mut tx, scope, id).await? {
+            Some(position) => Some(position),
+            None => return Ok(RepositionOutcome::AnchorNotFound),
+        },
+        None => None,
+    };
+    let before = match before_id {
+        Some(id) => match position_of(&mut tx, scope, id).await? {
+            Some(position) => Some(position),
+            None => return Ok(RepositionOutcome::AnchorNotFound),
+        },
+        None => None,
+    };
+
+    // Недостающий сосед — ближайший элемент с нужной стороны (кроме самого item).
+    let (low, high) = match (after, before) {
+        (Some(low), Some(high)) if low >= high => return Ok(RepositionOutcome::AnchorsOutOfOrder),
+        (Some(low), Some(high)) => (Some(low), Some(high)),
+        (Some(low), None) => {
+            let high: Option<String> = sqlx::query_scalar(&format!(
+                "SELECT MIN(position) FROM {} WHERE {} = $1 AND position > $2 AND id <> $3",
+                scope.table(),
+                scope.column(),
+            ))
+            .bind(scope.id())
+            .bind(&low)
+            .bind(item_id)
+            .fetch_one(&mut *tx)
+            .await?;
+            (Some(low), high)
+        }
+        (None, Some(high)) => {
+            let low: Option<String> = sqlx::query_scalar(&format!(
+                "SELECT MAX(position) FROM {} WHERE {} = $1 AND position < $2 AND id <> $3",
+                scope.table(),
+                scope.column(),
+            ))
+            .bind(scope.id())
+            .bind(&high)
+            .bind(item_id)
+            .fetch_one(&mut *tx)
+            .await?;
+            (low, Some(high))
+        }
+        (None, None) => (None, None),
+    };
+
+    match rank::between(low.as_deref(), high.as_deref()) {
+        Some(position) if position.len() <= MAX_RANK_LEN => {
+            set_position(&mut tx, scope, item_id, &position).await?;
+        }
+        _ => rebalance(&mut tx, scope, item_id, after_id, before_id).await?,
+    }
+    // Порядок колонок определяет колонку по умолчанию для задач списка.
+    if let Scope::ListStatuses(list_id) = scope {
+        task_repo::touch_list(&mut *tx, list_id).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(RepositionOutcome::Moved)
+}
+
+async fn set_position(conn: &mut PgConnection, scope: Scope, id: Uuid, position: &str) -> Result<(), sqlx::Error> {
+    let version = if scope.versioned() { ", version = version + 1" } else { "" };
+    sqlx::query(&format!("UPDATE {} SET position = $1{} WHERE id = $2", scope.table(), version))
+        .bind(position)
+        .bind(id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Переназначает всей области равномерные ранги, ставя `item_id`
+/// после `after_id` (или перед `before_id`).
+async fn rebalance(
+    conn: &mut PgConnection,
+    scope: Scope,
+    item_id: Uuid,
+    after_id: Option<Uuid>,
+    before_id: Option<Uuid>,
+) -> Result<(), sqlx::Error> {
+    let mut ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+        "SELECT id FROM {} WHERE {} = $1 AND id <> $2 ORDER BY position, id",
+        scope.table(),
+        scope.column(),
+    ))
+    .bind(scope.id())
+    .bind(item_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let index = match (after_id, before_id) {
+        (Some(after), _) => ids.iter().position(|id| *id == after).map_or(ids.len(), |index| index + 1),
+        (None, Some(before)) => ids.iter().position(|id| *id == before).unwrap_or(0),
+        (None, None) => ids.len(),
+    };
+    ids.insert(index, item_id);
+
+    assign_evenly(conn, scope, &ids).await
+}
+
+/// Переназначает всей области равномерные ранги, сохраняя порядок.
+async fn rebalance_scope(conn: &mut PgConnection, scope: Scope) -> Result<(), sqlx::Error> {
+    let ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+        "SELECT id FROM {} WHERE {} = $1 ORDER BY position, id",
+        scope.table(),
+        scope.column(),
+    ))
+    .bind(scope.id())
+    .fetch_all(&mut *conn)
+    .await?;
+
+    assign_evenly(conn, scope, &ids).await
+}
+
+async fn assign_evenly(conn: &mut PgConnection, scope: Scope, ids: &[Uuid]) -> Result<(), sqlx::Error> {
+    for (id, position) in ids.iter().zip(rank::evenly_spaced(ids.len())) {
+        set_position(&mut *conn, scope, *id, &position).await?;
+    }
+    Ok(())
+}
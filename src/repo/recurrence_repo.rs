@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::task::TaskCompletion;
+
+/// Записывает выполнение повторения серии.
+pub async fn record_completion(
+    db: impl PgExecutor<'_>,
+    series_id: Uuid,
+    task_id: Uuid,
+    due_at: Option<DateTime<Utc>>,
+) -> Result<TaskCompletion, sqlx::Error> {
+    let completion = sqlx::query_as::<_, TaskCompletion>(
+        "INSERT INTO task_completions (series_id, task_id, due_at) VALUES ($1, $2, $3) RETURNING *",
+    )
+    .bind(series_id)
+    .bind(task_id)
+    .bind(due_at)
+    .fetch_one(db)
+    .await?;
+
+    Ok(completion)
+}
+
+/// Записано ли уже выполнение этой задачи (её переоткрыли и выполнили снова).
+pub async fn has_completion(db: impl PgExecutor<'_>, task_id: Uuid) -> Result<bool, sqlx::Error> {
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM task_completions WHERE task_id = $1)")
+            .bind(task_id)
+            .fetch_one(db)
+            .await?;
+
+    Ok(exists)
+}
+
+/// Сколько раз серия уже была выполнена.
+pub async fn count_completions(db: impl PgExecutor<'_>, series_id: Uuid) -> Result<i64, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM task_completions WHERE series_id = $1")
+        .bind(series_id)
+        .fetch_one(db)
+        .await?;
+
+    Ok(count)
+}
+
+/// История выполнений серии (сначала последние).
+pub async fn find_completions(
+    pool: &PgPool,
+    series_id: Uuid,
+) -> Result<Vec<TaskCompletion>, sqlx::Error> {
+    let completions = sqlx::query_as::<_, TaskCompletion>(
+        "SELECT * FROM task_completions WHERE series_id = $1 ORDER BY completed_at DESC",
+    )
+    .bind(series_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(completions)
+}
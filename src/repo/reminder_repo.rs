@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::reminder::{DueReminder, Reminder};
+
+/// Создаёт напоминание для задачи.
+pub async fn create(
+    pool: &PgPool,
+    task_id: Uuid,
+    user_id: Uuid,
+    remind_at: DateTime<Utc>,
+    channel: &str,
+    target: &str,
+) -> Result<Reminder, sqlx::Error> {
+    let reminder = sqlx::query_as::<_, Reminder>(
+        "INSERT INTO reminders (task_id, user_id, remind_at, channel, target) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING *",
+    )
+    .bind(task_id)
+    .bind(user_id)
+    .bind(remind_at)
+    .bind(channel)
+    .bind(target)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(reminder)
+}
+
+/// Возвращает все напоминания задачи (по времени срабатывания).
+pub async fn find_all_by_task(pool: &PgPool, task_id: Uuid) -> Result<Vec<Reminder>, sqlx::Error> {
+    let reminders = sqlx::query_as::<_, Reminder>(
+        "SELECT * FROM reminders WHERE task_id = $1 ORDER BY remind_at ASC",
+    )
+    .bind(task_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(reminders)
+}
+
+/// Откладывает напоминание: новое время срабатывания, статус снова "pending".
+pub async fn snooze(
+    pool: &PgPool,
+    task_id: Uuid,
+    reminder_id: Uuid,
+    remind_at: DateTime<Utc>,
+) -> Result<Option<Reminder>, sqlx::Error> {
+    let reminder = sqlx::query_as::<_, Reminder>(
+        "UPDATE reminders SET remind_at = $1, status = 'pending', attempts = 0, \
+         last_error = NULL, sent_at = NULL, lease_until = NULL, updated_at = now() \
+         WHERE id = $2 AND task_id = $3 RETURNING *",
+    )
+    .bind(remind_at)
+    .bind(reminder_id)
+    .bind(task_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(reminder)
+}
+
+/// Удаляет напоминание.
+pub async fn delete(pool: &PgPool, task_id: Uuid, reminder_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM reminders WHERE id = $1 AND task_id = $2")
+        .bind(reminder_id)
+        .bind(task_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Захватывает пачку сработавших напоминаний: переводит их в "sending"
+/// с арендой на `lease_secs` секунд и сразу коммитит (отдельный запрос).
+/// Напоминания задач в корзине ждут: после восстановления задачи они сработают.
+///
+/// `FOR UPDATE SKIP LOCKED` не даёт двум воркерам захватить одну строку,
+/// а аренда — взять её снова, пока идёт доставка. Напоминание, чья аренда
+/// истекла (воркер упал, не отметив результат), захватывается повторно.
+pub async fn claim_due(pool: &PgPool, limit: i64, lease_secs: i64) -> Result<Vec<DueReminder>, sqlx::Error> {
+    let reminders = sqlx::query_as::<_, DueReminder>(
+        "WITH claimed AS ( \
+             UPDATE reminders SET status = 'sending', \
+                 lease_until = now() + make_interval(secs => $2), updated_at = now() \
+             WHERE id IN ( \
+                 SELECT r.id FROM reminders r JOIN tasks t ON t.id = r.task_id \
+                 WHERE t.deleted_at IS NULL AND r.remind_at <= now() \
+                 AND (r.status = 'pending' OR (r.status = 'sending' AND r.lease_until < now())) \
+                 ORDER BY r.remind_at ASC \
+                 LIMIT $1 \
+                 FOR UPDATE OF r SKIP LOCKED \
+             ) \
+             RETURNING * \
+         ) \
+         SELECT c.*, t.list_id, t.title AS task_title, t.due_at AS task_due_at \
+         FROM claimed c JOIN tasks t ON t.id = c.task_id \
+         ORDER BY c.remind_at ASC",
+    )
+    .bind(limit)
+    .bind(lease_secs as f64)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(reminders)
+}
+
+/// Отмечает захваченное напоминание доставленным. Если за время доставки
+/// напоминание отложили или удалили, ничего не меняет.
+pub async fn mark_sent(pool: &PgPool, reminder_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE reminders SET status = 'sent', sent_at = now(), attempts = attempts + 1, \
+         last_error = NULL, lease_until = NULL, updated_at = now() \
+         WHERE id = $1 AND status = 'sending'",
+    )
+    .bind(reminder_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Фиксирует неудачную попытку доставки захваченного напоминания.
+///
+/// `retry_at = Some(..)` — попробовать ещё раз в указанное время,
+/// `None` — попытки исчерпаны, статус "failed".
+pub async fn mark_failed(
+    pool: &PgPool,
+    reminder_id: Uuid,
+    error: &str,
+    retry_at: Option<DateTime<Utc>>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE reminders SET attempts = attempts + 1, last_error = $1, \
+         status = CASE WHEN $2::timestamptz IS NULL THEN 'failed' ELSE 'pending' END, \
+         remind_at = COALESCE($2, remind_at), lease_until = NULL, updated_at = now() \
+         WHERE id = $3 AND status = 'sending'",
+    )
+    .bind(error)
+    .bind(retry_at)
+    .bind(reminder_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::models::search::{SearchHit, HIGHLIGHT_START, HIGHLIGHT_STOP};
+
+/// Порог сходства триграмм, начиная с которого название считается
+/// совпавшим с опечаткой (`word_similarity`, от 0 до 1).
+const TYPO_SIMILARITY: f32 = 0.3;
+
+/// Позиция в выдаче, после которой начинается следующая страница.
+#[derive(Debug)]
+pub struct SearchAfter<'a> {
+    pub rank: f64,
+    pub title: &'a str,
+    pub id: Uuid,
+}
+
+/// Ищет списки пользователя и доступные ему задачи (в его списках или
+/// назначенные ему) по словам `words` исходного запроса `text`.
+///
+/// Каждое слово ищется как префикс (`слово:*`), все слова должны
+/// совпасть. Названия, не совпавшие по словам, но похожие на запрос
+/// (опечатки), тоже попадают в выдачу — ниже точных совпадений.
+/// Удалённые в корзину не ищутся, архивные — ищутся.
+///
+/// Полнотекстовые совпадения и опечатки ищутся отдельными запросами,
+/// объединёнными через UNION: так каждый использует свой GIN-индекс
+/// (`search_vector` и триграммы `title`). Выдача упорядочена по
+/// `rank DESC, title, id`; `after` — ключ последнего элемента прошлой страницы.
+pub async fn search(
+    pool: &PgPool,
+    user_id: Uuid,
+    words: &[String],
+    text: &str,
+    after: Option<&SearchAfter<'_>>,
+    limit: i64,
+) -> Result<Vec<SearchHit>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    // Оператор `<%` сравнивает word_similarity с этим порогом; SET LOCAL — до конца транзакции.
+    sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+        .bind(TYPO_SIMILARITY.to_string())
+        .execute(&mut *tx)
+        .await?;
+
+    let mut builder = QueryBuilder::<Postgres>::new("WITH q AS (SELECT ");
+    for (index, word) in words.iter().enumerate() {
+        if index > 0 {
+            builder.push(" && ");
+        }
+        builder.push("to_tsquery('russian', ").push_bind(format!("{}:*", word)).push(")");
+    }
+    builder.push(" AS query, ").push_bind(text.to_string()).push("::text AS text, ");
+    builder
+        .push_bind(format!("StartSel={}, StopSel={}, HighlightAll=true", HIGHLIGHT_START, HIGHLIGHT_STOP))
+        .push("::text AS options, ");
+    builder.push_bind(user_id).push("::uuid AS user_id), ");
+
+    builder.push(
+        "matches AS ( \
+             SELECT 'list' AS kind, l.id FROM todo_lists l, q \
+             WHERE l.user_id = q.user_id AND l.deleted_at IS NULL AND l.search_vector @@ q.query \
+             UNION \
+             SELECT 'list', l.id FROM todo_lists l, q \
+             WHERE l.user_id = q.user_id AND l.deleted_at IS NULL AND q.text <% l.title \
+             UNION \
+             SELECT 'task', t.id FROM tasks t JOIN todo_lists l ON l.id = t.list_id, q \
+             WHERE (l.user_id = q.user_id OR t.assignee_id = q.user_id) \
+                 AND t.deleted_at IS NULL AND l.deleted_at IS NULL AND t.search_vector @@ q.query \
+             UNION \
+             SELECT 'task', t.id FROM tasks t JOIN todo_lists l ON l.id = t.list_id, q \
+             WHERE (l.user_id = q.user_id OR t.assignee_id = q.user_id) \
+                 AND t.deleted_at IS NULL AND l.deleted_at IS NULL AND q.text <% t.title \
+         ), \
+         hits AS ( \
+             SELECT m.kind, l.id, l.id AS list_id, l.title AS list_title, l.title, \
+                 NULL::text AS status, l.archived_at IS NOT NULL AS archived, \
+                 (ts_rank_cd(l.search_vector, q.query) + word_similarity(q.text, l.title))::float8 AS rank \
+             FROM matches m JOIN todo_lists l ON m.kind = 'list' AND l.id = m.id, q \
+             UNION ALL \
+             SELECT m.kind, t.id, t.list_id, l.title, t.title, \
+                 t.status, t.archived_at IS NOT NULL OR l.archived_at IS NOT NULL, \
+                 (ts_rank_cd(t.search_vector, q.query) + word_similarity(q.text, t.title))::float8 \
+             FROM matches m JOIN tasks t ON m.kind = 'task' AND t.id = m.id \
+                 JOIN todo_lists l ON l.id = t.list_id, q \
+         ) \
+         SELECT hits.*, ts_headline('russian', hits.title, q.query, q.options) AS highlight \
+         FROM hits, q",
+    );
+
+    if let Some(after) = after {
+        builder
+            .push(" WHERE hits.rank < ")
+            .push_bind(after.rank)
+            .push(" OR (hits.rank = ")
+            .push_bind(after.rank)
+            .push(" AND (hits.title, hits.id) > (")
+            .push_bind(after.title.to_string())
+            .push(", ")
+            .push_bind(after.id)
+            .push("))");
+    }
+
+    builder.push(" ORDER BY hits.rank DESC, hits.title, hits.id LIMIT ").push_bind(limit);
+
+    let hits = builder.build_query_as::<SearchHit>().fetch_all(&mut *tx).await?;
+    tx.commit().await?;
+
+    Ok(hits)
+}
@@ -0,0 +1,184 @@
+use sqlx::{PgConnection, PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::list_status::{ListStatus, StatusCategory};
+use crate::models::rank;
+use crate::repo::position_repo::{self, Scope};
+use crate::repo::task_repo;
+
+/// Статусы нового списка: по одному на категорию.
+const DEFAULT_STATUSES: [(&str, StatusCategory); 3] = [
+    ("To Do", StatusCategory::NotStarted),
+    ("In Progress", StatusCategory::Active),
+    ("Done", StatusCategory::Done),
+];
+
+/// Создаёт статусы по умолчанию для только что созданного списка
+/// (в транзакции создания списка).
+pub async fn create_defaults(conn: &mut PgConnection, list_id: Uuid) -> Result<(), sqlx::Error> {
+    for ((name, category), position) in DEFAULT_STATUSES.iter().zip(rank::evenly_spaced(DEFAULT_STATUSES.len())) {
+        sqlx::query("INSERT INTO list_statuses (list_id, name, category, position) VALUES ($1, $2, $3, $4)")
+            .bind(list_id)
+            .bind(name)
+            .bind(category.as_str())
+            .bind(position)
+            .execute(&mut *conn)
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Добавляет статус в конец доски списка.
+pub async fn create(
+    pool: &PgPool,
+    list_id: Uuid,
+    name: &str,
+    category: StatusCategory,
+    wip_limit: Option<i32>,
+) -> Result<ListStatus, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListStatuses(list_id)).await?;
+    let position = position_repo::last_position(&mut tx, Scope::ListStatuses(list_id)).await?;
+
+    let status = sqlx::query_as::<_, ListStatus>(
+        "INSERT INTO list_statuses (list_id, name, category, position, wip_limit) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING *",
+    )
+    .bind(list_id)
+    .bind(name)
+    .bind(category.as_str())
+    .bind(position)
+    .bind(wip_limit)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(status)
+}
+
+/// Возвращает статусы списка в порядке колонок доски.
+pub async fn find_all_by_list(db: impl PgExecutor<'_>, list_id: Uuid) -> Result<Vec<ListStatus>, sqlx::Error> {
+    let statuses = sqlx::query_as::<_, ListStatus>(
+        "SELECT * FROM list_statuses WHERE list_id = $1 ORDER BY position, id",
+    )
+    .bind(list_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(statuses)
+}
+
+/// Возвращает статусы сразу нескольких списков (внутри списка — в порядке колонок).
+pub async fn find_by_lists(pool: &PgPool, list_ids: &[Uuid]) -> Result<Vec<ListStatus>, sqlx::Error> {
+    let statuses = sqlx::query_as::<_, ListStatus>(
+        "SELECT * FROM list_statuses WHERE list_id = ANY($1) ORDER BY list_id, position, id",
+    )
+    .bind(list_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(statuses)
+}
+
+/// Возвращает статус по ID внутри списка.
+pub async fn find_by_id(pool: &PgPool, list_id: Uuid, status_id: Uuid) -> Result<Option<ListStatus>, sqlx::Error> {
+    let status = sqlx::query_as::<_, ListStatus>(
+        "SELECT * FROM list_statuses WHERE id = $1 AND list_id = $2",
+    )
+    .bind(status_id)
+    .bind(list_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(status)
+}
+
+/// Ищет статус списка по имени (для проверки уникальности).
+pub async fn find_by_name(pool: &PgPool, list_id: Uuid, name: &str) -> Result<Option<ListStatus>, sqlx::Error> {
+    let status = sqlx::query_as::<_, ListStatus>(
+        "SELECT * FROM list_statuses WHERE list_id = $1 AND name = $2",
+    )
+    .bind(list_id)
+    .bind(name)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(status)
+}
+
+/// Обновляет статус (name + category + wip_limit).
+///
+/// Категорию нельзя сменить у последнего статуса своей категории:
+/// тогда строка не меняется и возвращается `None`, как и для
+/// несуществующего статуса. Проверка и запись идут под блокировкой статусов списка.
+pub async fn update(
+    pool: &PgPool,
+    list_id: Uuid,
+    status_id: Uuid,
+    name: &str,
+    category: StatusCategory,
+    wip_limit: Option<i32>,
+) -> Result<Option<ListStatus>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListStatuses(list_id)).await?;
+
+    let status = sqlx::query_as::<_, ListStatus>(
+        "UPDATE list_statuses s SET name = $1, category = $2, wip_limit = $3, updated_at = now() \
+         WHERE s.id = $4 AND s.list_id = $5 \
+         AND (s.category = $2 OR EXISTS ( \
+             SELECT 1 FROM list_statuses o WHERE o.list_id = s.list_id AND o.category = s.category AND o.id <> s.id \
+         )) \
+         RETURNING s.*",
+    )
+    .bind(name)
+    .bind(category.as_str())
+    .bind(wip_limit)
+    .bind(status_id)
+    .bind(list_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    if status.is_some() {
+        task_repo::touch_list(&mut *tx, list_id).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(status)
+}
+
+/// Удаляет статус, если он не последний в своей категории.
+/// Задачи из удалённой колонки переходят в первую колонку той же категории.
+pub async fn delete(pool: &PgPool, list_id: Uuid, status_id: Uuid) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListStatuses(list_id)).await?;
+
+    let result = sqlx::query(
+        "DELETE FROM list_statuses s WHERE s.id = $1 AND s.list_id = $2 \
+         AND EXISTS ( \
+             SELECT 1 FROM list_statuses o WHERE o.list_id = s.list_id AND o.category = s.category AND o.id <> s.id \
+         )",
+    )
+    .bind(status_id)
+    .bind(list_id)
+    .execute(&mut *tx)
+    .await?;
+    if result.rows_affected() > 0 {
+        task_repo::touch_list(&mut *tx, list_id).await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Сериализует перемещения задач по доске списка до конца транзакции —
+/// так два параллельных запроса не превысят WIP-лимит колонки.
+pub async fn lock_board(conn: &mut PgConnection, list_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtext('board:' || $1::text))")
+        .bind(list_id)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
@@ -1,79 +1,847 @@
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Connection, PgConnection, PgExecutor, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 use crate::models::task::Task;
+use crate::models::task_query::{Condition, Keyset, Sort, TaskQuery, Value};
+use crate::models::task_status::TaskStatus;
+use crate::models::trash::TrashedTask;
+use crate::repo::position_repo::{self, Scope};
+
+/// Изменения задачи для частичного обновления: `None` — поле не меняется.
+#[derive(Debug, Default)]
+pub struct TaskChanges<'a> {
+    pub title: Option<&'a str>,
+    pub status: Option<TaskStatus>,
+    pub auto_complete: Option<bool>,
+    /// `Some(None)` — снять срок.
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    /// `Some(None)` — снять оценку.
+    pub estimate_minutes: Option<Option<i32>>,
+}
+
+impl TaskChanges<'_> {
+    /// `true`, если менять нечего.
+    pub fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.status.is_none()
+            && self.auto_complete.is_none()
+            && self.due_at.is_none()
+            && self.estimate_minutes.is_none()
+    }
+}
+
+/// Создаёт задачу в конце указанного списка. Статус по умолчанию — "todo" (DEFAULT в БД).
+///
+/// Внутри открытой транзакции работает в savepoint.
+pub async fn create(
+    conn: &mut PgConnection,
+    list_id: Uuid,
+    title: &str,
+    auto_complete: bool,
+    due_at: Option<DateTime<Utc>>,
+) -> Result<Task, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListTasks(list_id)).await?;
+    let position = position_repo::last_position(&mut tx, Scope::ListTasks(list_id)).await?;
 
-/// Создаёт задачу в указанном списке. Статус по умолчанию — "todo" (DEFAULT в БД).
-pub async fn create(pool: &PgPool, list_id: Uuid, title: &str) -> Result<Task, sqlx::Error> {
     let task = sqlx::query_as::<_, Task>(
-        "INSERT INTO tasks (list_id, title) VALUES ($1, $2) RETURNING *",
+        "INSERT INTO tasks (list_id, title, auto_complete, due_at, position) \
+         VALUES ($1, $2, $3, $4, $5) RETURNING *",
     )
     .bind(list_id)
     .bind(title)
-    .fetch_one(pool)
+    .bind(auto_complete)
+    .bind(due_at)
+    .bind(position)
+    .fetch_one(&mut *tx)
     .await?;
 
+    tx.commit().await?;
+
     Ok(task)
 }
 
-/// Возвращает все задачи конкретного списка.
-pub async fn find_all_by_list(pool: &PgPool, list_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+/// Возвращает все задачи конкретного списка (в ручном порядке).
+/// Если передан `label_id` — только задачи с этой меткой.
+/// Архивные задачи — только с `include_archived`.
+/// Задачи в корзине не возвращаются — ни здесь, ни в остальных выборках.
+pub async fn find_all_by_list(
+    pool: &PgPool,
+    list_id: Uuid,
+    label_id: Option<Uuid>,
+    include_archived: bool,
+) -> Result<Vec<Task>, sqlx::Error> {
     let tasks = sqlx::query_as::<_, Task>(
-        "SELECT * FROM tasks WHERE list_id = $1 ORDER BY created_at ASC",
+        "SELECT * FROM tasks WHERE list_id = $1 AND deleted_at IS NULL \
+         AND ($3 OR archived_at IS NULL) \
+         AND ($2::uuid IS NULL OR EXISTS ( \
+             SELECT 1 FROM task_labels tl WHERE tl.task_id = tasks.id AND tl.label_id = $2 \
+         )) \
+         ORDER BY position, created_at",
     )
     .bind(list_id)
+    .bind(label_id)
+    .bind(include_archived)
     .fetch_all(pool)
     .await?;
 
     Ok(tasks)
 }
 
+/// Где искать задачи для `find_page`.
+#[derive(Debug, Clone, Copy)]
+pub enum TaskScope {
+    /// Задачи одного списка.
+    List(Uuid),
+    /// Задачи всех списков пользователя (кроме удалённых; архивные
+    /// списки — только с `include_archived`).
+    User(Uuid),
+}
+
+/// Возвращает страницу задач по запросу `query`: метка, архив, условия
+/// фильтра и сортировка (см. `models::task_query`). `after` — место
+/// в сортировке, после которого начинается страница.
+///
+/// Значения из запроса попадают в SQL только через параметры;
+/// имена колонок берутся из `Field`, а не из запроса.
+pub async fn find_page(
+    pool: &PgPool,
+    scope: TaskScope,
+    query: &TaskQuery,
+    after: Option<&Keyset>,
+    limit: i64,
+) -> Result<Vec<Task>, sqlx::Error> {
+    let mut builder = QueryBuilder::<Postgres>::new("SELECT * FROM tasks WHERE ");
+    match scope {
+        TaskScope::List(list_id) => {
+            builder.push("list_id = ").push_bind(list_id);
+        }
+        TaskScope::User(user_id) => {
+            builder
+                .push("list_id IN (SELECT id FROM todo_lists WHERE user_id = ")
+                .push_bind(user_id)
+                .push(" AND deleted_at IS NULL");
+            if !query.include_archived {
+                builder.push(" AND archived_at IS NULL");
+            }
+            builder.push(")");
+        }
+    }
+    builder.push(" AND deleted_at IS NULL");
+    if !query.include_archived {
+        builder.push(" AND archived_at IS NULL");
+    }
+    if let Some(label_id) = query.label_id {
+        builder
+            .push(" AND EXISTS (SELECT 1 FROM task_labels tl WHERE tl.task_id = tasks.id AND tl.label_id = ")
+            .push_bind(label_id)
+            .push(")");
+    }
+    for condition in &query.filter.conditions {
+        builder.push(" AND ");
+        push_condition(&mut builder, condition);
+    }
+    if let Some(after) = after {
+        builder.push(" AND ");
+        push_after(&mut builder, &query.sort, after);
+    }
+
+    builder.push(" ORDER BY ");
+    for key in &query.sort.keys {
+        builder
+            .push(key.field.sort_expr())
+            .push(if key.descending { " DESC NULLS FIRST, " } else { " ASC NULLS LAST, " });
+    }
+    builder.push("id LIMIT ").push_bind(limit);
+
+    let tasks = builder.build_query_as::<Task>().fetch_all(pool).await?;
+
+    Ok(tasks)
+}
+
+/// Добавляет значение условия параметром запроса.
+fn push_value(builder: &mut QueryBuilder<'_, Postgres>, value: &Value) {
+    match value {
+        Value::Text(text) => builder.push_bind(text.clone()),
+        Value::Status(status) => builder.push_bind(status.as_str()),
+        Value::Uuid(id) => builder.push_bind(*id),
+        Value::Bool(flag) => builder.push_bind(*flag),
+        Value::DateTime(at) => builder.push_bind(*at),
+        Value::Int(number) => builder.push_bind(*number),
+    };
+}
+
+/// Компилирует одно условие фильтра.
+fn push_condition(builder: &mut QueryBuilder<'_, Postgres>, condition: &Condition) {
+    match condition {
+        Condition::Compare { field, op, value } => {
+            builder.push(field.column()).push(" ").push(op.sql()).push(" ");
+            push_value(builder, value);
+        }
+        Condition::In { field, values } => {
+            builder.push(field.column()).push(" IN (");
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    builder.push(", ");
+                }
+                push_value(builder, value);
+            }
+            builder.push(")");
+        }
+        Condition::Contains { field, text } => {
+            let escaped = text.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+            builder.push(field.column()).push(" ILIKE ").push_bind(format!("%{}%", escaped));
+        }
+        Condition::Null { field, is_null } => {
+            builder
+                .push(field.column())
+                .push(if *is_null { " IS NULL" } else { " IS NOT NULL" });
+        }
+    }
+}
+
+/// Условие «строка идёт в сортировке после `after`»: для ключей k1..kn и ID —
+/// `k1 после v1 OR (k1 = v1 AND k2 после v2) OR … OR (все равны AND id > id0)`.
+/// Пустые значения стоят в конце при возрастании и в начале при убывании.
+fn push_after(builder: &mut QueryBuilder<'_, Postgres>, sort: &Sort, after: &Keyset) {
+    builder.push("(");
+    for index in 0..=sort.keys.len() {
+        if index > 0 {
+            builder.push(" OR ");
+        }
+        builder.push("(");
+        for (key, value) in sort.keys.iter().zip(&after.values).take(index) {
+            let expr = key.field.sort_expr();
+            match value {
+                Some(value) => {
+                    builder.push(expr).push(" = ");
+                    push_value(builder, value);
+                }
+                None => {
+                    builder.push(expr).push(" IS NULL");
+                }
+            }
+            builder.push(" AND ");
+        }
+
+        match (sort.keys.get(index), after.values.get(index)) {
+            (Some(key), Some(value)) => {
+                let expr = key.field.sort_expr();
+                match (key.descending, value) {
+                    (false, Some(value)) => {
+                        builder.push("(").push(expr).push(" > ");
+                        push_value(builder, value);
+                        builder.push(" OR ").push(expr).push(" IS NULL)");
+                    }
+                    (false, None) => {
+                        builder.push("FALSE");
+                    }
+                    (true, Some(value)) => {
+                        builder.push(expr).push(" < ");
+                        push_value(builder, value);
+                    }
+                    (true, None) => {
+                        builder.push(expr).push(" IS NOT NULL");
+                    }
+                }
+            }
+            _ => {
+                builder.push("id > ").push_bind(after.id);
+            }
+        }
+        builder.push(")");
+    }
+    builder.push(")");
+}
+
+/// Возвращает неархивные задачи списка в указанном статусе (для подсчёта WIP-лимитов).
+pub async fn find_by_status(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    status: TaskStatus,
+) -> Result<Vec<Task>, sqlx::Error> {
+    let tasks = sqlx::query_as::<_, Task>(
+        "SELECT * FROM tasks WHERE list_id = $1 AND status = $2 AND deleted_at IS NULL AND archived_at IS NULL",
+    )
+    .bind(list_id)
+    .bind(status.as_str())
+    .fetch_all(db)
+    .await?;
+
+    Ok(tasks)
+}
+
 /// Возвращает одну задачу по ID внутри конкретного списка.
 pub async fn find_by_id(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    task_id: Uuid,
+) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "SELECT * FROM tasks WHERE id = $1 AND list_id = $2 AND deleted_at IS NULL",
+    )
+    .bind(task_id)
+    .bind(list_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Блокирует задачу списка до конца транзакции (`FOR UPDATE`) и возвращает
+/// её текущее состояние. Параллельные изменения задачи ждут коммита.
+pub async fn lock(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    task_id: Uuid,
+) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "SELECT * FROM tasks WHERE id = $1 AND list_id = $2 AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(task_id)
+    .bind(list_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Увеличивает версию задач, зависящих от `task_ids`: у них меняются
+/// `depends_on` и `blocked`, а от версии зависит ETag.
+pub async fn touch_dependents(db: impl PgExecutor<'_>, task_ids: &[Uuid]) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE tasks SET version = version + 1 \
+         WHERE id IN (SELECT task_id FROM task_dependencies WHERE depends_on_id = ANY($1))",
+    )
+    .bind(task_ids)
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Увеличивает версию всех задач списка: после изменения колонок доски
+/// у них может смениться `column`.
+pub async fn touch_list(db: impl PgExecutor<'_>, list_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE tasks SET version = version + 1 WHERE list_id = $1")
+        .bind(list_id)
+        .execute(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Возвращает задачу по ID из любого списка пользователя.
+/// Нужна для связей между задачами разных списков (зависимости и т.п.).
+pub async fn find_by_id_for_user(
+    pool: &PgPool,
+    task_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "SELECT t.* FROM tasks t JOIN todo_lists l ON l.id = t.list_id \
+         WHERE t.id = $1 AND l.user_id = $2 AND t.deleted_at IS NULL",
+    )
+    .bind(task_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(task)
+}
+
+/// Возвращает задачу, если пользователь — владелец списка или её исполнитель.
+pub async fn find_by_id_for_viewer(
     pool: &PgPool,
     list_id: Uuid,
     task_id: Uuid,
+    user_id: Uuid,
 ) -> Result<Option<Task>, sqlx::Error> {
     let task = sqlx::query_as::<_, Task>(
-        "SELECT * FROM tasks WHERE id = $1 AND list_id = $2",
+        "SELECT t.* FROM tasks t JOIN todo_lists l ON l.id = t.list_id \
+         WHERE t.id = $1 AND t.list_id = $2 AND t.deleted_at IS NULL AND (l.user_id = $3 OR t.assignee_id = $3)",
     )
     .bind(task_id)
     .bind(list_id)
+    .bind(user_id)
     .fetch_optional(pool)
     .await?;
 
     Ok(task)
 }
 
+/// Возвращает все неархивные задачи, назначенные пользователю, из любых списков.
+pub async fn find_all_by_assignee(pool: &PgPool, assignee_id: Uuid) -> Result<Vec<Task>, sqlx::Error> {
+    let tasks = sqlx::query_as::<_, Task>(
+        "SELECT * FROM tasks WHERE assignee_id = $1 AND deleted_at IS NULL AND archived_at IS NULL \
+         ORDER BY created_at ASC",
+    )
+    .bind(assignee_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(tasks)
+}
+
 /// Обновляет задачу (title + status). Возвращает обновлённую задачу.
+/// Отметки `started_at` / `completed_at` выставляются по новому статусу,
+/// уход из `done` возвращает задачу из архива;
+/// при смене статуса задача уходит в первую колонку его категории.
+/// `auto_complete = None` / `due_at = None` — значение не меняется.
+/// `expected_version` — обновить, только если версия строки совпадает.
+#[allow(clippy::too_many_arguments)]
 pub async fn update(
-    pool: &PgPool,
+    db: impl PgExecutor<'_>,
     list_id: Uuid,
     task_id: Uuid,
     title: &str,
-    status: &str,
+    status: TaskStatus,
+    auto_complete: Option<bool>,
+    due_at: Option<DateTime<Utc>>,
+    expected_version: Option<i32>,
 ) -> Result<Option<Task>, sqlx::Error> {
     let task = sqlx::query_as::<_, Task>(
-        "UPDATE tasks SET title = $1, status = $2, updated_at = now() \
-         WHERE id = $3 AND list_id = $4 RETURNING *",
+        "UPDATE tasks SET title = $1, status = $2, status_id = CASE WHEN status = $2 THEN status_id END, \
+         auto_complete = COALESCE($5, auto_complete), due_at = COALESCE($6, due_at), \
+         started_at = CASE WHEN $2 = 'in_progress' THEN COALESCE(started_at, now()) ELSE started_at END, \
+         completed_at = CASE WHEN $2 = 'done' THEN COALESCE(completed_at, now()) END, \
+         archived_at = CASE WHEN $2 = 'done' THEN archived_at END, \
+         version = version + 1, updated_at = now() \
+         WHERE id = $3 AND list_id = $4 AND deleted_at IS NULL AND ($7::int IS NULL OR version = $7) RETURNING *",
     )
     .bind(title)
-    .bind(status)
+    .bind(status.as_str())
     .bind(task_id)
     .bind(list_id)
-    .fetch_optional(pool)
+    .bind(auto_complete)
+    .bind(due_at)
+    .bind(expected_version)
+    .fetch_optional(db)
     .await?;
 
     Ok(task)
 }
 
-/// Удаляет задачу по ID внутри списка.
-pub async fn delete(pool: &PgPool, list_id: Uuid, task_id: Uuid) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query("DELETE FROM tasks WHERE id = $1 AND list_id = $2")
+/// Частично обновляет задачу: в UPDATE попадают только переданные поля,
+/// поэтому параллельные правки других полей не затираются.
+/// Пустой набор изменений ничего не пишет и просто возвращает задачу.
+pub async fn patch(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    task_id: Uuid,
+    changes: &TaskChanges<'_>,
+    expected_version: Option<i32>,
+) -> Result<Option<Task>, sqlx::Error> {
+    if changes.is_empty() {
+        return find_by_id(db, list_id, task_id).await;
+    }
+
+    let mut query = QueryBuilder::<Postgres>::new("UPDATE tasks SET ");
+    let mut set = query.separated(", ");
+    if let Some(title) = changes.title {
+        set.push("title = ").push_bind_unseparated(title);
+    }
+    if let Some(status) = changes.status {
+        set.push("status = ").push_bind_unseparated(status.as_str());
+        set.push("status_id = CASE WHEN status = ")
+            .push_bind_unseparated(status.as_str())
+            .push_unseparated(" THEN status_id END");
+        set.push("started_at = CASE WHEN ")
+            .push_bind_unseparated(status.as_str())
+            .push_unseparated(" = 'in_progress' THEN COALESCE(started_at, now()) ELSE started_at END");
+        set.push("completed_at = CASE WHEN ")
+            .push_bind_unseparated(status.as_str())
+            .push_unseparated(" = 'done' THEN COALESCE(completed_at, now()) END");
+        set.push("archived_at = CASE WHEN ")
+            .push_bind_unseparated(status.as_str())
+            .push_unseparated(" = 'done' THEN archived_at END");
+    }
+    if let Some(auto_complete) = changes.auto_complete {
+        set.push("auto_complete = ").push_bind_unseparated(auto_complete);
+    }
+    if let Some(due_at) = changes.due_at {
+        set.push("due_at = ").push_bind_unseparated(due_at);
+    }
+    if let Some(estimate_minutes) = changes.estimate_minutes {
+        set.push("estimate_minutes = ").push_bind_unseparated(estimate_minutes);
+    }
+    set.push("version = version + 1, updated_at = now()");
+
+    query
+        .push(" WHERE id = ")
+        .push_bind(task_id)
+        .push(" AND list_id = ")
+        .push_bind(list_id)
+        .push(" AND deleted_at IS NULL");
+    if let Some(version) = expected_version {
+        query.push(" AND version = ").push_bind(version);
+    }
+    query.push(" RETURNING *");
+
+    let task = query.build_query_as::<Task>().fetch_optional(db).await?;
+
+    Ok(task)
+}
+
+/// Меняет только статус задачи (без проверки списка — вызывается
+/// из сервисов, которые уже проверили доступ).
+/// С `expected_version` строка меняется, только если её версия совпадает.
+/// Переход в `todo` из `done` (переоткрытие) очищает `completed_at`.
+/// При смене статуса задача уходит в первую колонку его категории.
+pub async fn set_status(
+    db: impl PgExecutor<'_>,
+    task_id: Uuid,
+    status: TaskStatus,
+    expected_version: Option<i32>,
+) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET status = $1, status_id = CASE WHEN status = $1 THEN status_id END, \
+         started_at = CASE WHEN $1 = 'in_progress' THEN COALESCE(started_at, now()) ELSE started_at END, \
+         completed_at = CASE WHEN $1 = 'done' THEN COALESCE(completed_at, now()) END, \
+         archived_at = CASE WHEN $1 = 'done' THEN archived_at END, \
+         version = version + 1, updated_at = now() \
+         WHERE id = $2 AND deleted_at IS NULL AND ($3::int IS NULL OR version = $3) RETURNING *",
+    )
+    .bind(status.as_str())
+    .bind(task_id)
+    .bind(expected_version)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Ставит задачу в колонку доски `status_id`; статус задачи — по категории колонки.
+/// Проверки переходов и WIP-лимита делает сервис.
+pub async fn set_column(
+    db: impl PgExecutor<'_>,
+    task_id: Uuid,
+    status_id: Uuid,
+    status: TaskStatus,
+    expected_version: Option<i32>,
+) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET status = $1, status_id = $2, \
+         started_at = CASE WHEN $1 = 'in_progress' THEN COALESCE(started_at, now()) ELSE started_at END, \
+         completed_at = CASE WHEN $1 = 'done' THEN COALESCE(completed_at, now()) END, \
+         archived_at = CASE WHEN $1 = 'done' THEN archived_at END, \
+         version = version + 1, updated_at = now() \
+         WHERE id = $3 AND deleted_at IS NULL AND ($4::int IS NULL OR version = $4) RETURNING *",
+    )
+    .bind(status.as_str())
+    .bind(status_id)
+    .bind(task_id)
+    .bind(expected_version)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Назначает исполнителя задачи (`None` — снять исполнителя).
+pub async fn set_assignee(
+    db: impl PgExecutor<'_>,
+    task_id: Uuid,
+    assignee_id: Option<Uuid>,
+) -> Result<Task, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET assignee_id = $1, version = version + 1, updated_at = now() WHERE id = $2 RETURNING *",
+    )
+    .bind(assignee_id)
+    .bind(task_id)
+    .fetch_one(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Настраивает повторение задачи. `None` во всех полях — повторение выключено.
+pub async fn set_recurrence(
+    pool: &PgPool,
+    task_id: Uuid,
+    rrule: Option<&str>,
+    mode: Option<&str>,
+    interval_days: Option<i32>,
+    series_id: Option<Uuid>,
+) -> Result<Task, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET rrule = $1, recurrence_mode = $2, recurrence_interval_days = $3, \
+         series_id = COALESCE($4, series_id), version = version + 1, updated_at = now() \
+         WHERE id = $5 RETURNING *",
+    )
+    .bind(rrule)
+    .bind(mode)
+    .bind(interval_days)
+    .bind(series_id)
+    .bind(task_id)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(task)
+}
+
+/// Создаёт следующее повторение по образцу `template` со сроком `due_at`.
+///
+/// Копирует настройки повторения, исполнителя, оценку, метки и пункты чек-листа (неотмеченными)
+/// в одной транзакции — повторение появляется целиком или не появляется вовсе.
+pub async fn create_occurrence(
+    conn: &mut PgConnection,
+    template: &Task,
+    due_at: DateTime<Utc>,
+) -> Result<Task, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListTasks(template.list_id)).await?;
+    let position = position_repo::last_position(&mut tx, Scope::ListTasks(template.list_id)).await?;
+
+    let task = sqlx::query_as::<_, Task>(
+        "INSERT INTO tasks (list_id, title, auto_complete, due_at, rrule, recurrence_mode, \
+         recurrence_interval_days, series_id, assignee_id, position, estimate_minutes) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *",
+    )
+    .bind(template.list_id)
+    .bind(&template.title)
+    .bind(template.auto_complete)
+    .bind(due_at)
+    .bind(&template.rrule)
+    .bind(&template.recurrence_mode)
+    .bind(template.recurrence_interval_days)
+    .bind(template.series_id)
+    .bind(template.assignee_id)
+    .bind(position)
+    .bind(template.estimate_minutes)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO task_labels (task_id, label_id) \
+         SELECT $1, label_id FROM task_labels WHERE task_id = $2",
+    )
+    .bind(task.id)
+    .bind(template.id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO checklist_items (task_id, title, created_at) \
+         SELECT $1, title, created_at FROM checklist_items WHERE task_id = $2",
+    )
+    .bind(task.id)
+    .bind(template.id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(task)
+}
+
+/// Переносит задачу в конец другого списка. ID, даты и все связи (метки,
+/// чек-лист, зависимости, комментарии, вложения, напоминания) остаются на месте.
+/// На доске нового списка задача встаёт в первую колонку категории своего статуса.
+pub async fn move_to_list(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    from_list_id: Uuid,
+    to_list_id: Uuid,
+) -> Result<Option<Task>, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListTasks(to_list_id)).await?;
+    let position = position_repo::last_position(&mut tx, Scope::ListTasks(to_list_id)).await?;
+
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET list_id = $1, position = $4, status_id = NULL, version = version + 1, updated_at = now() \
+         WHERE id = $2 AND list_id = $3 AND deleted_at IS NULL RETURNING *",
+    )
+    .bind(to_list_id)
+    .bind(task_id)
+    .bind(from_list_id)
+    .bind(position)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(task)
+}
+
+/// Создаёт копию задачи в конце списка `to_list_id` одной транзакцией.
+///
+/// Копируются все атрибуты задачи (статус с отметками переходов, срок, оценка, повторение, исполнитель;
+/// колонка доски — только при копировании в тот же список),
+/// метки, пункты чек-листа (с отметками) и зависимости. Повторяющаяся копия
+/// начинает собственную серию. Комментарии, вложения, напоминания и учтённое время
+/// принадлежат исходной задаче и не копируются.
+pub async fn copy_to_list(
+    conn: &mut PgConnection,
+    task_id: Uuid,
+    to_list_id: Uuid,
+) -> Result<Task, sqlx::Error> {
+    let mut tx = conn.begin().await?;
+    position_repo::lock_scope(&mut tx, Scope::ListTasks(to_list_id)).await?;
+    let position = position_repo::last_position(&mut tx, Scope::ListTasks(to_list_id)).await?;
+
+    let task = sqlx::query_as::<_, Task>(
+        "WITH new_task AS (SELECT gen_random_uuid() AS id) \
+         INSERT INTO tasks (id, list_id, title, status, auto_complete, due_at, rrule, \
+             recurrence_mode, recurrence_interval_days, series_id, assignee_id, position, \
+             started_at, completed_at, status_id, estimate_minutes) \
+         SELECT n.id, $2, t.title, t.status, t.auto_complete, t.due_at, t.rrule, \
+             t.recurrence_mode, t.recurrence_interval_days, \
+             CASE WHEN t.recurrence_mode IS NOT NULL THEN n.id END, t.assignee_id, $3, \
+             t.started_at, t.completed_at, CASE WHEN t.list_id = $2 THEN t.status_id END, \
+             t.estimate_minutes \
+         FROM tasks t, new_task n WHERE t.id = $1 \
+         RETURNING *",
+    )
+    .bind(task_id)
+    .bind(to_list_id)
+    .bind(position)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO task_labels (task_id, label_id) \
+         SELECT $1, label_id FROM task_labels WHERE task_id = $2",
+    )
+    .bind(task.id)
+    .bind(task_id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO checklist_items (task_id, title, done, created_at) \
+         SELECT $1, title, done, created_at FROM checklist_items WHERE task_id = $2",
+    )
+    .bind(task.id)
+    .bind(task_id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO task_dependencies (task_id, depends_on_id) \
+         SELECT $1, depends_on_id FROM task_dependencies WHERE task_id = $2",
+    )
+    .bind(task.id)
+    .bind(task_id)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(task)
+}
+
+/// Переносит задачу списка в корзину (с `expected_version` — только эту версию).
+/// Запущенные по задаче таймеры останавливаются тем же запросом.
+pub async fn delete(
+    db: impl PgExecutor<'_>,
+    list_id: Uuid,
+    task_id: Uuid,
+    expected_version: Option<i32>,
+) -> Result<bool, sqlx::Error> {
+    let deleted: i64 = sqlx::query_scalar(
+        "WITH deleted AS ( \
+             UPDATE tasks SET deleted_at = now(), version = version + 1, updated_at = now() \
+             WHERE id = $1 AND list_id = $2 AND deleted_at IS NULL AND ($3::int IS NULL OR version = $3) \
+             RETURNING id \
+         ), stopped AS ( \
+             UPDATE time_entries SET ended_at = now() \
+             WHERE task_id IN (SELECT id FROM deleted) AND ended_at IS NULL \
+         ) \
+         SELECT count(*) FROM deleted",
+    )
         .bind(task_id)
         .bind(list_id)
-        .execute(pool)
+        .bind(expected_version)
+        .fetch_one(db)
         .await?;
 
-    Ok(result.rows_affected() > 0)
+    Ok(deleted > 0)
+}
+
+/// Задачи пользователя в корзине, удалённые отдельно от своего списка
+/// (сначала недавно удалённые). Задачи, удалённые вместе со списком,
+/// показываются в корзине как часть списка.
+pub async fn find_deleted_by_user(pool: &PgPool, user_id: Uuid) -> Result<Vec<TrashedTask>, sqlx::Error> {
+    let tasks = sqlx::query_as::<_, TrashedTask>(
+        "SELECT t.id, t.list_id, l.title AS list_title, l.deleted_at IS NOT NULL AS list_deleted, \
+             t.title, t.status, t.deleted_at \
+         FROM tasks t JOIN todo_lists l ON l.id = t.list_id \
+         WHERE l.user_id = $1 AND t.deleted_at IS NOT NULL \
+         AND (l.deleted_at IS NULL OR t.deleted_at <> l.deleted_at) \
+         ORDER BY t.deleted_at DESC, t.id",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(tasks)
+}
+
+/// Возвращает задачу из корзины, если список принадлежит пользователю.
+pub async fn find_deleted(
+    pool: &PgPool,
+    list_id: Uuid,
+    task_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<TrashedTask>, sqlx::Error> {
+    let task = sqlx::query_as::<_, TrashedTask>(
+        "SELECT t.id, t.list_id, l.title AS list_title, l.deleted_at IS NOT NULL AS list_deleted, \
+             t.title, t.status, t.deleted_at \
+         FROM tasks t JOIN todo_lists l ON l.id = t.list_id \
+         WHERE t.id = $1 AND t.list_id = $2 AND l.user_id = $3 AND t.deleted_at IS NOT NULL",
+    )
+    .bind(task_id)
+    .bind(list_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(task)
+}
+
+/// Возвращает задачу из корзины, если её список не удалён.
+/// `None` — задача не в корзине или список тоже в корзине.
+pub async fn restore(db: impl PgExecutor<'_>, task_id: Uuid) -> Result<Option<Task>, sqlx::Error> {
+    let task = sqlx::query_as::<_, Task>(
+        "UPDATE tasks SET deleted_at = NULL, version = version + 1, updated_at = now() \
+         WHERE id = $1 AND deleted_at IS NOT NULL \
+         AND EXISTS (SELECT 1 FROM todo_lists l WHERE l.id = tasks.list_id AND l.deleted_at IS NULL) \
+         RETURNING *",
+    )
+    .bind(task_id)
+    .fetch_optional(db)
+    .await?;
+
+    Ok(task)
+}
+
+/// Окончательно удаляет задачи, попавшие в корзину раньше `before`.
+/// Возвращает количество удалённых задач.
+pub async fn purge_deleted(db: impl PgExecutor<'_>, before: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM tasks WHERE deleted_at < $1")
+        .bind(before)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Архивирует выполненные задачи списков с правилом `auto_archive_days`,
+/// если с перехода в `done` прошло не меньше указанного числа дней.
+/// Архивные списки и корзина не затрагиваются. Возвращает количество задач.
+pub async fn archive_completed(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE tasks t SET archived_at = now(), version = t.version + 1, updated_at = now() \
+         FROM todo_lists l \
+         WHERE l.id = t.list_id AND l.auto_archive_days IS NOT NULL \
+         AND l.archived_at IS NULL AND l.deleted_at IS NULL \
+         AND t.deleted_at IS NULL AND t.archived_at IS NULL AND t.status = 'done' \
+         AND t.completed_at <= now() - make_interval(days => l.auto_archive_days)",
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
 }
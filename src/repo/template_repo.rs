@@ -0,0 +1,108 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::list_template::{ListTemplate, TemplateTask};
+
+/// Создаёт шаблон пользователя с задачами `tasks` (название и смещение срока)
+/// в переданном порядке — задачи вставляются одним запросом. Возвращает шаблон и его задачи.
+pub async fn create(
+    pool: &PgPool,
+    user_id: Uuid,
+    title: &str,
+    tasks: &[(String, Option<i32>)],
+) -> Result<(ListTemplate, Vec<TemplateTask>), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let template = sqlx::query_as::<_, ListTemplate>(
+        "INSERT INTO list_templates (user_id, title) VALUES ($1, $2) RETURNING *",
+    )
+    .bind(user_id)
+    .bind(title)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    let (titles, offsets): (Vec<&str>, Vec<Option<i32>>) =
+        tasks.iter().map(|(title, offset)| (title.as_str(), *offset)).unzip();
+    let mut created = sqlx::query_as::<_, TemplateTask>(
+        "INSERT INTO list_template_tasks (template_id, position, title, due_offset_minutes) \
+         SELECT $1, t.ordinality - 1, t.title, t.due_offset_minutes \
+         FROM UNNEST($2::text[], $3::int[]) WITH ORDINALITY AS t(title, due_offset_minutes, ordinality) \
+         ORDER BY t.ordinality \
+         RETURNING *",
+    )
+    .bind(template.id)
+    .bind(&titles)
+    .bind(&offsets)
+    .fetch_all(&mut *tx)
+    .await?;
+    // Порядок строк RETURNING не гарантирован.
+    created.sort_by_key(|task| task.position);
+
+    tx.commit().await?;
+
+    Ok((template, created))
+}
+
+/// Возвращает страницу шаблонов пользователя: по названию, после ключа
+/// `after` (название, ID).
+pub async fn find_page_by_user(
+    pool: &PgPool,
+    user_id: Uuid,
+    after: Option<(&str, Uuid)>,
+    limit: i64,
+) -> Result<Vec<ListTemplate>, sqlx::Error> {
+    let (after_title, after_id) = after.unzip();
+    let templates = sqlx::query_as::<_, ListTemplate>(
+        "SELECT * FROM list_templates WHERE user_id = $1 \
+         AND ($2::text IS NULL OR (title, id) > ($2, $3)) \
+         ORDER BY title, id LIMIT $4",
+    )
+    .bind(user_id)
+    .bind(after_title)
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(templates)
+}
+
+/// Возвращает один шаблон по ID, только если он принадлежит пользователю.
+pub async fn find_by_id(
+    pool: &PgPool,
+    template_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<ListTemplate>, sqlx::Error> {
+    let template = sqlx::query_as::<_, ListTemplate>(
+        "SELECT * FROM list_templates WHERE id = $1 AND user_id = $2",
+    )
+    .bind(template_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(template)
+}
+
+/// Возвращает задачи шаблонов `template_ids` — по шаблонам, внутри шаблона по порядку.
+pub async fn find_tasks(pool: &PgPool, template_ids: &[Uuid]) -> Result<Vec<TemplateTask>, sqlx::Error> {
+    let tasks = sqlx::query_as::<_, TemplateTask>(
+        "SELECT * FROM list_template_tasks WHERE template_id = ANY($1) ORDER BY template_id, position",
+    )
+    .bind(template_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(tasks)
+}
+
+/// Удаляет шаблон вместе с задачами. Возвращает true, если шаблон был удалён.
+pub async fn delete(pool: &PgPool, template_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM list_templates WHERE id = $1 AND user_id = $2")
+        .bind(template_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
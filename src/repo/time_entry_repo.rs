@@ -0,0 +1,174 @@
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::time_entry::{TimeEntry, TimeReportRow, TrackedTime};
+
+/// Запускает таймер пользователя на задаче.
+/// Возвращает `None`, если у пользователя уже идёт таймер (на любой задаче).
+///
+/// Все изменения записей увеличивают версию задачи: учтённое время входит в её ETag.
+pub async fn start(pool: &PgPool, task_id: Uuid, user_id: Uuid) -> Result<Option<TimeEntry>, sqlx::Error> {
+    let entry = sqlx::query_as::<_, TimeEntry>(
+        "WITH entry AS ( \
+             INSERT INTO time_entries (task_id, user_id, started_at) VALUES ($1, $2, now()) \
+             ON CONFLICT (user_id) WHERE ended_at IS NULL DO NOTHING \
+             RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM entry) \
+         ) \
+         SELECT * FROM entry",
+    )
+    .bind(task_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Останавливает таймер пользователя на задаче (`None` — таймер не идёт).
+pub async fn stop(pool: &PgPool, task_id: Uuid, user_id: Uuid) -> Result<Option<TimeEntry>, sqlx::Error> {
+    let entry = sqlx::query_as::<_, TimeEntry>(
+        "WITH entry AS ( \
+             UPDATE time_entries SET ended_at = now() \
+             WHERE task_id = $1 AND user_id = $2 AND ended_at IS NULL RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM entry) \
+         ) \
+         SELECT * FROM entry",
+    )
+    .bind(task_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Возвращает идущий таймер пользователя.
+pub async fn find_running(pool: &PgPool, user_id: Uuid) -> Result<Option<TimeEntry>, sqlx::Error> {
+    let entry = sqlx::query_as::<_, TimeEntry>(
+        "SELECT * FROM time_entries WHERE user_id = $1 AND ended_at IS NULL",
+    )
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Добавляет завершённую запись вручную.
+pub async fn create(
+    pool: &PgPool,
+    task_id: Uuid,
+    user_id: Uuid,
+    started_at: DateTime<Utc>,
+    ended_at: DateTime<Utc>,
+    note: Option<&str>,
+) -> Result<TimeEntry, sqlx::Error> {
+    let entry = sqlx::query_as::<_, TimeEntry>(
+        "WITH entry AS ( \
+             INSERT INTO time_entries (task_id, user_id, started_at, ended_at, note) \
+             VALUES ($1, $2, $3, $4, $5) RETURNING * \
+         ), touched AS ( \
+             UPDATE tasks SET version = version + 1 WHERE id = $1 \
+         ) \
+         SELECT * FROM entry",
+    )
+    .bind(task_id)
+    .bind(user_id)
+    .bind(started_at)
+    .bind(ended_at)
+    .bind(note)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(entry)
+}
+
+/// Возвращает все записи задачи (по времени начала).
+pub async fn find_all_by_task(pool: &PgPool, task_id: Uuid) -> Result<Vec<TimeEntry>, sqlx::Error> {
+    let entries = sqlx::query_as::<_, TimeEntry>(
+        "SELECT * FROM time_entries WHERE task_id = $1 ORDER BY started_at ASC",
+    )
+    .bind(task_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(entries)
+}
+
+/// Удаляет запись задачи, только если её автор — `user_id`.
+pub async fn delete(pool: &PgPool, task_id: Uuid, entry_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query(
+        "WITH deleted AS ( \
+             DELETE FROM time_entries WHERE id = $1 AND task_id = $2 AND user_id = $3 RETURNING task_id \
+         ) \
+         UPDATE tasks SET version = version + 1 WHERE id IN (SELECT task_id FROM deleted)",
+    )
+    .bind(entry_id)
+    .bind(task_id)
+    .bind(user_id)
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Суммарное время сразу по нескольким задачам (идущие таймеры — до текущего момента).
+/// Задачи без записей в результат не попадают.
+pub async fn tracked_by_tasks(pool: &PgPool, task_ids: &[Uuid]) -> Result<Vec<TrackedTime>, sqlx::Error> {
+    let tracked = sqlx::query_as::<_, TrackedTime>(
+        "SELECT task_id, \
+             SUM(EXTRACT(EPOCH FROM COALESCE(ended_at, now()) - started_at))::bigint AS seconds \
+         FROM time_entries WHERE task_id = ANY($1) GROUP BY task_id",
+    )
+    .bind(task_ids)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(tracked)
+}
+
+/// Время по спискам пользователя за `[from, to)`, сгруппированное по дням (UTC).
+/// Запись обрезается по границам периода и делится по полуночам: каждый день
+/// получает свою часть. Время относится к текущему списку задачи.
+/// Идущие таймеры и задачи в корзине не учитываются.
+/// `list_id` — только этот список.
+pub async fn report(
+    pool: &PgPool,
+    user_id: Uuid,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    list_id: Option<Uuid>,
+) -> Result<Vec<TimeReportRow>, sqlx::Error> {
+    let rows = sqlx::query_as::<_, TimeReportRow>(
+        "WITH spans AS ( \
+             SELECT l.id AS list_id, l.title AS list_title, \
+                 GREATEST(e.started_at, $2) AT TIME ZONE 'UTC' AS started, \
+                 LEAST(e.ended_at, $3) AT TIME ZONE 'UTC' AS ended \
+             FROM time_entries e \
+             JOIN tasks t ON t.id = e.task_id \
+             JOIN todo_lists l ON l.id = t.list_id \
+             WHERE l.user_id = $1 AND t.deleted_at IS NULL AND e.ended_at IS NOT NULL \
+             AND e.started_at < $3 AND e.ended_at > $2 \
+             AND ($4::uuid IS NULL OR l.id = $4) \
+         ) \
+         SELECT s.list_id, s.list_title, d.day::date AS day, \
+             SUM(EXTRACT(EPOCH FROM LEAST(s.ended, d.day + interval '1 day') - GREATEST(s.started, d.day)))::bigint AS seconds \
+         FROM spans s \
+         CROSS JOIN LATERAL generate_series(date_trunc('day', s.started), s.ended, interval '1 day') AS d(day) \
+         WHERE LEAST(s.ended, d.day + interval '1 day') > GREATEST(s.started, d.day) \
+         GROUP BY s.list_id, s.list_title, d.day \
+         ORDER BY day, s.list_title, s.list_id",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .bind(list_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows)
+}
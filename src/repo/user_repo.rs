@@ -34,3 +34,13 @@ pub async fn find_by_id(pool: &PgPool, id: uuid::Uuid) -> sqlx::Result<Option<Us
 
     Ok(user)
 }
+
+/// Возвращает пользователей по набору ID (для встраивания в ответы).
+pub async fn find_by_ids(pool: &PgPool, ids: &[uuid::Uuid]) -> sqlx::Result<Vec<User>> {
+    let users = sqlx::query_as::<_, User>("SELECT * FROM users WHERE id = ANY($1)")
+        .bind(ids)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(users)
+}
@@ -0,0 +1,118 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::models::view::SavedView;
+
+/// Создаёт сохранённое представление пользователя.
+pub async fn create(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    filter: &str,
+    sort: &str,
+) -> Result<SavedView, sqlx::Error> {
+    let view = sqlx::query_as::<_, SavedView>(
+        "INSERT INTO views (user_id, name, filter, sort) VALUES ($1, $2, $3, $4) RETURNING *",
+    )
+    .bind(user_id)
+    .bind(name)
+    .bind(filter)
+    .bind(sort)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(view)
+}
+
+/// Возвращает страницу сохранённых представлений пользователя: по имени,
+/// после ключа `after` (имя, ID).
+pub async fn find_page_by_user(
+    pool: &PgPool,
+    user_id: Uuid,
+    after: Option<(&str, Uuid)>,
+    limit: i64,
+) -> Result<Vec<SavedView>, sqlx::Error> {
+    let (after_name, after_id) = after.unzip();
+    let views = sqlx::query_as::<_, SavedView>(
+        "SELECT * FROM views WHERE user_id = $1 \
+         AND ($2::text IS NULL OR (name, id) > ($2, $3)) \
+         ORDER BY name, id LIMIT $4",
+    )
+    .bind(user_id)
+    .bind(after_name)
+    .bind(after_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(views)
+}
+
+/// Возвращает одно представление по ID, только если оно принадлежит пользователю.
+pub async fn find_by_id(
+    pool: &PgPool,
+    view_id: Uuid,
+    user_id: Uuid,
+) -> Result<Option<SavedView>, sqlx::Error> {
+    let view = sqlx::query_as::<_, SavedView>(
+        "SELECT * FROM views WHERE id = $1 AND user_id = $2",
+    )
+    .bind(view_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(view)
+}
+
+/// Ищет представление пользователя по имени.
+pub async fn find_by_name(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+) -> Result<Option<SavedView>, sqlx::Error> {
+    let view = sqlx::query_as::<_, SavedView>(
+        "SELECT * FROM views WHERE user_id = $1 AND name = $2",
+    )
+    .bind(user_id)
+    .bind(name)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(view)
+}
+
+/// Обновляет представление (name + filter + sort).
+pub async fn update(
+    pool: &PgPool,
+    view_id: Uuid,
+    user_id: Uuid,
+    name: &str,
+    filter: &str,
+    sort: &str,
+) -> Result<Option<SavedView>, sqlx::Error> {
+    let view = sqlx::query_as::<_, SavedView>(
+        "UPDATE views SET name = $1, filter = $2, sort = $3, updated_at = now() \
+         WHERE id = $4 AND user_id = $5 RETURNING *",
+    )
+    .bind(name)
+    .bind(filter)
+    .bind(sort)
+    .bind(view_id)
+    .bind(user_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(view)
+}
+
+/// Удаляет представление. Возвращает `true`, если оно было.
+pub async fn delete(pool: &PgPool, view_id: Uuid, user_id: Uuid) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM views WHERE id = $1 AND user_id = $2")
+        .bind(view_id)
+        .bind(user_id)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
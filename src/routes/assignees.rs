@@ -0,0 +1,22 @@
+use axum::routing::{delete, get, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для исполнителей задач.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // PUT /lists/:list_id/tasks/:task_id/assignee — назначить исполнителя
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/assignee",
+            put(handlers::assignees::assign),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/assignee — снять исполнителя
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/assignee",
+            delete(handlers::assignees::unassign),
+        )
+        // GET /me/assigned-tasks — задачи, назначенные мне
+        .route("/me/assigned-tasks", get(handlers::assignees::assigned_to_me))
+}
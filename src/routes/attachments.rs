@@ -0,0 +1,38 @@
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::services::attachments::MAX_ATTACHMENT_SIZE;
+use crate::state::AppState;
+
+/// Запас на заголовки и границы multipart поверх размера файла.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+/// Суб-роутер для вложений задачи.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/attachments — загрузить файл (multipart, поле "file")
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/attachments",
+            post(handlers::attachments::upload)
+                .layer(DefaultBodyLimit::max(MAX_ATTACHMENT_SIZE + MULTIPART_OVERHEAD)),
+        )
+        // GET /lists/:list_id/tasks/:task_id/attachments — вложения задачи
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/attachments",
+            get(handlers::attachments::get_all),
+        )
+        // GET /lists/:list_id/tasks/:task_id/attachments/:attachment_id — скачать
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/attachments/{attachment_id}",
+            get(handlers::attachments::download),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/attachments/:attachment_id — удалить
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/attachments/{attachment_id}",
+            delete(handlers::attachments::delete),
+        )
+        // GET /me/storage — использование хранилища и квота
+        .route("/me/storage", get(handlers::attachments::storage_usage))
+}
@@ -0,0 +1,24 @@
+use axum::routing::{delete, get, post, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для чек-листа внутри задачи.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/items — добавить пункт
+        .route("/lists/{list_id}/tasks/{task_id}/items", post(handlers::checklist::create))
+        // GET /lists/:list_id/tasks/:task_id/items — все пункты
+        .route("/lists/{list_id}/tasks/{task_id}/items", get(handlers::checklist::get_all))
+        // PUT /lists/:list_id/tasks/:task_id/items/:item_id — обновить пункт
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/items/{item_id}",
+            put(handlers::checklist::update),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/items/:item_id — удалить пункт
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/items/{item_id}",
+            delete(handlers::checklist::delete),
+        )
+}
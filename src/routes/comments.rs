@@ -0,0 +1,24 @@
+use axum::routing::{delete, get, post, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для комментариев к задаче.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/comments — добавить комментарий
+        .route("/lists/{list_id}/tasks/{task_id}/comments", post(handlers::comments::create))
+        // GET /lists/:list_id/tasks/:task_id/comments — все комментарии
+        .route("/lists/{list_id}/tasks/{task_id}/comments", get(handlers::comments::get_all))
+        // PUT /lists/:list_id/tasks/:task_id/comments/:comment_id — отредактировать (только автор)
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/comments/{comment_id}",
+            put(handlers::comments::update),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/comments/:comment_id — удалить (только автор)
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/comments/{comment_id}",
+            delete(handlers::comments::delete),
+        )
+}
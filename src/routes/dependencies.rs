@@ -0,0 +1,20 @@
+use axum::routing::{delete, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для зависимостей между задачами ("blocked by").
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/dependencies — добавить зависимость
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/dependencies",
+            post(handlers::dependencies::add),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/dependencies/:depends_on_id — удалить зависимость
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/dependencies/{depends_on_id}",
+            delete(handlers::dependencies::remove),
+        )
+}
@@ -0,0 +1,17 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для журнала изменений.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // GET /lists/:list_id/history — журнал списка и его задач
+        .route("/lists/{list_id}/history", get(handlers::history::list_history))
+        // GET /lists/:list_id/tasks/:task_id/history — журнал задачи
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/history",
+            get(handlers::history::task_history),
+        )
+}
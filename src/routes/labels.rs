@@ -0,0 +1,20 @@
+use axum::routing::{delete, get, post, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для меток (общие для всех списков пользователя).
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /labels — создать метку
+        .route("/labels", post(handlers::labels::create))
+        // GET /labels — все метки пользователя
+        .route("/labels", get(handlers::labels::get_all))
+        // GET /labels/:id — одна метка
+        .route("/labels/{id}", get(handlers::labels::get_one))
+        // PUT /labels/:id — обновить метку
+        .route("/labels/{id}", put(handlers::labels::update))
+        // DELETE /labels/:id — удалить метку
+        .route("/labels/{id}", delete(handlers::labels::delete))
+}
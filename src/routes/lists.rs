@@ -1,4 +1,4 @@
-use axum::routing::{delete, get, post, put};
+use axum::routing::{delete, get, patch, post, put};
 use axum::Router;
 
 use crate::handlers;
@@ -15,6 +15,14 @@ pub fn router() -> Router<AppState> {
         .route("/lists/{id}", get(handlers::lists::get_one))
         // PUT /lists/:id — обновить список
         .route("/lists/{id}", put(handlers::lists::update))
+        // PATCH /lists/:id — частично обновить список
+        .route("/lists/{id}", patch(handlers::lists::patch))
         // DELETE /lists/:id — удалить список
         .route("/lists/{id}", delete(handlers::lists::delete))
+        // PATCH /lists/:id/position — переставить список
+        .route("/lists/{id}/position", patch(handlers::lists::reposition))
+        // POST /lists/:id/archive — отправить список в архив
+        .route("/lists/{id}/archive", post(handlers::lists::archive))
+        // POST /lists/:id/unarchive — вернуть список из архива
+        .route("/lists/{id}/unarchive", post(handlers::lists::unarchive))
 }
@@ -1,4 +1,20 @@
+pub mod assignees;
+pub mod attachments;
 pub mod auth;
+pub mod checklist;
+pub mod comments;
+pub mod dependencies;
+pub mod history;
 pub mod health;
+pub mod labels;
 pub mod lists;
+pub mod quick_add;
+pub mod recurrence;
+pub mod reminders;
+pub mod search;
+pub mod statuses;
 pub mod tasks;
+pub mod templates;
+pub mod time_tracking;
+pub mod trash;
+pub mod views;
@@ -0,0 +1,12 @@
+use axum::routing::post;
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для быстрого добавления задач из свободного текста.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /tasks/quick-add — создать задачу из текста
+        .route("/tasks/quick-add", post(handlers::quick_add::quick_add))
+}
@@ -0,0 +1,25 @@
+use axum::routing::{delete, get, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для повторяющихся задач.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // PUT /lists/:list_id/tasks/:task_id/recurrence — настроить повторение
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/recurrence",
+            put(handlers::recurrence::set),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/recurrence — выключить повторение
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/recurrence",
+            delete(handlers::recurrence::clear),
+        )
+        // GET /lists/:list_id/tasks/:task_id/recurrence/history — история выполнений
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/recurrence/history",
+            get(handlers::recurrence::history),
+        )
+}
@@ -0,0 +1,30 @@
+use axum::routing::{delete, get, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для напоминаний по задачам.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/reminders — создать напоминание
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/reminders",
+            post(handlers::reminders::create),
+        )
+        // GET /lists/:list_id/tasks/:task_id/reminders — напоминания задачи
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/reminders",
+            get(handlers::reminders::get_all),
+        )
+        // POST /lists/:list_id/tasks/:task_id/reminders/:reminder_id/snooze — отложить
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/reminders/{reminder_id}/snooze",
+            post(handlers::reminders::snooze),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/reminders/:reminder_id — удалить
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/reminders/{reminder_id}",
+            delete(handlers::reminders::delete),
+        )
+}
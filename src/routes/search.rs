@@ -0,0 +1,12 @@
+use axum::routing::get;
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для поиска.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // GET /search?q= — поиск по спискам и задачам
+        .route("/search", get(handlers::search::search))
+}
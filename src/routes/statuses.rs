@@ -0,0 +1,27 @@
+use axum::routing::{delete, get, patch, post, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для статусов списка и канбан-доски.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/statuses — добавить статус (колонку)
+        .route("/lists/{list_id}/statuses", post(handlers::statuses::create))
+        // GET /lists/:list_id/statuses — статусы списка в порядке колонок
+        .route("/lists/{list_id}/statuses", get(handlers::statuses::get_all))
+        // PUT /lists/:list_id/statuses/:status_id — обновить статус
+        .route("/lists/{list_id}/statuses/{status_id}", put(handlers::statuses::update))
+        // DELETE /lists/:list_id/statuses/:status_id — удалить статус
+        .route("/lists/{list_id}/statuses/{status_id}", delete(handlers::statuses::delete))
+        // PATCH /lists/:list_id/statuses/:status_id/position — переставить колонку
+        .route(
+            "/lists/{list_id}/statuses/{status_id}/position",
+            patch(handlers::statuses::reposition),
+        )
+        // GET /lists/:list_id/board — задачи, сгруппированные по колонкам
+        .route("/lists/{list_id}/board", get(handlers::statuses::board))
+        // PUT /lists/:list_id/tasks/:task_id/column — переместить задачу в колонку
+        .route("/lists/{list_id}/tasks/{task_id}/column", put(handlers::statuses::move_to_column))
+}
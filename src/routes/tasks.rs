@@ -1,4 +1,4 @@
-use axum::routing::{delete, get, post, put};
+use axum::routing::{delete, get, patch, post, put};
 use axum::Router;
 
 use crate::handlers;
@@ -14,10 +14,37 @@ pub fn router() -> Router<AppState> {
         .route("/lists/{list_id}/tasks", post(handlers::tasks::create))
         // GET /lists/:list_id/tasks — все задачи списка
         .route("/lists/{list_id}/tasks", get(handlers::tasks::get_all))
+        // POST /lists/:list_id/tasks/bulk — пакет операций в одной транзакции
+        .route("/lists/{list_id}/tasks/bulk", post(handlers::tasks::bulk))
         // GET /lists/:list_id/tasks/:task_id — одна задача
         .route("/lists/{list_id}/tasks/{task_id}", get(handlers::tasks::get_one))
         // PUT /lists/:list_id/tasks/:task_id — обновить задачу
         .route("/lists/{list_id}/tasks/{task_id}", put(handlers::tasks::update))
+        // PATCH /lists/:list_id/tasks/:task_id — частично обновить задачу
+        .route("/lists/{list_id}/tasks/{task_id}", patch(handlers::tasks::patch))
+        // PUT /lists/:list_id/tasks/:task_id/status — сменить статус (владелец или исполнитель)
+        .route("/lists/{list_id}/tasks/{task_id}/status", put(handlers::tasks::update_status))
+        // POST /lists/:list_id/tasks/:task_id/reopen — переоткрыть выполненную задачу
+        .route("/lists/{list_id}/tasks/{task_id}/reopen", post(handlers::tasks::reopen))
         // DELETE /lists/:list_id/tasks/:task_id — удалить задачу
         .route("/lists/{list_id}/tasks/{task_id}", delete(handlers::tasks::delete))
+        // PATCH /lists/:list_id/tasks/:task_id/position — переставить внутри списка
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/position",
+            patch(handlers::tasks::reposition),
+        )
+        // POST /lists/:list_id/tasks/:task_id/move — перенести в другой список
+        .route("/lists/{list_id}/tasks/{task_id}/move", post(handlers::tasks::move_task))
+        // POST /lists/:list_id/tasks/:task_id/copy — скопировать в список
+        .route("/lists/{list_id}/tasks/{task_id}/copy", post(handlers::tasks::copy_task))
+        // POST /lists/:list_id/tasks/:task_id/labels/:label_id — навесить метку
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/labels/{label_id}",
+            post(handlers::tasks::attach_label),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/labels/:label_id — снять метку
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/labels/{label_id}",
+            delete(handlers::tasks::detach_label),
+        )
 }
@@ -0,0 +1,20 @@
+use axum::routing::{delete, get, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для шаблонов списков.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:id/template — сохранить список как шаблон
+        .route("/lists/{id}/template", post(handlers::templates::save))
+        // GET /templates — встроенные и сохранённые шаблоны
+        .route("/templates", get(handlers::templates::get_all))
+        // GET /templates/:id — один шаблон
+        .route("/templates/{id}", get(handlers::templates::get_one))
+        // DELETE /templates/:id — удалить шаблон
+        .route("/templates/{id}", delete(handlers::templates::delete))
+        // POST /templates/:id/instantiate — создать список из шаблона
+        .route("/templates/{id}/instantiate", post(handlers::templates::instantiate))
+}
@@ -0,0 +1,41 @@
+use axum::routing::{delete, get, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для учёта времени по задачам.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /lists/:list_id/tasks/:task_id/timer/start — запустить таймер
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/timer/start",
+            post(handlers::time_tracking::start_timer),
+        )
+        // POST /lists/:list_id/tasks/:task_id/timer/stop — остановить таймер
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/timer/stop",
+            post(handlers::time_tracking::stop_timer),
+        )
+        // POST /lists/:list_id/tasks/:task_id/time-entries — добавить запись вручную
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/time-entries",
+            post(handlers::time_tracking::create_entry),
+        )
+        // GET /lists/:list_id/tasks/:task_id/time-entries — записи по задаче
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/time-entries",
+            get(handlers::time_tracking::get_entries),
+        )
+        // DELETE /lists/:list_id/tasks/:task_id/time-entries/:entry_id — удалить свою запись
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/time-entries/{entry_id}",
+            delete(handlers::time_tracking::delete_entry),
+        )
+        // GET /lists/:list_id/time — сводка по времени списка
+        .route("/lists/{list_id}/time", get(handlers::time_tracking::list_time))
+        // GET /me/timer — мой идущий таймер
+        .route("/me/timer", get(handlers::time_tracking::running_timer))
+        // GET /reports/time — отчёт по спискам и дням (JSON или CSV)
+        .route("/reports/time", get(handlers::time_tracking::report))
+}
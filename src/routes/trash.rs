@@ -0,0 +1,19 @@
+use axum::routing::{get, post};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для корзины.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // GET /trash — удалённые списки и задачи
+        .route("/trash", get(handlers::trash::get_trash))
+        // POST /lists/:list_id/restore — восстановить список (с его задачами)
+        .route("/lists/{list_id}/restore", post(handlers::trash::restore_list))
+        // POST /lists/:list_id/tasks/:task_id/restore — восстановить задачу
+        .route(
+            "/lists/{list_id}/tasks/{task_id}/restore",
+            post(handlers::trash::restore_task),
+        )
+}
@@ -0,0 +1,22 @@
+use axum::routing::{delete, get, post, put};
+use axum::Router;
+
+use crate::handlers;
+use crate::state::AppState;
+
+/// Суб-роутер для представлений (задачи из всех списков по фильтру).
+pub fn router() -> Router<AppState> {
+    Router::new()
+        // POST /views — сохранить представление
+        .route("/views", post(handlers::views::create))
+        // GET /views — встроенные и сохранённые представления
+        .route("/views", get(handlers::views::get_all))
+        // GET /views/:id — одно представление
+        .route("/views/{id}", get(handlers::views::get_one))
+        // PUT /views/:id — обновить представление
+        .route("/views/{id}", put(handlers::views::update))
+        // DELETE /views/:id — удалить представление
+        .route("/views/{id}", delete(handlers::views::delete))
+        // GET /views/:id/tasks — задачи представления
+        .route("/views/{id}/tasks", get(handlers::views::tasks))
+}
@@ -0,0 +1,69 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::tasks::{AssignTaskRequest, TaskResponse};
+use crate::errors::AppError;
+use crate::repo::{task_repo, user_repo};
+use crate::services;
+use crate::services::tasks::{find_editable_task, lock_task, to_responses, to_single_response};
+
+/// Назначает исполнителя задачи. Назначать может только владелец списка;
+/// исполнитель ищется по email среди всех зарегистрированных пользователей.
+///
+/// Незнакомый email и чужая/несуществующая задача дают один и тот же 404
+/// (`not_assignable`), а email ищется в любом случае — по ответу нельзя
+/// перебором узнать, кто зарегистрирован.
+pub async fn assign_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &AssignTaskRequest,
+) -> Result<TaskResponse, AppError> {
+    let assignee = user_repo::find_by_email(pool, body.email.trim()).await?;
+    match find_editable_task(pool, list_id, user_id, task_id).await {
+        Ok(_) => {}
+        Err(AppError::NotFound(_)) => return Err(not_assignable()),
+        Err(err) => return Err(err),
+    }
+    let assignee = assignee.ok_or_else(not_assignable)?;
+
+    let mut tx = pool.begin().await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    let task = task_repo::set_assignee(&mut *tx, current.id, Some(assignee.id)).await?;
+    services::history::task_updated(&mut *tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+    to_single_response(pool, task).await
+}
+
+/// Ответ на назначение, когда задачи нет, она чужая или email не найден.
+fn not_assignable() -> AppError {
+    AppError::NotFound("Task or user not found".to_string())
+}
+
+/// Снимает исполнителя с задачи (вместе с его доступом к ней).
+pub async fn unassign_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<(), AppError> {
+    find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let mut tx = pool.begin().await?;
+    let task = lock_task(&mut tx, list_id, task_id).await?;
+    if task.assignee_id.is_none() {
+        return Err(AppError::NotFound("Task has no assignee".to_string()));
+    }
+
+    let updated = task_repo::set_assignee(&mut *tx, task.id, None).await?;
+    services::history::task_updated(&mut *tx, user_id, &task, &updated).await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Все задачи, назначенные пользователю, из любых списков.
+pub async fn get_assigned_tasks(pool: &PgPool, user_id: Uuid) -> Result<Vec<TaskResponse>, AppError> {
+    let tasks = task_repo::find_all_by_assignee(pool, user_id).await?;
+    to_responses(pool, tasks).await
+}
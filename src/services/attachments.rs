@@ -0,0 +1,226 @@
+use bytes::Bytes;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::attachments::{AttachmentResponse, StorageUsageResponse};
+use crate::errors::AppError;
+use crate::models::attachment::Attachment;
+use crate::repo::attachment_repo::{self, NewAttachment};
+use crate::services::tasks::{find_accessible_task, find_editable_task};
+use crate::storage::{BlobStore, ByteStream};
+
+/// Максимальный размер одного файла.
+pub const MAX_ATTACHMENT_SIZE: usize = 10 * 1024 * 1024;
+/// Квота на суммарный размер вложений одного пользователя.
+pub const STORAGE_QUOTA_BYTES: i64 = 100 * 1024 * 1024;
+/// Разрешённые типы содержимого.
+const ALLOWED_CONTENT_TYPES: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+];
+
+/// Загруженный клиентом файл (уже прочитанный из multipart).
+pub struct UploadedFile {
+    pub filename: String,
+    pub content_type: String,
+    pub data: Bytes,
+}
+
+/// Конвертирует доменную модель Attachment в AttachmentResponse (DTO).
+fn to_response(attachment: Attachment) -> AttachmentResponse {
+    AttachmentResponse {
+        id: attachment.id,
+        task_id: attachment.task_id,
+        filename: attachment.filename,
+        content_type: attachment.content_type,
+        size_bytes: attachment.size_bytes,
+        created_at: attachment.created_at,
+    }
+}
+
+/// Оставляет от имени файла только последний компонент пути,
+/// без управляющих символов и кавычек; пустое имя → "file".
+pub fn sanitize_filename(filename: &str) -> String {
+    let name = filename.rsplit(['/', '\\']).next().unwrap_or_default();
+    let name: String = name
+        .chars()
+        .filter(|c| !c.is_control() && *c != '"')
+        .take(255)
+        .collect();
+    let name = name.trim();
+
+    if name.is_empty() || name == "." || name == ".." {
+        "file".to_string()
+    } else {
+        name.to_string()
+    }
+}
+
+/// Определяет тип по сигнатуре для форматов, у которых она есть.
+fn sniff_content_type(data: &[u8]) -> Option<&'static str> {
+    match data {
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [b'G', b'I', b'F', b'8', ..] => Some("image/gif"),
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Some("image/webp"),
+        [b'%', b'P', b'D', b'F', b'-', ..] => Some("application/pdf"),
+        _ => None,
+    }
+}
+
+/// Проверяет тип содержимого: он должен быть в белом списке, а для
+/// бинарных форматов — совпадать с сигнатурой файла.
+fn validate_content_type(content_type: &str, data: &[u8]) -> Result<String, AppError> {
+    // "text/plain; charset=utf-8" → "text/plain"
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+
+    if !ALLOWED_CONTENT_TYPES.contains(&essence.as_str()) {
+        return Err(AppError::Validation(format!(
+            "Unsupported content type '{essence}'. Allowed: {}",
+            ALLOWED_CONTENT_TYPES.join(", ")
+        )));
+    }
+
+    let sniffed = sniff_content_type(data);
+    let is_binary = !essence.starts_with("text/");
+    if is_binary && sniffed != Some(essence.as_str()) {
+        return Err(AppError::Validation(
+            "File content does not match its content type".to_string(),
+        ));
+    }
+    if !is_binary && sniffed.is_some() {
+        return Err(AppError::Validation(
+            "File content does not match its content type".to_string(),
+        ));
+    }
+
+    Ok(essence)
+}
+
+/// Сообщение об ошибке превышения квоты.
+fn quota_exceeded() -> AppError {
+    AppError::PayloadTooLarge(format!(
+        "Storage quota of {STORAGE_QUOTA_BYTES} bytes exceeded"
+    ))
+}
+
+/// Загружает вложение к задаче: проверки → запись в хранилище →
+/// метаданные в БД (с атомарной проверкой квоты).
+pub async fn upload_attachment(
+    pool: &PgPool,
+    blobs: &dyn BlobStore,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    file: UploadedFile,
+) -> Result<AttachmentResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    if file.data.len() > MAX_ATTACHMENT_SIZE {
+        return Err(AppError::PayloadTooLarge(format!(
+            "File exceeds the limit of {MAX_ATTACHMENT_SIZE} bytes"
+        )));
+    }
+    let content_type = validate_content_type(&file.content_type, &file.data)?;
+    let filename = sanitize_filename(&file.filename);
+    let size_bytes = file.data.len() as i64;
+
+    // Дешёвая предварительная проверка, чтобы не гонять файл в хранилище зря.
+    if attachment_repo::used_bytes(pool, user_id).await? + size_bytes > STORAGE_QUOTA_BYTES {
+        return Err(quota_exceeded());
+    }
+
+    let storage_key = format!("{}/{}", user_id, Uuid::new_v4());
+    blobs.put(&storage_key, file.data, &content_type).await?;
+
+    let new = NewAttachment {
+        task_id: task.id,
+        uploader_id: user_id,
+        filename: &filename,
+        content_type: &content_type,
+        size_bytes,
+        storage_key: &storage_key,
+    };
+
+    // Если метаданные не сохранились — убираем уже записанный объект.
+    match attachment_repo::create_within_quota(pool, &new, STORAGE_QUOTA_BYTES).await {
+        Ok(Some(attachment)) => Ok(to_response(attachment)),
+        Ok(None) => {
+            blobs.delete(&storage_key).await?;
+            Err(quota_exceeded())
+        }
+        Err(err) => {
+            if let Err(cleanup) = blobs.delete(&storage_key).await {
+                tracing::warn!("Failed to remove orphaned blob {}: {}", storage_key, cleanup);
+            }
+            Err(err.into())
+        }
+    }
+}
+
+/// Возвращает все вложения задачи.
+pub async fn get_all_attachments(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<AttachmentResponse>, AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+
+    let attachments = attachment_repo::find_all_by_task(pool, task.id).await?;
+    Ok(attachments.into_iter().map(to_response).collect())
+}
+
+/// Открывает вложение на чтение: метаданные + поток содержимого.
+pub async fn open_attachment(
+    pool: &PgPool,
+    blobs: &dyn BlobStore,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<(Attachment, ByteStream), AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+
+    let attachment = attachment_repo::find_by_id(pool, task.id, attachment_id)
+        .await?
+        .ok_or(AppError::NotFound("Attachment not found".to_string()))?;
+
+    let stream = blobs.get(&attachment.storage_key).await?;
+    Ok((attachment, stream))
+}
+
+/// Удаляет вложение: сначала объект в хранилище, затем метаданные.
+/// Если хранилище недоступно, строка остаётся и удаление можно повторить;
+/// в обратном порядке объект остался бы в хранилище без ссылок на него.
+/// Содержимое вложений задач из корзины удаляет очистка (`jobs::trash`).
+pub async fn delete_attachment(
+    pool: &PgPool,
+    blobs: &dyn BlobStore,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    attachment_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let attachment = attachment_repo::find_by_id(pool, task.id, attachment_id)
+        .await?
+        .ok_or(AppError::NotFound("Attachment not found".to_string()))?;
+
+    blobs.delete(&attachment.storage_key).await?;
+    attachment_repo::delete(pool, attachment.id).await?;
+
+    Ok(())
+}
+
+/// Сколько места занимают вложения пользователя и какова квота.
+pub async fn get_storage_usage(pool: &PgPool, user_id: Uuid) -> Result<StorageUsageResponse, AppError> {
+    let used_bytes = attachment_repo::used_bytes(pool, user_id).await?;
+    Ok(StorageUsageResponse { used_bytes, quota_bytes: STORAGE_QUOTA_BYTES })
+}
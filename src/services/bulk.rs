@@ -0,0 +1,224 @@
+use axum::http::StatusCode;
+use sqlx::{Acquire, PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::dto::bulk::{BulkItemResult, BulkMode, BulkOperation, BulkRequest, BulkResponse};
+use crate::errors::AppError;
+use crate::models::task::Task;
+use crate::repo::{label_repo, status_repo, task_repo};
+use crate::services;
+
+/// Максимум операций в одном пакете.
+pub const MAX_BULK_OPERATIONS: usize = 100;
+
+/// Итог успешно выполненной операции.
+struct Applied {
+    status: StatusCode,
+    task: Option<Task>,
+}
+
+/// Выполняет пакет операций над задачами списка в одной транзакции.
+///
+/// Каждая операция идёт в своём savepoint: ошибочная откатывается целиком.
+/// Доска списка заблокирована на весь пакет — WIP-лимиты колонок проверяются
+/// так же, как в одиночных запросах.
+/// В режиме `atomic` первая ошибка откатывает весь пакет, и ответ получает
+/// её статус; в `best_effort` остальные операции применяются, и при ошибках
+/// ответ — 207. Ошибки БД прерывают пакет целиком (500).
+pub async fn bulk_tasks(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    body: &BulkRequest,
+) -> Result<(StatusCode, BulkResponse), AppError> {
+    services::tasks::verify_list_editable(pool, list_id, user_id).await?;
+
+    if body.operations.is_empty() || body.operations.len() > MAX_BULK_OPERATIONS {
+        return Err(AppError::Validation(format!(
+            "operations must contain from 1 to {} items",
+            MAX_BULK_OPERATIONS
+        )));
+    }
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let mut outcomes = Vec::with_capacity(body.operations.len());
+
+    for operation in &body.operations {
+        let mut savepoint = tx.begin().await?;
+        let outcome = match apply(pool, &mut savepoint, list_id, user_id, operation).await {
+            Ok(applied) => {
+                savepoint.commit().await?;
+                Ok(applied)
+            }
+            Err(err @ (AppError::Internal(_) | AppError::Storage(_))) => return Err(err),
+            Err(err) => {
+                savepoint.rollback().await?;
+                Err(err)
+            }
+        };
+
+        let failed = outcome.is_err();
+        outcomes.push(outcome);
+        if failed && body.mode == BulkMode::Atomic {
+            break;
+        }
+    }
+
+    let first_error = outcomes
+        .iter()
+        .find_map(|outcome| outcome.as_ref().err().map(AppError::status_code));
+    let committed = body.mode == BulkMode::BestEffort || first_error.is_none();
+
+    if committed {
+        tx.commit().await?;
+    } else {
+        tx.rollback().await?;
+    }
+
+    let status = match first_error {
+        None => StatusCode::OK,
+        Some(_) if committed => StatusCode::MULTI_STATUS,
+        Some(status) => status,
+    };
+
+    let results = if committed {
+        committed_results(pool, outcomes).await?
+    } else {
+        rolled_back_results(outcomes, body.operations.len())
+    };
+
+    Ok((status, BulkResponse { mode: body.mode, committed, results }))
+}
+
+/// Результаты зафиксированного пакета: задачи собираются одним `to_responses`.
+async fn committed_results(
+    pool: &PgPool,
+    outcomes: Vec<Result<Applied, AppError>>,
+) -> Result<Vec<BulkItemResult>, AppError> {
+    let tasks: Vec<Task> = outcomes
+        .iter()
+        .filter_map(|outcome| outcome.as_ref().ok().and_then(|applied| applied.task.clone()))
+        .collect();
+    let mut responses = services::tasks::to_responses(pool, tasks).await?.into_iter();
+
+    let results = outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(index, outcome)| match outcome {
+            Ok(applied) => BulkItemResult {
+                index,
+                status: applied.status.as_u16(),
+                task: applied.task.and_then(|_| responses.next()),
+                error: None,
+            },
+            Err(err) => failed_result(index, &err),
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Результаты откатанного пакета: успешные и невыполненные операции — 424.
+fn rolled_back_results(outcomes: Vec<Result<Applied, AppError>>, total: usize) -> Vec<BulkItemResult> {
+    let executed = outcomes.len();
+    let mut results: Vec<BulkItemResult> = outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(index, outcome)| match outcome {
+            Ok(_) => not_applied(index, "Rolled back: another operation in the batch failed"),
+            Err(err) => failed_result(index, &err),
+        })
+        .collect();
+
+    results.extend((executed..total).map(|index| not_applied(index, "Skipped: an earlier operation failed")));
+    results
+}
+
+fn failed_result(index: usize, err: &AppError) -> BulkItemResult {
+    BulkItemResult {
+        index,
+        status: err.status_code().as_u16(),
+        task: None,
+        error: Some(err.to_string()),
+    }
+}
+
+fn not_applied(index: usize, reason: &str) -> BulkItemResult {
+    BulkItemResult {
+        index,
+        status: StatusCode::FAILED_DEPENDENCY.as_u16(),
+        task: None,
+        error: Some(reason.to_string()),
+    }
+}
+
+/// Выполняет одну операцию внутри savepoint `conn`.
+/// Проверки чужих списков и меток читают из пула: пакет их не меняет.
+async fn apply(
+    pool: &PgPool,
+    conn: &mut PgConnection,
+    list_id: Uuid,
+    user_id: Uuid,
+    operation: &BulkOperation,
+) -> Result<Applied, AppError> {
+    match operation {
+        BulkOperation::Create(body) => {
+            let task = task_repo::create(&mut *conn, list_id, &body.title, body.auto_complete, body.due_at).await?;
+            services::tasks::task_added(conn, user_id, &task).await?;
+            Ok(Applied { status: StatusCode::CREATED, task: Some(task) })
+        }
+        BulkOperation::UpdateStatus { task_id, status, force } => {
+            let status = services::tasks::parse_status(status)?;
+            let current = services::tasks::lock_task(conn, list_id, *task_id).await?;
+            services::tasks::check_transition(&mut *conn, &current, status, *force).await?;
+
+            let task = task_repo::set_status(&mut *conn, current.id, status, None)
+                .await?
+                .ok_or(AppError::NotFound("Task not found".to_string()))?;
+            services::tasks::task_changed(conn, user_id, &current, &task).await?;
+
+            Ok(Applied { status: StatusCode::OK, task: Some(task) })
+        }
+        BulkOperation::Move { task_id, target_list_id } => {
+            services::tasks::verify_list_editable(pool, *target_list_id, user_id).await?;
+            status_repo::lock_board(&mut *conn, *target_list_id).await?;
+            let current = services::tasks::lock_task(conn, list_id, *task_id).await?;
+
+            let task = task_repo::move_to_list(&mut *conn, *task_id, list_id, *target_list_id)
+                .await?
+                .ok_or(AppError::NotFound("Task not found".to_string()))?;
+            services::tasks::task_changed(conn, user_id, &current, &task).await?;
+
+            Ok(Applied { status: StatusCode::OK, task: Some(task) })
+        }
+        BulkOperation::Label { task_id, label_id } => {
+            let task = find_task(conn, list_id, *task_id).await?;
+            label_repo::find_by_id(pool, *label_id, user_id)
+                .await?
+                .ok_or(AppError::NotFound("Label not found".to_string()))?;
+
+            label_repo::attach(&mut *conn, task.id, *label_id).await?;
+            let task = find_task(conn, list_id, task.id).await?;
+
+            Ok(Applied { status: StatusCode::OK, task: Some(task) })
+        }
+        BulkOperation::Delete { task_id } => {
+            let current = find_task(conn, list_id, *task_id).await?;
+            if !task_repo::delete(&mut *conn, list_id, *task_id, None).await? {
+                return Err(AppError::NotFound("Task not found".to_string()));
+            }
+            task_repo::touch_dependents(&mut *conn, &[current.id]).await?;
+            services::history::task_deleted(&mut *conn, user_id, &current).await?;
+
+            Ok(Applied { status: StatusCode::NO_CONTENT, task: None })
+        }
+    }
+}
+
+/// Задача из списка — с учётом предыдущих операций пакета.
+async fn find_task(conn: &mut PgConnection, list_id: Uuid, task_id: Uuid) -> Result<Task, AppError> {
+    task_repo::find_by_id(conn, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))
+}
@@ -0,0 +1,150 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::checklist::ChecklistItemResponse;
+use crate::errors::AppError;
+use crate::models::checklist_item::ChecklistItem;
+use crate::models::task::Task;
+use crate::models::task_status::TaskStatus;
+use crate::repo::{checklist_repo, dependency_repo, status_repo, task_repo};
+use crate::services;
+use crate::services::tasks::{find_accessible_task, find_editable_task};
+
+/// Конвертирует доменную модель ChecklistItem в ChecklistItemResponse (DTO).
+fn to_response(item: ChecklistItem) -> ChecklistItemResponse {
+    ChecklistItemResponse {
+        id: item.id,
+        task_id: item.task_id,
+        title: item.title,
+        done: item.done,
+        created_at: item.created_at,
+        updated_at: item.updated_at,
+    }
+}
+
+/// Проверяет название пункта: не пустое после trim.
+fn validate_title(title: &str) -> Result<&str, AppError> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(AppError::Validation("Checklist item title must not be empty".to_string()));
+    }
+    Ok(title)
+}
+
+/// Если у задачи включён `auto_complete` и все пункты чек-листа отмечены —
+/// переводит задачу в "done". Заблокированная зависимостями задача не завершается.
+/// В журнал изменение записывается от имени `user_id`, отметившего пункт.
+///
+/// Если колонка `done` упёрлась в WIP-лимит, задача тоже не завершается.
+/// Задача блокируется на время проверки, поэтому два параллельно отмеченных
+/// последних пункта завершат её (и создадут повторение) один раз.
+async fn auto_complete_task(pool: &PgPool, user_id: Uuid, task: &Task) -> Result<(), AppError> {
+    if !task.auto_complete {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, task.list_id).await?;
+    // Задачу могли удалить параллельно — тогда завершать нечего.
+    let Some(current) = task_repo::lock(&mut *tx, task.list_id, task.id).await? else {
+        return Ok(());
+    };
+    if !current.auto_complete || current.status == TaskStatus::Done {
+        return Ok(());
+    }
+
+    let progress = checklist_repo::progress_by_tasks(&mut *tx, &[current.id]).await?;
+    let all_done = progress
+        .first()
+        .is_some_and(|progress| progress.total > 0 && progress.done == progress.total);
+
+    if !all_done || dependency_repo::is_blocked(&mut *tx, current.id).await? {
+        return Ok(());
+    }
+
+    if let Some(completed) = task_repo::set_status(&mut *tx, current.id, TaskStatus::Done, None).await? {
+        match services::tasks::task_changed(&mut tx, user_id, &current, &completed).await {
+            // Колонка заполнена — задача остаётся как есть, как и заблокированная.
+            Err(AppError::Conflict(_)) => return Ok(()),
+            result => result?,
+        }
+    }
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Добавляет пункт в чек-лист задачи.
+pub async fn create_item(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    title: &str,
+) -> Result<ChecklistItemResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+    let title = validate_title(title)?;
+
+    let item = checklist_repo::create(pool, task.id, title).await?;
+    Ok(to_response(item))
+}
+
+/// Возвращает все пункты чек-листа задачи.
+pub async fn get_all_items(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<ChecklistItemResponse>, AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+
+    let items = checklist_repo::find_all_by_task(pool, task.id).await?;
+    Ok(items.into_iter().map(to_response).collect())
+}
+
+/// Обновляет пункт чек-листа. Отметка последнего пункта может
+/// автоматически завершить задачу (см. `auto_complete_task`).
+pub async fn update_item(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    item_id: Uuid,
+    title: &str,
+    done: bool,
+) -> Result<ChecklistItemResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+    let title = validate_title(title)?;
+
+    let item = checklist_repo::update(pool, task.id, item_id, title, done)
+        .await?
+        .ok_or(AppError::NotFound("Checklist item not found".to_string()))?;
+
+    if item.done {
+        auto_complete_task(pool, user_id, &task).await?;
+    }
+
+    Ok(to_response(item))
+}
+
+/// Удаляет пункт чек-листа. Удаление последнего незакрытого пункта
+/// тоже может завершить задачу.
+pub async fn delete_item(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    item_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let deleted = checklist_repo::delete(pool, task.id, item_id).await?;
+
+    if !deleted {
+        return Err(AppError::NotFound("Checklist item not found".to_string()));
+    }
+
+    auto_complete_task(pool, user_id, &task).await?;
+
+    Ok(())
+}
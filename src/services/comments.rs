@@ -0,0 +1,120 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::comments::{CommentRequest, CommentResponse};
+use crate::errors::AppError;
+use crate::models::comment::Comment;
+use crate::repo::comment_repo;
+use crate::services::tasks::{find_viewable_task, find_viewable_task_for_update};
+
+/// Максимальная длина комментария в символах.
+const MAX_BODY_LENGTH: usize = 10_000;
+
+/// Конвертирует доменную модель Comment в CommentResponse (DTO).
+fn to_response(comment: Comment) -> CommentResponse {
+    CommentResponse {
+        id: comment.id,
+        task_id: comment.task_id,
+        author_id: comment.author_id,
+        author_email: comment.author_email,
+        body: comment.body,
+        created_at: comment.created_at,
+        edited_at: comment.edited_at,
+    }
+}
+
+/// Проверяет текст комментария: не пустой и не длиннее MAX_BODY_LENGTH.
+fn validate_body(body: &str) -> Result<&str, AppError> {
+    let body = body.trim();
+    if body.is_empty() {
+        return Err(AppError::Validation("Comment body must not be empty".to_string()));
+    }
+    if body.chars().count() > MAX_BODY_LENGTH {
+        return Err(AppError::Validation(format!(
+            "Comment body must be at most {MAX_BODY_LENGTH} characters"
+        )));
+    }
+    Ok(body)
+}
+
+/// Находит комментарий и проверяет, что его автор — текущий пользователь.
+/// Чужой комментарий → 403 (сам комментарий пользователю виден).
+async fn find_own_comment(
+    pool: &PgPool,
+    task_id: Uuid,
+    user_id: Uuid,
+    comment_id: Uuid,
+) -> Result<Comment, AppError> {
+    let comment = comment_repo::find_by_id(pool, task_id, comment_id)
+        .await?
+        .ok_or(AppError::NotFound("Comment not found".to_string()))?;
+
+    if comment.author_id != user_id {
+        return Err(AppError::Forbidden(
+            "Only the author can modify a comment".to_string(),
+        ));
+    }
+
+    Ok(comment)
+}
+
+/// Добавляет комментарий к задаче от имени текущего пользователя.
+/// Комментировать могут владелец списка и исполнитель задачи.
+pub async fn create_comment(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &CommentRequest,
+) -> Result<CommentResponse, AppError> {
+    let task = find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+    let text = validate_body(&body.body)?;
+
+    let comment = comment_repo::create(pool, task.id, user_id, text).await?;
+    Ok(to_response(comment))
+}
+
+/// Возвращает все комментарии задачи.
+pub async fn get_all_comments(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<CommentResponse>, AppError> {
+    let task = find_viewable_task(pool, list_id, user_id, task_id).await?;
+
+    let comments = comment_repo::find_all_by_task(pool, task.id).await?;
+    Ok(comments.into_iter().map(to_response).collect())
+}
+
+/// Редактирует комментарий (только автор).
+pub async fn update_comment(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    comment_id: Uuid,
+    body: &CommentRequest,
+) -> Result<CommentResponse, AppError> {
+    let task = find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+    let comment = find_own_comment(pool, task.id, user_id, comment_id).await?;
+    let text = validate_body(&body.body)?;
+
+    let comment = comment_repo::update(pool, comment.id, text).await?;
+    Ok(to_response(comment))
+}
+
+/// Удаляет комментарий (только автор).
+pub async fn delete_comment(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    comment_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+    let comment = find_own_comment(pool, task.id, user_id, comment_id).await?;
+
+    comment_repo::delete(pool, comment.id).await?;
+    Ok(())
+}
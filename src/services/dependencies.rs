@@ -0,0 +1,58 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::tasks::TaskResponse;
+use crate::errors::AppError;
+use crate::repo::dependency_repo::{self, AddOutcome};
+use crate::repo::task_repo;
+use crate::services::tasks::{find_editable_task, to_single_response};
+
+/// Добавляет зависимость: задача `task_id` не может начаться,
+/// пока не выполнена `depends_on_id`.
+///
+/// Блокирующая задача может лежать в любом списке пользователя.
+/// Зависимость, замыкающая цикл, отклоняется с 409.
+pub async fn add_dependency(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    depends_on_id: Uuid,
+) -> Result<TaskResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    if depends_on_id == task.id {
+        return Err(AppError::Validation("Task cannot depend on itself".to_string()));
+    }
+
+    task_repo::find_by_id_for_user(pool, depends_on_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Dependency task not found".to_string()))?;
+
+    if dependency_repo::add(pool, task.id, depends_on_id).await? == AddOutcome::Cycle {
+        return Err(AppError::Conflict("Dependency would create a cycle".to_string()));
+    }
+
+    // Зависимость увеличила версию задачи — перечитываем её.
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+    to_single_response(pool, task).await
+}
+
+/// Удаляет зависимость.
+pub async fn remove_dependency(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    depends_on_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let removed = dependency_repo::remove(pool, task.id, depends_on_id).await?;
+
+    if !removed {
+        return Err(AppError::NotFound("Dependency not found".to_string()));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,183 @@
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::dto::history::{HistoryEventResponse, HistoryPageResponse, HistoryQuery};
+use crate::errors::AppError;
+use crate::models::history_event::{HistoryAction, HistoryEvent};
+use crate::models::task::Task;
+use crate::models::todo_list::TodoList;
+use crate::repo::history_repo;
+use crate::services;
+
+/// Поля задачи, изменения которых попадают в журнал.
+/// Ранг сортировки, версия и служебные отметки времени не записываются.
+const TASK_FIELDS: &[&str] = &[
+    "title",
+    "status",
+    "status_id",
+    "list_id",
+    "auto_complete",
+    "due_at",
+    "estimate_minutes",
+    "assignee_id",
+    "archived_at",
+];
+
+/// Поля списка, изменения которых попадают в журнал.
+const LIST_FIELDS: &[&str] = &["title", "archived_at", "auto_archive_days"];
+
+/// Размер страницы журнала по умолчанию и максимальный.
+const DEFAULT_PAGE_SIZE: i64 = 50;
+const MAX_PAGE_SIZE: i64 = 100;
+
+/// Разница между состояниями ресурса по полям `fields`:
+/// `{"поле": {"old": ..., "new": ...}}`. `None` — ресурса ещё (уже) нет,
+/// его поля считаются `null`.
+fn diff<T: Serialize>(old: Option<&T>, new: Option<&T>, fields: &[&str]) -> Map<String, Value> {
+    let as_object = |value: Option<&T>| match value.map(serde_json::to_value) {
+        Some(Ok(Value::Object(object))) => object,
+        _ => Map::new(),
+    };
+    let (old, new) = (as_object(old), as_object(new));
+
+    fields
+        .iter()
+        .filter_map(|field| {
+            let before = old.get(*field).cloned().unwrap_or(Value::Null);
+            let after = new.get(*field).cloned().unwrap_or(Value::Null);
+            (before != after).then(|| (field.to_string(), json!({ "old": before, "new": after })))
+        })
+        .collect()
+}
+
+/// Записывает создание задачи.
+pub(crate) async fn task_created(db: impl PgExecutor<'_>, actor_id: Uuid, task: &Task) -> Result<(), AppError> {
+    let changes = diff(None, Some(task), TASK_FIELDS);
+    history_repo::record(db, task.list_id, Some(task.id), actor_id, HistoryAction::Created, changes).await?;
+    Ok(())
+}
+
+/// Записывает изменение задачи. Если отслеживаемые поля не изменились — ничего не пишет.
+pub(crate) async fn task_updated(
+    db: impl PgExecutor<'_>,
+    actor_id: Uuid,
+    old: &Task,
+    new: &Task,
+) -> Result<(), AppError> {
+    let changes = diff(Some(old), Some(new), TASK_FIELDS);
+    if changes.is_empty() {
+        return Ok(());
+    }
+    history_repo::record(db, new.list_id, Some(new.id), actor_id, HistoryAction::Updated, changes).await?;
+    Ok(())
+}
+
+/// Записывает удаление задачи (с последними значениями полей).
+pub(crate) async fn task_deleted(db: impl PgExecutor<'_>, actor_id: Uuid, task: &Task) -> Result<(), AppError> {
+    let changes = diff(Some(task), None, TASK_FIELDS);
+    history_repo::record(db, task.list_id, Some(task.id), actor_id, HistoryAction::Deleted, changes).await?;
+    Ok(())
+}
+
+/// Записывает создание, изменение (`old` и `new`) или удаление списка.
+pub(crate) async fn list_changed(
+    db: impl PgExecutor<'_>,
+    actor_id: Uuid,
+    old: Option<&TodoList>,
+    new: Option<&TodoList>,
+) -> Result<(), AppError> {
+    let (list_id, action) = match (old, new) {
+        (None, Some(list)) => (list.id, HistoryAction::Created),
+        (Some(list), None) => (list.id, HistoryAction::Deleted),
+        (Some(_), Some(list)) => (list.id, HistoryAction::Updated),
+        (None, None) => return Ok(()),
+    };
+
+    let changes = diff(old, new, LIST_FIELDS);
+    if action == HistoryAction::Updated && changes.is_empty() {
+        return Ok(());
+    }
+    history_repo::record(db, list_id, None, actor_id, action, changes).await?;
+    Ok(())
+}
+
+/// Записывает восстановление задачи из корзины.
+pub(crate) async fn task_restored(db: impl PgExecutor<'_>, actor_id: Uuid, task: &Task) -> Result<(), AppError> {
+    history_repo::record(db, task.list_id, Some(task.id), actor_id, HistoryAction::Restored, Map::new()).await?;
+    Ok(())
+}
+
+/// Записывает восстановление списка из корзины (вместе с его задачами).
+pub(crate) async fn list_restored(db: impl PgExecutor<'_>, actor_id: Uuid, list: &TodoList) -> Result<(), AppError> {
+    history_repo::record(db, list.id, None, actor_id, HistoryAction::Restored, Map::new()).await?;
+    Ok(())
+}
+
+/// Конвертирует доменную модель HistoryEvent в HistoryEventResponse (DTO).
+fn to_response(event: HistoryEvent) -> HistoryEventResponse {
+    HistoryEventResponse {
+        id: event.id,
+        list_id: event.list_id,
+        task_id: event.task_id,
+        actor_id: event.actor_id,
+        actor_email: event.actor_email,
+        action: event.action.to_string(),
+        changes: event.changes,
+        created_at: event.created_at,
+    }
+}
+
+/// Проверяет размер страницы: от 1 до MAX_PAGE_SIZE.
+fn page_size(query: &HistoryQuery) -> Result<i64, AppError> {
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(AppError::Validation(format!(
+            "limit must be between 1 and {}",
+            MAX_PAGE_SIZE
+        )));
+    }
+    Ok(limit)
+}
+
+/// Собирает страницу: лишняя запись сверх `limit` означает, что есть следующая.
+fn to_page(mut events: Vec<HistoryEvent>, limit: i64) -> HistoryPageResponse {
+    let has_more = events.len() as i64 > limit;
+    events.truncate(limit as usize);
+    let next_before = if has_more { events.last().map(|event| event.id) } else { None };
+
+    HistoryPageResponse {
+        events: events.into_iter().map(to_response).collect(),
+        next_before,
+    }
+}
+
+/// Журнал списка: события самого списка и всех его задач (включая удалённые).
+pub async fn get_list_history(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    query: &HistoryQuery,
+) -> Result<HistoryPageResponse, AppError> {
+    services::tasks::verify_list_ownership(pool, list_id, user_id).await?;
+    let limit = page_size(query)?;
+
+    let events = history_repo::find_by_list(pool, list_id, query.before, limit + 1).await?;
+    Ok(to_page(events, limit))
+}
+
+/// Журнал задачи (владельцу списка или исполнителю).
+pub async fn get_task_history(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    query: &HistoryQuery,
+) -> Result<HistoryPageResponse, AppError> {
+    let task = services::tasks::find_viewable_task(pool, list_id, user_id, task_id).await?;
+    let limit = page_size(query)?;
+
+    let events = history_repo::find_by_task(pool, task.id, query.before, limit + 1).await?;
+    Ok(to_page(events, limit))
+}
@@ -0,0 +1,137 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::labels::LabelResponse;
+use crate::errors::AppError;
+use crate::models::label::Label;
+use crate::repo::label_repo;
+
+/// Цвет метки по умолчанию (серый).
+const DEFAULT_COLOR: &str = "#9e9e9e";
+
+/// Ошибка 409 при занятом имени метки.
+const NAME_TAKEN: &str = "Label with this name already exists";
+
+/// Конвертирует доменную модель Label в LabelResponse (DTO).
+pub fn to_response(label: Label) -> LabelResponse {
+    LabelResponse {
+        id: label.id,
+        name: label.name,
+        color: label.color,
+        created_at: label.created_at,
+        updated_at: label.updated_at,
+    }
+}
+
+/// Проверяет имя метки: не пустое после trim.
+fn validate_name(name: &str) -> Result<&str, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation("Label name must not be empty".to_string()));
+    }
+    Ok(name)
+}
+
+/// Проверяет цвет: строго `#RRGGBB` (hex).
+fn validate_color(color: &str) -> Result<(), AppError> {
+    let valid = color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit());
+    if !valid {
+        return Err(AppError::Validation("Color must be in #RRGGBB format".to_string()));
+    }
+    Ok(())
+}
+
+/// Проверяет, что имя метки не занято другой меткой пользователя.
+///
+/// Параллельный запрос с тем же именем может успеть между проверкой и
+/// записью — его отсекает UNIQUE (user_id, name), см. `name_conflict`.
+async fn ensure_name_free(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    except: Option<Uuid>,
+) -> Result<(), AppError> {
+    let existing = label_repo::find_by_name(pool, user_id, name).await?;
+    match existing {
+        Some(label) if Some(label.id) != except => {
+            Err(AppError::Conflict(NAME_TAKEN.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Нарушение UNIQUE (user_id, name) (SQLSTATE 23505) → 409, как и при проверке заранее.
+fn name_conflict(err: sqlx::Error) -> AppError {
+    match err.as_database_error() {
+        Some(db_err) if db_err.is_unique_violation() => AppError::Conflict(NAME_TAKEN.to_string()),
+        _ => AppError::Internal(err),
+    }
+}
+
+/// Создаёт новую метку.
+pub async fn create_label(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    color: Option<&str>,
+) -> Result<LabelResponse, AppError> {
+    let name = validate_name(name)?;
+    let color = color.unwrap_or(DEFAULT_COLOR);
+    validate_color(color)?;
+    ensure_name_free(pool, user_id, name, None).await?;
+
+    let label = label_repo::create(pool, user_id, name, color).await.map_err(name_conflict)?;
+    Ok(to_response(label))
+}
+
+/// Возвращает все метки пользователя.
+pub async fn get_all_labels(pool: &PgPool, user_id: Uuid) -> Result<Vec<LabelResponse>, AppError> {
+    let labels = label_repo::find_all_by_user(pool, user_id).await?;
+    Ok(labels.into_iter().map(to_response).collect())
+}
+
+/// Возвращает одну метку по ID.
+pub async fn get_label(
+    pool: &PgPool,
+    label_id: Uuid,
+    user_id: Uuid,
+) -> Result<LabelResponse, AppError> {
+    let label = label_repo::find_by_id(pool, label_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Label not found".to_string()))?;
+
+    Ok(to_response(label))
+}
+
+/// Обновляет метку (name + color).
+pub async fn update_label(
+    pool: &PgPool,
+    label_id: Uuid,
+    user_id: Uuid,
+    name: &str,
+    color: &str,
+) -> Result<LabelResponse, AppError> {
+    let name = validate_name(name)?;
+    validate_color(color)?;
+    ensure_name_free(pool, user_id, name, Some(label_id)).await?;
+
+    let label = label_repo::update(pool, label_id, user_id, name, color)
+        .await
+        .map_err(name_conflict)?
+        .ok_or(AppError::NotFound("Label not found".to_string()))?;
+
+    Ok(to_response(label))
+}
+
+/// Удаляет метку (и снимает её со всех задач).
+pub async fn delete_label(pool: &PgPool, label_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let deleted = label_repo::delete(pool, label_id, user_id).await?;
+
+    if !deleted {
+        return Err(AppError::NotFound("Label not found".to_string()));
+    }
+
+    Ok(())
+}
@@ -1,9 +1,53 @@
-use sqlx::PgPool;
+use std::collections::HashMap;
+
+use chrono::{Duration, Utc};
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
-use crate::dto::lists::ListResponse;
+use crate::dto::lists::{ListQuery, ListResponse, PatchListRequest};
+use crate::dto::patch::PatchPayload;
+use crate::dto::positions::PositionRequest;
+use crate::dto::templates::{InstantiateTemplateRequest, TemplateQuery, TemplateResponse, TemplateTaskResponse};
 use crate::errors::AppError;
-use crate::repo::list_repo;
+use crate::middleware::preconditions::Preconditions;
+use crate::models::list_template::{self, BuiltinTemplate, ListTemplate, TemplateTask, BUILTIN_TEMPLATES};
+use crate::models::todo_list::TodoList;
+use crate::repo::list_repo::{self, ListChanges};
+use crate::repo::position_repo::Scope;
+use crate::repo::{task_repo, template_repo};
+use crate::services;
+use crate::services::pagination::{self, NameKey, Page, PositionKey};
+
+/// Сколько задач может быть в сохранённом шаблоне.
+const MAX_TEMPLATE_TASKS: usize = 500;
+
+/// Конвертирует доменную модель TodoList в ListResponse (DTO).
+pub(crate) fn to_response(list: TodoList) -> ListResponse {
+    ListResponse {
+        id: list.id,
+        title: list.title,
+        position: list.position,
+        version: list.version,
+        archived_at: list.archived_at,
+        auto_archive_days: list.auto_archive_days,
+        created_at: list.created_at,
+        updated_at: list.updated_at,
+    }
+}
+
+/// Возвращает список пользователя или 404.
+async fn find_list(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<TodoList, AppError> {
+    list_repo::find_by_id(pool, list_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("List not found".to_string()))
+}
+
+/// Блокирует список пользователя до конца транзакции или 404.
+async fn lock_list(conn: &mut PgConnection, list_id: Uuid, user_id: Uuid) -> Result<TodoList, AppError> {
+    list_repo::lock(conn, list_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("List not found".to_string()))
+}
 
 /// Создаёт новый TODO-лист.
 pub async fn create_list(
@@ -11,32 +55,40 @@ pub async fn create_list(
     user_id: Uuid,
     title: &str,
 ) -> Result<ListResponse, AppError> {
-    let list = list_repo::create(pool, user_id, title).await?;
+    let mut tx = pool.begin().await?;
+    let list = list_repo::create(&mut tx, user_id, title).await?;
+    services::history::list_changed(&mut *tx, user_id, None, Some(&list)).await?;
+    tx.commit().await?;
 
-    Ok(ListResponse {
-        id: list.id,
-        title: list.title,
-        created_at: list.created_at,
-        updated_at: list.updated_at,
-    })
+    Ok(to_response(list))
 }
 
-/// Возвращает все TODO-листы пользователя.
-pub async fn get_all_lists(pool: &PgPool, user_id: Uuid) -> Result<Vec<ListResponse>, AppError> {
-    let lists = list_repo::find_all_by_user(pool, user_id).await?;
+/// Возвращает страницу TODO-листов пользователя; архивные — только с `include_archived`.
+pub async fn get_all_lists(
+    pool: &PgPool,
+    user_id: Uuid,
+    query: &ListQuery,
+) -> Result<Page<ListResponse>, AppError> {
+    let limit = pagination::page_size(query.limit)?;
+    let after: Option<PositionKey> = pagination::decode_cursor(query.cursor.as_deref())?;
 
-    // Конвертируем Vec<TodoList> → Vec<ListResponse>.
-    let response = lists
-        .into_iter()
-        .map(|list| ListResponse {
-            id: list.id,
-            title: list.title,
-            created_at: list.created_at,
-            updated_at: list.updated_at,
-        })
-        .collect();
+    let lists = list_repo::find_page_by_user(
+        pool,
+        user_id,
+        query.include_archived,
+        after.as_ref().map(|key| (key.position.as_str(), key.id)),
+        limit + 1,
+    )
+    .await?;
+    let page = pagination::to_page(lists, limit, |list| PositionKey {
+        position: list.position.clone(),
+        id: list.id,
+    });
 
-    Ok(response)
+    Ok(Page {
+        items: page.items.into_iter().map(to_response).collect(),
+        next_cursor: page.next_cursor,
+    })
 }
 
 /// Возвращает один список по ID.
@@ -46,48 +98,349 @@ pub async fn get_list(
     list_id: Uuid,
     user_id: Uuid,
 ) -> Result<ListResponse, AppError> {
-    let list = list_repo::find_by_id(pool, list_id, user_id)
-        .await?
-        .ok_or(AppError::NotFound("List not found".to_string()))?;
+    let list = find_list(pool, list_id, user_id).await?;
 
-    Ok(ListResponse {
-        id: list.id,
-        title: list.title,
-        created_at: list.created_at,
-        updated_at: list.updated_at,
-    })
+    Ok(to_response(list))
 }
 
-/// Обновляет название списка.
+/// Обновляет название списка. С `If-Match` — только если версия не изменилась.
 pub async fn update_list(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
     title: &str,
+    preconditions: &Preconditions,
 ) -> Result<ListResponse, AppError> {
-    let list = list_repo::update(pool, list_id, user_id, title)
+    let mut tx = pool.begin().await?;
+    let current = lock_list(&mut tx, list_id, user_id).await?;
+    services::tasks::ensure_not_archived(&current)?;
+    preconditions.check(current.version)?;
+
+    let expected_version = preconditions.expected_version(current.version);
+    let list = list_repo::update(&mut *tx, list_id, user_id, title, expected_version)
         .await?
-        .ok_or(AppError::NotFound("List not found".to_string()))?;
+        .ok_or_else(|| preconditions.row_missing("List not found"))?;
+    services::history::list_changed(&mut *tx, user_id, Some(&current), Some(&list)).await?;
+    tx.commit().await?;
 
-    Ok(ListResponse {
-        id: list.id,
-        title: list.title,
-        created_at: list.created_at,
-        updated_at: list.updated_at,
-    })
+    Ok(to_response(list))
 }
 
-/// Удаляет список.
+/// Частично обновляет список (PATCH): разреженный JSON или JSON Patch.
+pub async fn patch_list(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    payload: PatchPayload<PatchListRequest>,
+    preconditions: &Preconditions,
+) -> Result<ListResponse, AppError> {
+    let mut tx = pool.begin().await?;
+    let current = lock_list(&mut tx, list_id, user_id).await?;
+    services::tasks::ensure_not_archived(&current)?;
+    preconditions.check(current.version)?;
+
+    let body = services::patch::resolve(payload, || {
+        let mut document = serde_json::Map::new();
+        document.insert("title".to_string(), current.title.clone().into());
+        document.insert("auto_archive_days".to_string(), serde_json::json!(current.auto_archive_days));
+        document
+    })?;
+    let title = body.title.required("title")?;
+    let auto_archive_days = body.auto_archive_days.nullable();
+    if auto_archive_days.flatten().is_some_and(|days| days < 1) {
+        return Err(AppError::Validation("auto_archive_days must be positive".to_string()));
+    }
+
+    let changes = ListChanges {
+        title: title.as_deref(),
+        auto_archive_days,
+    };
+    let expected_version = preconditions.expected_version(current.version);
+    let list = list_repo::patch(&mut *tx, list_id, user_id, &changes, expected_version)
+        .await?
+        .ok_or_else(|| preconditions.row_missing("List not found"))?;
+    services::history::list_changed(&mut *tx, user_id, Some(&current), Some(&list)).await?;
+    tx.commit().await?;
+
+    Ok(to_response(list))
+}
+
+/// Переносит список в корзину вместе с задачами. С `If-Match` — только если версия не изменилась.
 pub async fn delete_list(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
+    preconditions: &Preconditions,
 ) -> Result<(), AppError> {
-    let deleted = list_repo::delete(pool, list_id, user_id).await?;
+    let mut tx = pool.begin().await?;
+    let current = lock_list(&mut tx, list_id, user_id).await?;
+    preconditions.check(current.version)?;
+
+    let expected_version = preconditions.expected_version(current.version);
+    let deleted = list_repo::delete(&mut tx, list_id, user_id, expected_version).await?;
+
+    if !deleted {
+        return Err(preconditions.row_missing("List not found"));
+    }
+    services::history::list_changed(&mut *tx, user_id, Some(&current), None).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Перемещает список среди списков пользователя (drag-and-drop).
+pub async fn reposition_list(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    body: &PositionRequest,
+) -> Result<ListResponse, AppError> {
+    let current = find_list(pool, list_id, user_id).await?;
+    services::tasks::ensure_not_archived(&current)?;
+    services::positions::reposition(pool, Scope::UserLists(user_id), list_id, body).await?;
+
+    let list = find_list(pool, list_id, user_id).await?;
+
+    Ok(to_response(list))
+}
+
+/// Архивирует список: он пропадает из `GET /lists` и становится доступен только для чтения.
+pub async fn archive_list(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<ListResponse, AppError> {
+    set_archived(pool, list_id, user_id, true).await
+}
+
+/// Возвращает список из архива.
+pub async fn unarchive_list(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<ListResponse, AppError> {
+    set_archived(pool, list_id, user_id, false).await
+}
+
+/// Переводит список в архив или из него; повторный перевод — 409.
+async fn set_archived(pool: &PgPool, list_id: Uuid, user_id: Uuid, archived: bool) -> Result<ListResponse, AppError> {
+    let mut tx = pool.begin().await?;
+    let current = lock_list(&mut tx, list_id, user_id).await?;
+
+    let Some(list) = list_repo::set_archived(&mut *tx, list_id, user_id, archived).await? else {
+        let message = if archived { "List is already archived" } else { "List is not archived" };
+        return Err(AppError::Conflict(message.to_string()));
+    };
+    services::history::list_changed(&mut *tx, user_id, Some(&current), Some(&list)).await?;
+    tx.commit().await?;
+
+    Ok(to_response(list))
+}
+
+/// Конвертирует сохранённый шаблон и его задачи в TemplateResponse (DTO).
+fn template_response(template: ListTemplate, tasks: Vec<TemplateTask>) -> TemplateResponse {
+    TemplateResponse {
+        id: template.id.to_string(),
+        title: template.title,
+        builtin: false,
+        tasks: tasks
+            .into_iter()
+            .map(|task| TemplateTaskResponse {
+                title: task.title,
+                due_offset_minutes: task.due_offset_minutes,
+            })
+            .collect(),
+        created_at: template.created_at,
+        updated_at: template.updated_at,
+    }
+}
+
+/// Конвертирует встроенный шаблон в TemplateResponse (DTO).
+fn builtin_template_response(template: &BuiltinTemplate) -> TemplateResponse {
+    TemplateResponse {
+        id: template.slug.to_string(),
+        title: template.title.to_string(),
+        builtin: true,
+        tasks: template
+            .tasks
+            .iter()
+            .map(|task| TemplateTaskResponse {
+                title: task.title.to_string(),
+                due_offset_minutes: task.due_offset_minutes,
+            })
+            .collect(),
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+/// Шаблон по идентификатору из пути: встроенный или сохранённый.
+enum TemplateRef {
+    Builtin(&'static BuiltinTemplate),
+    Saved(Uuid),
+}
+
+/// Разбирает идентификатор: имя встроенного шаблона или UUID.
+fn template_ref(template_id: &str) -> Result<TemplateRef, AppError> {
+    if let Some(builtin) = list_template::builtin(template_id) {
+        return Ok(TemplateRef::Builtin(builtin));
+    }
+    template_id
+        .parse()
+        .map(TemplateRef::Saved)
+        .map_err(|_| AppError::NotFound("Template not found".to_string()))
+}
+
+/// Возвращает сохранённый шаблон пользователя или 404.
+async fn find_template(pool: &PgPool, template_id: Uuid, user_id: Uuid) -> Result<ListTemplate, AppError> {
+    template_repo::find_by_id(pool, template_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Template not found".to_string()))
+}
+
+/// Название из запроса: не пустое после trim, иначе 422.
+fn requested_title<'a>(title: Option<&'a str>, field: &str) -> Result<Option<&'a str>, AppError> {
+    match title.map(str::trim) {
+        Some("") => Err(AppError::Validation(format!("{} must not be empty", field))),
+        title => Ok(title),
+    }
+}
+
+/// Сохраняет список как шаблон: задачи (кроме удалённых, включая архивные)
+/// в ручном порядке, сроки — смещениями от самого раннего срока в списке
+/// (у этой задачи смещение 0). Больше MAX_TEMPLATE_TASKS задач → 422.
+pub async fn save_list_as_template(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    title: Option<&str>,
+) -> Result<TemplateResponse, AppError> {
+    let list = find_list(pool, list_id, user_id).await?;
+    let title = requested_title(title, "Template title")?.unwrap_or(&list.title);
+
+    let tasks = task_repo::find_all_by_list(pool, list_id, None, true).await?;
+    if tasks.len() > MAX_TEMPLATE_TASKS {
+        return Err(AppError::Validation(format!(
+            "Template must have at most {} tasks",
+            MAX_TEMPLATE_TASKS
+        )));
+    }
+
+    let starts_at = tasks.iter().filter_map(|task| task.due_at).min();
+    let tasks: Vec<(String, Option<i32>)> = tasks
+        .into_iter()
+        .map(|task| {
+            let offset = task.due_at.zip(starts_at).map(|(due_at, starts_at)| {
+                i32::try_from((due_at - starts_at).num_minutes()).unwrap_or(i32::MAX)
+            });
+            (task.title, offset)
+        })
+        .collect();
+
+    let (template, tasks) = template_repo::create(pool, user_id, title, &tasks).await?;
+
+    Ok(template_response(template, tasks))
+}
+
+/// Возвращает страницу шаблонов: сохранённые — по названию, постранично.
+/// Встроенных немного и их число постоянно, поэтому они целиком идут
+/// в начале первой страницы и в `limit` не считаются.
+pub async fn get_all_templates(
+    pool: &PgPool,
+    user_id: Uuid,
+    query: &TemplateQuery,
+) -> Result<Page<TemplateResponse>, AppError> {
+    let limit = pagination::page_size(query.limit)?;
+    let after: Option<NameKey> = pagination::decode_cursor(query.cursor.as_deref())?;
+
+    let templates = template_repo::find_page_by_user(
+        pool,
+        user_id,
+        after.as_ref().map(|key| (key.name.as_str(), key.id)),
+        limit + 1,
+    )
+    .await?;
+    let Page { items: templates, next_cursor } = pagination::to_page(templates, limit, |template| NameKey {
+        name: template.title.clone(),
+        id: template.id,
+    });
+    let ids: Vec<Uuid> = templates.iter().map(|template| template.id).collect();
+
+    let mut tasks: HashMap<Uuid, Vec<TemplateTask>> = HashMap::new();
+    for task in template_repo::find_tasks(pool, &ids).await? {
+        tasks.entry(task.template_id).or_default().push(task);
+    }
+
+    let builtin: &[BuiltinTemplate] = if after.is_none() { BUILTIN_TEMPLATES } else { &[] };
+    Ok(Page {
+        items: builtin
+            .iter()
+            .map(builtin_template_response)
+            .chain(templates.into_iter().map(|template| {
+                let template_tasks = tasks.remove(&template.id).unwrap_or_default();
+                template_response(template, template_tasks)
+            }))
+            .collect(),
+        next_cursor,
+    })
+}
+
+/// Возвращает один шаблон по идентификатору.
+pub async fn get_template(pool: &PgPool, template_id: &str, user_id: Uuid) -> Result<TemplateResponse, AppError> {
+    match template_ref(template_id)? {
+        TemplateRef::Builtin(builtin) => Ok(builtin_template_response(builtin)),
+        TemplateRef::Saved(id) => {
+            let template = find_template(pool, id, user_id).await?;
+            let tasks = template_repo::find_tasks(pool, &[template.id]).await?;
+            Ok(template_response(template, tasks))
+        }
+    }
+}
+
+/// Удаляет сохранённый шаблон; встроенный — 409.
+pub async fn delete_template(pool: &PgPool, template_id: &str, user_id: Uuid) -> Result<(), AppError> {
+    let TemplateRef::Saved(template_id) = template_ref(template_id)? else {
+        return Err(AppError::Conflict("Built-in templates cannot be changed".to_string()));
+    };
+    let deleted = template_repo::delete(pool, template_id, user_id).await?;
 
     if !deleted {
-        return Err(AppError::NotFound("List not found".to_string()));
+        return Err(AppError::NotFound("Template not found".to_string()));
     }
 
     Ok(())
 }
+
+/// Создаёт список из шаблона одной транзакцией: список встаёт первым,
+/// задачи — в порядке шаблона со сроками от `starts_at` (по умолчанию — сейчас).
+pub async fn instantiate_template(
+    pool: &PgPool,
+    template_id: &str,
+    user_id: Uuid,
+    body: &InstantiateTemplateRequest,
+) -> Result<ListResponse, AppError> {
+    let (template_title, tasks): (String, Vec<(String, Option<i32>)>) = match template_ref(template_id)? {
+        TemplateRef::Builtin(builtin) => (
+            builtin.title.to_string(),
+            builtin
+                .tasks
+                .iter()
+                .map(|task| (task.title.to_string(), task.due_offset_minutes))
+                .collect(),
+        ),
+        TemplateRef::Saved(id) => {
+            let template = find_template(pool, id, user_id).await?;
+            let tasks = template_repo::find_tasks(pool, &[template.id]).await?;
+            (
+                template.title,
+                tasks.into_iter().map(|task| (task.title, task.due_offset_minutes)).collect(),
+            )
+        }
+    };
+    let title = requested_title(body.title.as_deref(), "List title")?.unwrap_or(&template_title);
+    let starts_at = body.starts_at.unwrap_or_else(Utc::now);
+
+    let mut tx = pool.begin().await?;
+    let list = list_repo::create(&mut tx, user_id, title).await?;
+    services::history::list_changed(&mut *tx, user_id, None, Some(&list)).await?;
+    for (task_title, due_offset_minutes) in &tasks {
+        let due_at = due_offset_minutes.map(|minutes| starts_at + Duration::minutes(i64::from(minutes)));
+        let task = task_repo::create(&mut tx, list.id, task_title, false, due_at).await?;
+        services::history::task_created(&mut *tx, user_id, &task).await?;
+    }
+    tx.commit().await?;
+
+    Ok(to_response(list))
+}
@@ -1,3 +1,22 @@
+pub mod assignees;
+pub mod attachments;
 pub mod auth;
+pub mod bulk;
+pub mod checklist;
+pub mod comments;
+pub mod dependencies;
+pub mod history;
+pub mod labels;
 pub mod lists;
+pub mod pagination;
+pub mod patch;
+pub mod positions;
+pub mod quick_add;
+pub mod recurrence;
+pub mod reminders;
+pub mod search;
+pub mod statuses;
 pub mod tasks;
+pub mod time_tracking;
+pub mod trash;
+pub mod views;
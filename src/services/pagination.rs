@@ -0,0 +1,94 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::AppError;
+
+/// Размер страницы коллекции по умолчанию и максимальный.
+const DEFAULT_PAGE_SIZE: i64 = 100;
+const MAX_PAGE_SIZE: i64 = 500;
+
+/// Страница коллекции: элементы и курсор следующей страницы
+/// (`None` — это последняя).
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+/// Ключ сортировки по рангу: позиция элемента и его ID на случай
+/// совпадения рангов. Используется списками и задачами.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PositionKey {
+    pub position: String,
+    pub id: Uuid,
+}
+
+/// Ключ сортировки по имени: имя элемента и его ID на случай совпадения
+/// имён. Используется сохранёнными представлениями и шаблонами.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NameKey {
+    pub name: String,
+    pub id: Uuid,
+}
+
+/// Ключ сортировки результатов поиска: релевантность, название и ID.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SearchKey {
+    pub rank: f64,
+    pub title: String,
+    pub id: Uuid,
+}
+
+/// Ключ произвольной сортировки: сама сортировка (курсор другой
+/// сортировки не подходит), значения её ключей и ID элемента.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SortCursor {
+    pub sort: String,
+    pub values: Vec<Option<String>>,
+    pub id: Uuid,
+}
+
+/// Проверяет размер страницы: от 1 до MAX_PAGE_SIZE.
+pub(crate) fn page_size(limit: Option<i64>) -> Result<i64, AppError> {
+    let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    if !(1..=MAX_PAGE_SIZE).contains(&limit) {
+        return Err(AppError::Validation(format!(
+            "limit must be between 1 and {}",
+            MAX_PAGE_SIZE
+        )));
+    }
+    Ok(limit)
+}
+
+/// Кодирует ключ последнего элемента страницы в непрозрачный курсор.
+pub(crate) fn encode_cursor<K: Serialize>(key: &K) -> String {
+    let json = serde_json::to_vec(key).expect("cursor key is serializable");
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+/// Разбирает курсор из запроса; испорченный или чужой курсор → 422.
+pub(crate) fn decode_cursor<K: DeserializeOwned>(cursor: Option<&str>) -> Result<Option<K>, AppError> {
+    let Some(cursor) = cursor else {
+        return Ok(None);
+    };
+
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .map(Some)
+        .ok_or_else(|| AppError::Validation("Invalid cursor".to_string()))
+}
+
+/// Собирает страницу из `limit + 1` строк: лишняя строка означает,
+/// что есть следующая страница, и курсор указывает на последний элемент.
+pub(crate) fn to_page<T, K: Serialize>(mut rows: Vec<T>, limit: i64, key: impl Fn(&T) -> K) -> Page<T> {
+    let has_more = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+    let next_cursor = if has_more { rows.last().map(|row| encode_cursor(&key(row))) } else { None };
+
+    Page { items: rows, next_cursor }
+}
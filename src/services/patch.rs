@@ -0,0 +1,54 @@
+use json_patch::PatchErrorKind;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::dto::patch::PatchPayload;
+use crate::errors::AppError;
+
+/// Превращает тело PATCH-запроса в разреженный запрос `T`.
+///
+/// Для JSON Patch операции применяются к `current` — JSON-документу
+/// с изменяемыми полями ресурса. В результат попадают только поля,
+/// значение которых изменилось; удалённые поля становятся `null`.
+/// Неудачная операция `test` → 409, прочие ошибки патча → 422.
+pub(crate) fn resolve<T: DeserializeOwned>(
+    payload: PatchPayload<T>,
+    current: impl FnOnce() -> Map<String, Value>,
+) -> Result<T, AppError> {
+    let operations = match payload {
+        PatchPayload::Sparse(body) => return Ok(body),
+        PatchPayload::JsonPatch(operations) => operations,
+    };
+
+    let original = current();
+    let mut document = Value::Object(original.clone());
+
+    json_patch::patch(&mut document, &operations).map_err(|err| match err.kind {
+        PatchErrorKind::TestFailed => AppError::Conflict(format!(
+            "JSON Patch test failed at {}",
+            err.path
+        )),
+        _ => AppError::Validation(format!("Invalid JSON Patch: {}", err)),
+    })?;
+
+    let Value::Object(patched) = document else {
+        return Err(AppError::Validation(
+            "JSON Patch must keep the document an object".to_string(),
+        ));
+    };
+
+    let removed: Vec<String> = original
+        .keys()
+        .filter(|field| !patched.contains_key(*field))
+        .cloned()
+        .collect();
+
+    let mut changes: Map<String, Value> = patched
+        .into_iter()
+        .filter(|(field, value)| original.get(field) != Some(value))
+        .collect();
+    changes.extend(removed.into_iter().map(|field| (field, Value::Null)));
+
+    serde_json::from_value(Value::Object(changes))
+        .map_err(|err| AppError::Validation(format!("Invalid JSON Patch: {}", err)))
+}
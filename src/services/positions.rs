@@ -0,0 +1,35 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::positions::PositionRequest;
+use crate::errors::AppError;
+use crate::repo::position_repo::{self, RepositionOutcome, Scope};
+
+/// Переставляет элемент области (задачу в списке или список пользователя).
+/// Доступ к самой области проверяет вызывающий сервис.
+pub(crate) async fn reposition(
+    pool: &PgPool,
+    scope: Scope,
+    item_id: Uuid,
+    body: &PositionRequest,
+) -> Result<(), AppError> {
+    if body.after_id.is_none() && body.before_id.is_none() {
+        return Err(AppError::Validation(
+            "Specify after_id and/or before_id".to_string(),
+        ));
+    }
+    if body.after_id == Some(item_id) || body.before_id == Some(item_id) {
+        return Err(AppError::Validation(
+            "An item cannot be positioned relative to itself".to_string(),
+        ));
+    }
+
+    match position_repo::reposition(pool, scope, item_id, body.after_id, body.before_id).await? {
+        RepositionOutcome::Moved => Ok(()),
+        RepositionOutcome::ItemNotFound => Err(AppError::NotFound("Item not found".to_string())),
+        RepositionOutcome::AnchorNotFound => Err(AppError::NotFound("Neighbour not found".to_string())),
+        RepositionOutcome::AnchorsOutOfOrder => Err(AppError::Validation(
+            "after_id must come before before_id".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::{FixedOffset, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::quick_add::{QuickAddInterpretation, QuickAddRequest, QuickAddResponse};
+use crate::dto::tasks::CreateTaskRequest;
+use crate::errors::AppError;
+use crate::models::quick_add::QuickAdd;
+use crate::models::task_status::TaskStatus;
+use crate::models::todo_list::TodoList;
+use crate::repo::list_repo;
+use crate::services;
+
+/// Самое большое смещение часового пояса от UTC (UTC+14), в минутах.
+const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// Часовой пояс пользователя; вне диапазона → 422.
+fn utc_offset(minutes: Option<i32>) -> Result<FixedOffset, AppError> {
+    minutes
+        .unwrap_or(0)
+        .checked_mul(60)
+        .filter(|_| minutes.unwrap_or(0).abs() <= MAX_UTC_OFFSET_MINUTES)
+        .and_then(FixedOffset::east_opt)
+        .ok_or_else(|| AppError::Validation("utc_offset_minutes must be between -840 and 840".to_string()))
+}
+
+/// Находит список: по названию из `@список`, иначе по `list_id` из запроса.
+async fn resolve_list(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: Option<&str>,
+    list_id: Option<Uuid>,
+) -> Result<TodoList, AppError> {
+    if let Some(name) = name {
+        let mut lists = list_repo::find_by_title(pool, user_id, name).await?;
+        // `@Список_дел` — это «Список дел», если списка «Список_дел» нет.
+        let spaced = name.replace('_', " ");
+        if lists.is_empty() && spaced != name {
+            lists = list_repo::find_by_title(pool, user_id, &spaced).await?;
+        }
+        return match lists.len() {
+            0 => Err(AppError::NotFound(format!("List '{}' not found", name))),
+            1 => Ok(lists.remove(0)),
+            _ => Err(AppError::Conflict(format!("Several lists are named '{}'", name))),
+        };
+    }
+
+    let list_id = list_id.ok_or_else(|| {
+        AppError::Validation("Target list is not specified: add @list to the text or pass list_id".to_string())
+    })?;
+    list_repo::find_by_id(pool, list_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("List not found".to_string()))
+}
+
+/// Создаёт задачу из свободного текста (см. `models::quick_add`):
+/// список, статус и срок берутся из текста, остальное — название.
+/// Задача создаётся сразу в нужном статусе одной транзакцией, с теми же
+/// проверками прав и архива и той же записью в журнал, что и обычная.
+pub async fn quick_add(
+    pool: &PgPool,
+    user_id: Uuid,
+    body: &QuickAddRequest,
+) -> Result<QuickAddResponse, AppError> {
+    let offset = utc_offset(body.utc_offset_minutes)?;
+    let parsed = QuickAdd::parse(&body.text, Utc::now(), offset);
+    if parsed.title.is_empty() {
+        return Err(AppError::Validation("Task title must not be empty".to_string()));
+    }
+
+    let list = resolve_list(pool, user_id, parsed.list.as_deref(), body.list_id).await?;
+
+    let create = CreateTaskRequest {
+        title: parsed.title.clone(),
+        auto_complete: false,
+        due_at: parsed.due_at,
+    };
+    let status = parsed.status.unwrap_or(TaskStatus::Todo);
+    let task = services::tasks::create_task_in_status(pool, list.id, user_id, &create, status).await?;
+
+    Ok(QuickAddResponse {
+        parsed: QuickAddInterpretation {
+            title: parsed.title,
+            list_id: list.id,
+            list_title: list.title,
+            status: status.to_string(),
+            due_at: parsed.due_at,
+            recognized: parsed.recognized,
+        },
+        task,
+    })
+}
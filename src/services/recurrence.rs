@@ -0,0 +1,172 @@
+use chrono::{TimeDelta, Utc};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::dto::recurrence::{CompletionResponse, RecurrenceResponse, SetRecurrenceRequest};
+use crate::dto::tasks::TaskResponse;
+use crate::errors::AppError;
+use crate::models::recurrence::RecurrenceRule;
+use crate::models::task::Task;
+use crate::repo::{recurrence_repo, task_repo};
+use crate::services::tasks::{find_accessible_task, find_editable_task, to_single_response};
+
+/// Режим "по расписанию RRULE".
+const MODE_SCHEDULE: &str = "schedule";
+/// Режим "через N дней после выполнения".
+const MODE_AFTER_COMPLETION: &str = "after_completion";
+/// Наибольший `interval_days` (10 лет) — чтобы срок следующего повторения
+/// оставался представимой датой.
+const MAX_INTERVAL_DAYS: i32 = 3650;
+
+/// Собирает RecurrenceResponse из полей задачи (`None`, если задача не повторяется).
+pub fn to_response(task: &Task) -> Option<RecurrenceResponse> {
+    let mode = task.recurrence_mode.clone()?;
+    Some(RecurrenceResponse {
+        mode,
+        rrule: task.rrule.clone(),
+        interval_days: task.recurrence_interval_days,
+        series_id: task.series_id.unwrap_or(task.id),
+    })
+}
+
+/// Настраивает повторение задачи.
+///
+/// Для `schedule` RRULE разбирается сразу, чтобы ошибка в правиле
+/// вернулась клиенту как 422, а не всплыла при выполнении задачи.
+pub async fn set_recurrence(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &SetRecurrenceRequest,
+) -> Result<TaskResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let mode = body.mode.as_deref().unwrap_or(MODE_SCHEDULE);
+    let (rrule, interval_days) = match mode {
+        MODE_SCHEDULE => {
+            let rrule = body
+                .rrule
+                .as_deref()
+                .ok_or(AppError::Validation("rrule is required for schedule mode".to_string()))?;
+            RecurrenceRule::parse(rrule).map_err(AppError::Validation)?;
+            (Some(rrule), None)
+        }
+        MODE_AFTER_COMPLETION => {
+            let days = body
+                .interval_days
+                .filter(|days| *days > 0)
+                .ok_or(AppError::Validation(
+                    "interval_days must be a positive number for after_completion mode".to_string(),
+                ))?;
+            if days > MAX_INTERVAL_DAYS {
+                return Err(AppError::Validation(format!(
+                    "interval_days must be at most {}",
+                    MAX_INTERVAL_DAYS
+                )));
+            }
+            (None, Some(days))
+        }
+        _ => {
+            return Err(AppError::Validation(
+                "mode must be 'schedule' or 'after_completion'".to_string(),
+            ));
+        }
+    };
+
+    // Первая задача серии становится её идентификатором.
+    let series_id = task.series_id.unwrap_or(task.id);
+    let task = task_repo::set_recurrence(
+        pool, task.id, rrule, Some(mode), interval_days, Some(series_id),
+    ).await?;
+
+    to_single_response(pool, task).await
+}
+
+/// Выключает повторение задачи. Серия и её история сохраняются.
+pub async fn clear_recurrence(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    if task.recurrence_mode.is_none() {
+        return Err(AppError::NotFound("Task is not recurring".to_string()));
+    }
+
+    task_repo::set_recurrence(pool, task.id, None, None, None, None).await?;
+
+    Ok(())
+}
+
+/// История выполнений серии, к которой относится задача.
+pub async fn get_history(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<CompletionResponse>, AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+
+    let series_id = task.series_id.unwrap_or(task.id);
+    let completions = recurrence_repo::find_completions(pool, series_id).await?;
+
+    let response = completions
+        .into_iter()
+        .map(|completion| CompletionResponse {
+            task_id: completion.task_id,
+            due_at: completion.due_at,
+            completed_at: completion.completed_at,
+        })
+        .collect();
+
+    Ok(response)
+}
+
+/// Вызывается, когда повторяющаяся задача перешла в "done":
+/// записывает выполнение в историю серии и создаёт следующее повторение.
+///
+/// Возвращает созданное повторение или `None`, если серия закончилась
+/// (`COUNT`/`UNTIL` или срок вышел за пределы дат) или задача не повторяется.
+/// Задача, которую переоткрыли и выполнили снова, уже записана в историю
+/// и породила своё повторение — второй раз ничего не создаётся.
+pub(crate) async fn on_task_completed(
+    conn: &mut PgConnection,
+    task: &Task,
+) -> Result<Option<Task>, AppError> {
+    let (Some(mode), Some(series_id)) = (task.recurrence_mode.as_deref(), task.series_id) else {
+        return Ok(None);
+    };
+
+    if recurrence_repo::has_completion(&mut *conn, task.id).await? {
+        return Ok(None);
+    }
+    recurrence_repo::record_completion(&mut *conn, series_id, task.id, task.due_at).await?;
+
+    let now = Utc::now();
+    let next_due = match mode {
+        MODE_AFTER_COMPLETION => task
+            .recurrence_interval_days
+            .and_then(|days| TimeDelta::try_days(i64::from(days)))
+            .and_then(|interval| now.checked_add_signed(interval)),
+        _ => {
+            let Some(rule) = task.rrule.as_deref().and_then(|rrule| RecurrenceRule::parse(rrule).ok()) else {
+                return Ok(None);
+            };
+            let completed = recurrence_repo::count_completions(&mut *conn, series_id).await?;
+            if rule.count.is_some_and(|count| completed >= i64::from(count)) {
+                return Ok(None);
+            }
+            rule.next_after(task.due_at.unwrap_or(now))
+        }
+    };
+
+    let Some(next_due) = next_due else {
+        return Ok(None);
+    };
+
+    let next = task_repo::create_occurrence(conn, task, next_due).await?;
+    Ok(Some(next))
+}
@@ -0,0 +1,130 @@
+use chrono::{TimeDelta, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::reminders::{CreateReminderRequest, ReminderResponse, SnoozeReminderRequest};
+use crate::errors::AppError;
+use crate::models::reminder::Reminder;
+use crate::notifications::webhook;
+use crate::repo::reminder_repo;
+use crate::services::tasks::{find_accessible_task, find_editable_task};
+
+/// Наибольшее откладывание в минутах (год).
+const MAX_SNOOZE_MINUTES: i64 = 365 * 24 * 60;
+
+/// Конвертирует доменную модель Reminder в ReminderResponse (DTO).
+fn to_response(reminder: Reminder) -> ReminderResponse {
+    ReminderResponse {
+        id: reminder.id,
+        task_id: reminder.task_id,
+        remind_at: reminder.remind_at,
+        channel: reminder.channel,
+        target: reminder.target,
+        status: reminder.status,
+        attempts: reminder.attempts,
+        last_error: reminder.last_error,
+        sent_at: reminder.sent_at,
+        created_at: reminder.created_at,
+    }
+}
+
+/// Проверяет канал и адрес получателя.
+///
+/// Вебхук принимается только на публичный адрес: хост резолвится сразу,
+/// loopback, частные и link-local сети отклоняются (защита от SSRF).
+/// При отправке адрес проверяется ещё раз.
+async fn validate_target(channel: &str, target: &str) -> Result<(), AppError> {
+    match channel {
+        "email" if target.contains('@') => Ok(()),
+        "email" => Err(AppError::Validation("target must be an email address".to_string())),
+        "webhook" => webhook::check_url(target).await.map_err(AppError::Validation),
+        _ => Err(AppError::Validation("channel must be 'email' or 'webhook'".to_string())),
+    }
+}
+
+/// Создаёт напоминание для задачи.
+pub async fn create_reminder(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &CreateReminderRequest,
+) -> Result<ReminderResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+    validate_target(&body.channel, &body.target).await?;
+
+    let reminder = reminder_repo::create(
+        pool, task.id, user_id, body.remind_at, &body.channel, &body.target,
+    ).await?;
+
+    Ok(to_response(reminder))
+}
+
+/// Возвращает все напоминания задачи.
+pub async fn get_all_reminders(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<ReminderResponse>, AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+
+    let reminders = reminder_repo::find_all_by_task(pool, task.id).await?;
+    Ok(reminders.into_iter().map(to_response).collect())
+}
+
+/// Откладывает напоминание (в т.ч. уже отправленное — оно сработает снова).
+/// `minutes` — от 1 до MAX_SNOOZE_MINUTES, иначе 422.
+pub async fn snooze_reminder(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    reminder_id: Uuid,
+    body: &SnoozeReminderRequest,
+) -> Result<ReminderResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let remind_at = match (body.minutes, body.until) {
+        (Some(minutes), None) if minutes > MAX_SNOOZE_MINUTES => {
+            return Err(AppError::Validation(format!(
+                "'minutes' must be at most {}",
+                MAX_SNOOZE_MINUTES
+            )));
+        }
+        (Some(minutes), None) if minutes > 0 => TimeDelta::try_minutes(minutes)
+            .and_then(|delay| Utc::now().checked_add_signed(delay))
+            .ok_or(AppError::Validation("'minutes' is out of range".to_string()))?,
+        (None, Some(until)) if until > Utc::now() => until,
+        _ => {
+            return Err(AppError::Validation(
+                "Specify either positive 'minutes' or a future 'until'".to_string(),
+            ));
+        }
+    };
+
+    let reminder = reminder_repo::snooze(pool, task.id, reminder_id, remind_at)
+        .await?
+        .ok_or(AppError::NotFound("Reminder not found".to_string()))?;
+
+    Ok(to_response(reminder))
+}
+
+/// Удаляет напоминание.
+pub async fn delete_reminder(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    reminder_id: Uuid,
+) -> Result<(), AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let deleted = reminder_repo::delete(pool, task.id, reminder_id).await?;
+
+    if !deleted {
+        return Err(AppError::NotFound("Reminder not found".to_string()));
+    }
+
+    Ok(())
+}
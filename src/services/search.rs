@@ -0,0 +1,82 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::search::{SearchQuery, SearchResultResponse};
+use crate::errors::AppError;
+use crate::models::search::{SearchHit, HIGHLIGHT_START, HIGHLIGHT_STOP};
+use crate::repo::search_repo::{self, SearchAfter};
+use crate::services::pagination::{self, Page, SearchKey};
+
+/// Больше слов в запросе не учитывается.
+const MAX_WORDS: usize = 10;
+
+/// Экранирует название для HTML и заменяет маркеры совпадений на `<mark>`.
+fn highlight_html(highlight: &str) -> String {
+    let mut html = String::with_capacity(highlight.len());
+    for ch in highlight.chars() {
+        match ch {
+            HIGHLIGHT_START => html.push_str("<mark>"),
+            HIGHLIGHT_STOP => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            other => html.push(other),
+        }
+    }
+    html
+}
+
+/// Конвертирует доменную модель SearchHit в SearchResultResponse (DTO).
+fn to_response(hit: SearchHit) -> SearchResultResponse {
+    SearchResultResponse {
+        highlight: highlight_html(&hit.highlight),
+        kind: hit.kind,
+        id: hit.id,
+        list_id: hit.list_id,
+        list_title: hit.list_title,
+        title: hit.title,
+        status: hit.status,
+        archived: hit.archived,
+        rank: hit.rank,
+    }
+}
+
+/// Ищет по названиям списков пользователя и доступных ему задач
+/// и возвращает страницу результатов — от самых релевантных.
+///
+/// Запрос делится на слова (буквы и цифры); пунктуация и операторы
+/// `tsquery` отбрасываются, поэтому в SQL попадают только слова.
+pub async fn search(pool: &PgPool, user_id: Uuid, query: &SearchQuery) -> Result<Page<SearchResultResponse>, AppError> {
+    let limit = pagination::page_size(query.limit)?;
+    let after: Option<SearchKey> = pagination::decode_cursor(query.cursor.as_deref())?;
+
+    let words: Vec<String> = query
+        .q
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .take(MAX_WORDS)
+        .collect();
+    if words.is_empty() {
+        return Err(AppError::Validation("q must contain at least one word".to_string()));
+    }
+
+    let after = after.as_ref().map(|key| SearchAfter {
+        rank: key.rank,
+        title: &key.title,
+        id: key.id,
+    });
+    let hits = search_repo::search(pool, user_id, &words, &words.join(" "), after.as_ref(), limit + 1).await?;
+    let page = pagination::to_page(hits, limit, |hit| SearchKey {
+        rank: hit.rank,
+        title: hit.title.clone(),
+        id: hit.id,
+    });
+
+    Ok(Page {
+        items: page.items.into_iter().map(to_response).collect(),
+        next_cursor: page.next_cursor,
+    })
+}
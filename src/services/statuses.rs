@@ -0,0 +1,298 @@
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::dto::positions::PositionRequest;
+use crate::dto::statuses::{
+    BoardColumnResponse, BoardResponse, MoveToColumnRequest, StatusRequest, StatusResponse,
+};
+use crate::dto::tasks::TaskResponse;
+use crate::errors::AppError;
+use crate::middleware::preconditions::Preconditions;
+use crate::models::list_status::{column_of, ListStatus, StatusCategory, UnknownStatusCategory};
+use crate::models::task::Task;
+use crate::repo::position_repo::Scope;
+use crate::repo::{status_repo, task_repo};
+use crate::services;
+
+/// Ошибка 409 при занятом имени статуса.
+const NAME_TAKEN: &str = "Status with this name already exists";
+
+/// Конвертирует доменную модель ListStatus в StatusResponse (DTO).
+fn to_response(status: ListStatus) -> StatusResponse {
+    StatusResponse {
+        id: status.id,
+        list_id: status.list_id,
+        name: status.name,
+        category: status.category.to_string(),
+        position: status.position,
+        wip_limit: status.wip_limit,
+        created_at: status.created_at,
+        updated_at: status.updated_at,
+    }
+}
+
+/// Проверенные поля запроса на создание/обновление статуса.
+struct ValidStatus<'a> {
+    name: &'a str,
+    category: StatusCategory,
+    wip_limit: Option<i32>,
+}
+
+/// Проверяет запрос: имя не пустое, категория известна, WIP-лимит положителен.
+fn validate(body: &StatusRequest) -> Result<ValidStatus<'_>, AppError> {
+    let name = body.name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation("Status name must not be empty".to_string()));
+    }
+
+    let category = body
+        .category
+        .parse()
+        .map_err(|err: UnknownStatusCategory| AppError::Validation(err.to_string()))?;
+
+    if body.wip_limit.is_some_and(|limit| limit < 1) {
+        return Err(AppError::Validation("wip_limit must be positive".to_string()));
+    }
+
+    Ok(ValidStatus { name, category, wip_limit: body.wip_limit })
+}
+
+/// Проверяет, что имя статуса не занято другим статусом списка.
+///
+/// Параллельный запрос с тем же именем может успеть между проверкой и
+/// записью — его отсекает UNIQUE (list_id, name), см. `name_conflict`.
+async fn ensure_name_free(
+    pool: &PgPool,
+    list_id: Uuid,
+    name: &str,
+    except: Option<Uuid>,
+) -> Result<(), AppError> {
+    match status_repo::find_by_name(pool, list_id, name).await? {
+        Some(status) if Some(status.id) != except => {
+            Err(AppError::Conflict(NAME_TAKEN.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Нарушение UNIQUE (list_id, name) (SQLSTATE 23505) → 409, как и при проверке заранее.
+fn name_conflict(err: sqlx::Error) -> AppError {
+    match err.as_database_error() {
+        Some(db_err) if db_err.is_unique_violation() => AppError::Conflict(NAME_TAKEN.to_string()),
+        _ => AppError::Internal(err),
+    }
+}
+
+/// Возвращает статус списка или 404.
+async fn find_status(pool: &PgPool, list_id: Uuid, status_id: Uuid) -> Result<ListStatus, AppError> {
+    status_repo::find_by_id(pool, list_id, status_id)
+        .await?
+        .ok_or(AppError::NotFound("Status not found".to_string()))
+}
+
+/// Добавляет статус в конец доски списка.
+pub async fn create_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    body: &StatusRequest,
+) -> Result<StatusResponse, AppError> {
+    services::tasks::verify_list_editable(pool, list_id, user_id).await?;
+    let valid = validate(body)?;
+    ensure_name_free(pool, list_id, valid.name, None).await?;
+
+    let status = status_repo::create(pool, list_id, valid.name, valid.category, valid.wip_limit)
+        .await
+        .map_err(name_conflict)?;
+    Ok(to_response(status))
+}
+
+/// Возвращает статусы списка в порядке колонок.
+pub async fn get_all_statuses(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+) -> Result<Vec<StatusResponse>, AppError> {
+    services::tasks::verify_list_ownership(pool, list_id, user_id).await?;
+
+    let statuses = status_repo::find_all_by_list(pool, list_id).await?;
+    Ok(statuses.into_iter().map(to_response).collect())
+}
+
+/// Обновляет статус (name + category + wip_limit).
+///
+/// После смены категории задачи колонки сохраняют свой статус
+/// и встают в первую колонку его категории.
+/// У последнего статуса категории категорию сменить нельзя → 409.
+pub async fn update_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    status_id: Uuid,
+    body: &StatusRequest,
+) -> Result<StatusResponse, AppError> {
+    services::tasks::verify_list_editable(pool, list_id, user_id).await?;
+    let valid = validate(body)?;
+    find_status(pool, list_id, status_id).await?;
+    ensure_name_free(pool, list_id, valid.name, Some(status_id)).await?;
+
+    match status_repo::update(pool, list_id, status_id, valid.name, valid.category, valid.wip_limit)
+        .await
+        .map_err(name_conflict)?
+    {
+        Some(status) => Ok(to_response(status)),
+        None => Err(last_in_category(pool, list_id, status_id).await),
+    }
+}
+
+/// Удаляет статус. Задачи колонки переходят в первую колонку той же категории.
+/// Последний статус категории удалить нельзя → 409.
+pub async fn delete_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    status_id: Uuid,
+) -> Result<(), AppError> {
+    services::tasks::verify_list_editable(pool, list_id, user_id).await?;
+    find_status(pool, list_id, status_id).await?;
+
+    if !status_repo::delete(pool, list_id, status_id).await? {
+        return Err(last_in_category(pool, list_id, status_id).await);
+    }
+
+    Ok(())
+}
+
+/// Ошибка для записи, не затронувшей статус: он либо последний
+/// в своей категории (409), либо уже удалён (404).
+async fn last_in_category(pool: &PgPool, list_id: Uuid, status_id: Uuid) -> AppError {
+    match find_status(pool, list_id, status_id).await {
+        Ok(status) => AppError::Conflict(format!(
+            "Status '{}' is the last one in category '{}'",
+            status.name, status.category
+        )),
+        Err(err) => err,
+    }
+}
+
+/// Переставляет колонку на доске (drag-and-drop).
+pub async fn reposition_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    status_id: Uuid,
+    body: &PositionRequest,
+) -> Result<StatusResponse, AppError> {
+    services::tasks::verify_list_editable(pool, list_id, user_id).await?;
+
+    services::positions::reposition(pool, Scope::ListStatuses(list_id), status_id, body).await?;
+
+    let status = find_status(pool, list_id, status_id).await?;
+    Ok(to_response(status))
+}
+
+/// Возвращает канбан-доску списка: задачи, сгруппированные по колонкам.
+pub async fn get_board(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<BoardResponse, AppError> {
+    services::tasks::verify_list_ownership(pool, list_id, user_id).await?;
+
+    let statuses = status_repo::find_all_by_list(pool, list_id).await?;
+    let tasks = task_repo::find_all_by_list(pool, list_id, None, false).await?;
+
+    let columns_of: Vec<Option<Uuid>> = tasks
+        .iter()
+        .map(|task| column_of(&statuses, task).map(|status| status.id))
+        .collect();
+    let responses = services::tasks::to_responses(pool, tasks).await?;
+
+    let mut columns: Vec<BoardColumnResponse> = statuses
+        .into_iter()
+        .map(|status| BoardColumnResponse {
+            status: to_response(status),
+            task_count: 0,
+            over_limit: false,
+            tasks: Vec::new(),
+        })
+        .collect();
+
+    for (column_id, task) in columns_of.into_iter().zip(responses) {
+        if let Some(column) = columns.iter_mut().find(|column| Some(column.status.id) == column_id) {
+            column.tasks.push(task);
+        }
+    }
+
+    for column in &mut columns {
+        column.task_count = column.tasks.len();
+        column.over_limit = column
+            .status
+            .wip_limit
+            .is_some_and(|limit| column.task_count > limit as usize);
+    }
+
+    Ok(BoardResponse { list_id, columns })
+}
+
+/// Перемещает задачу в колонку доски; статус задачи — по категории колонки.
+/// Доступно владельцу списка и исполнителю.
+///
+/// Правила переходов, блокировки и WIP-лимита — те же, что при смене статуса.
+pub async fn move_to_column(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &MoveToColumnRequest,
+    preconditions: &Preconditions,
+) -> Result<TaskResponse, AppError> {
+    services::tasks::find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+    let target = find_status(pool, list_id, body.status_id).await?;
+    let status = target.category.task_status();
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let current = services::tasks::lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+    services::tasks::check_transition(&mut *tx, &current, status, body.force).await?;
+
+    let expected_version = preconditions.expected_version(current.version);
+    let task = task_repo::set_column(&mut *tx, current.id, target.id, status, expected_version)
+        .await?
+        .ok_or_else(|| preconditions.row_missing("Task not found"))?;
+    services::tasks::task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+
+    services::tasks::to_single_response(pool, task).await
+}
+
+/// Проверяет WIP-лимит колонки, в которой задача `task` оказалась после
+/// изменения; `before` — её состояние до изменения (`None` — новая задача).
+///
+/// Вызывается после записи, в транзакции, где доска списка задачи уже
+/// заблокирована `status_repo::lock_board`, — так параллельные запросы не
+/// превысят лимит вместе. Задача, оставшаяся в своей колонке, лимит не
+/// нарушает. Превышение → 409, транзакция откатывается.
+pub(crate) async fn check_wip_limit(conn: &mut PgConnection, before: Option<&Task>, task: &Task) -> Result<(), AppError> {
+    let statuses = status_repo::find_all_by_list(&mut *conn, task.list_id).await?;
+    let Some(column) = column_of(&statuses, task) else {
+        return Ok(());
+    };
+    let Some(limit) = column.wip_limit else {
+        return Ok(());
+    };
+    if before.and_then(|before| column_of(&statuses, before)).is_some_and(|was| was.id == column.id) {
+        return Ok(());
+    }
+
+    let occupied = task_repo::find_by_status(&mut *conn, task.list_id, task.status)
+        .await?
+        .iter()
+        .filter(|other| column_of(&statuses, other).is_some_and(|other| other.id == column.id))
+        .count();
+
+    if occupied > limit as usize {
+        return Err(AppError::Conflict(format!(
+            "WIP limit of column '{}' reached ({})",
+            column.name, limit
+        )));
+    }
+    Ok(())
+}
@@ -1,105 +1,707 @@
-use sqlx::PgPool;
+use std::collections::HashMap;
+
+use sqlx::{PgConnection, PgExecutor, PgPool};
 use uuid::Uuid;
 
-use crate::dto::tasks::TaskResponse;
+use crate::dto::checklist::ChecklistProgressResponse;
+use crate::dto::labels::LabelResponse;
+use crate::dto::patch::PatchPayload;
+use crate::dto::positions::PositionRequest;
+use crate::dto::statuses::TaskColumnResponse;
+use crate::dto::tasks::{
+    AssigneeResponse, CreateTaskRequest, PatchTaskRequest, TaskListQuery, TaskResponse,
+    TaskTargetRequest, UpdateTaskRequest, UpdateTaskStatusRequest,
+};
 use crate::errors::AppError;
-use crate::repo::{list_repo, task_repo};
+use crate::middleware::preconditions::Preconditions;
+use crate::models::list_status::column_of;
+use crate::models::task::Task;
+use crate::models::task_query::{Filter, InvalidTaskQuery, Sort, TaskQuery};
+use crate::models::task_status::{TaskStatus, UnknownTaskStatus};
+use crate::models::todo_list::TodoList;
+use crate::repo::position_repo::Scope;
+use crate::repo::task_repo::{TaskChanges, TaskScope};
+use crate::repo::{checklist_repo, dependency_repo, label_repo, list_repo, status_repo, task_repo, time_entry_repo, user_repo};
+use crate::services;
+use crate::services::pagination::{self, Page, SortCursor};
+
+/// Связанные с задачей данные, которые подгружаются отдельными запросами.
+#[derive(Clone, Default)]
+struct TaskRelations {
+    labels: Vec<LabelResponse>,
+    checklist: ChecklistProgressResponse,
+    depends_on: Vec<Uuid>,
+    blocked: bool,
+    assignee: Option<AssigneeResponse>,
+    tracked_seconds: i64,
+}
 
 /// Конвертирует доменную модель Task в TaskResponse (DTO).
-fn to_response(task: crate::models::task::Task) -> TaskResponse {
+fn to_response(task: Task, relations: TaskRelations, column: Option<TaskColumnResponse>) -> TaskResponse {
+    let recurrence = services::recurrence::to_response(&task);
+
     TaskResponse {
         id: task.id,
         list_id: task.list_id,
         title: task.title,
-        status: task.status,
+        status: task.status.to_string(),
+        column,
+        position: task.position,
+        version: task.version,
+        labels: relations.labels,
+        checklist: relations.checklist,
+        auto_complete: task.auto_complete,
+        depends_on: relations.depends_on,
+        blocked: relations.blocked,
+        due_at: task.due_at,
+        estimate_minutes: task.estimate_minutes,
+        tracked_seconds: relations.tracked_seconds,
+        started_at: task.started_at,
+        completed_at: task.completed_at,
+        archived_at: task.archived_at,
+        recurrence,
+        assignee: relations.assignee,
         created_at: task.created_at,
         updated_at: task.updated_at,
     }
 }
 
+/// Собирает TaskResponse для набора задач, подгружая связанные данные
+/// (метки, прогресс чек-листа, зависимости, исполнитель, учтённое время, колонки доски)
+/// одним запросом на весь набор.
+pub(crate) async fn to_responses(pool: &PgPool, tasks: Vec<Task>) -> Result<Vec<TaskResponse>, AppError> {
+    let task_ids: Vec<Uuid> = tasks.iter().map(|task| task.id).collect();
+    let mut relations: HashMap<Uuid, TaskRelations> = HashMap::new();
+
+    for row in label_repo::find_by_tasks(pool, &task_ids).await? {
+        relations
+            .entry(row.task_id)
+            .or_default()
+            .labels
+            .push(services::labels::to_response(row.label));
+    }
+
+    for row in checklist_repo::progress_by_tasks(pool, &task_ids).await? {
+        relations.entry(row.task_id).or_default().checklist =
+            ChecklistProgressResponse { done: row.done, total: row.total };
+    }
+
+    for row in dependency_repo::find_by_tasks(pool, &task_ids).await? {
+        let entry = relations.entry(row.task_id).or_default();
+        entry.depends_on.push(row.depends_on_id);
+        entry.blocked |= !row.done;
+    }
+
+    for row in time_entry_repo::tracked_by_tasks(pool, &task_ids).await? {
+        relations.entry(row.task_id).or_default().tracked_seconds = row.seconds;
+    }
+
+    let assignee_ids: Vec<Uuid> = tasks.iter().filter_map(|task| task.assignee_id).collect();
+    let assignees: HashMap<Uuid, AssigneeResponse> = user_repo::find_by_ids(pool, &assignee_ids)
+        .await?
+        .into_iter()
+        .map(|user| (user.id, AssigneeResponse { id: user.id, email: user.email }))
+        .collect();
+
+    for task in &tasks {
+        if let Some(assignee) = task.assignee_id.and_then(|id| assignees.get(&id)) {
+            relations.entry(task.id).or_default().assignee = Some(assignee.clone());
+        }
+    }
+
+    let mut list_ids: Vec<Uuid> = tasks.iter().map(|task| task.list_id).collect();
+    list_ids.sort_unstable();
+    list_ids.dedup();
+    let statuses = status_repo::find_by_lists(pool, &list_ids).await?;
+
+    let response = tasks
+        .into_iter()
+        .map(|task| {
+            // Одна задача может встретиться в наборе несколько раз (пакетные операции).
+            let task_relations = relations.get(&task.id).cloned().unwrap_or_default();
+            let column = column_of(&statuses, &task)
+                .map(|status| TaskColumnResponse { id: status.id, name: status.name.clone() });
+            to_response(task, task_relations, column)
+        })
+        .collect();
+
+    Ok(response)
+}
+
+/// То же, что `to_responses`, но для одной задачи.
+pub(crate) async fn to_single_response(pool: &PgPool, task: Task) -> Result<TaskResponse, AppError> {
+    let mut response = to_responses(pool, vec![task]).await?;
+    Ok(response.remove(0))
+}
+
 /// Проверяет, что список принадлежит пользователю.
 /// Возвращает 404, если список не найден или чужой.
-async fn verify_list_ownership(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+pub(crate) async fn verify_list_ownership(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
     list_repo::find_by_id(pool, list_id, user_id)
         .await?
         .ok_or(AppError::NotFound("List not found".to_string()))?;
     Ok(())
 }
 
+/// Архивный список доступен только на чтение → 409.
+pub(crate) fn ensure_not_archived(list: &TodoList) -> Result<(), AppError> {
+    if list.archived_at.is_some() {
+        return Err(AppError::Conflict("List is archived; unarchive it first".to_string()));
+    }
+    Ok(())
+}
+
+/// То же, что `verify_list_ownership`, но для изменений: список не должен быть в архиве.
+pub(crate) async fn verify_list_editable(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<(), AppError> {
+    let list = list_repo::find_by_id(pool, list_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("List not found".to_string()))?;
+    ensure_not_archived(&list)
+}
+
+/// Проверяет доступ к списку и возвращает задачу из него.
+/// Используется вложенными ресурсами задачи (чек-лист и т.п.).
+pub(crate) async fn find_accessible_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Task, AppError> {
+    verify_list_ownership(pool, list_id, user_id).await?;
+
+    task_repo::find_by_id(pool, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))
+}
+
+/// То же, что `find_accessible_task`, но для изменений: список не должен быть в архиве.
+pub(crate) async fn find_editable_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Task, AppError> {
+    verify_list_editable(pool, list_id, user_id).await?;
+
+    task_repo::find_by_id(pool, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))
+}
+
+/// Возвращает задачу, доступную пользователю на чтение и смену статуса:
+/// он владелец списка или исполнитель этой задачи.
+pub(crate) async fn find_viewable_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Task, AppError> {
+    task_repo::find_by_id_for_viewer(pool, list_id, task_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))
+}
+
+/// То же, что `find_viewable_task`, но для изменений: список не должен быть в архиве.
+pub(crate) async fn find_viewable_task_for_update(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Task, AppError> {
+    let task = find_viewable_task(pool, list_id, user_id, task_id).await?;
+    if list_repo::is_archived(pool, task.list_id).await? {
+        return Err(AppError::Conflict("List is archived; unarchive it first".to_string()));
+    }
+    Ok(task)
+}
+
+/// Блокирует задачу до конца транзакции `conn` и перечитывает её.
+/// Предусловия, проверка перехода и запись в журнал работают с этой копией:
+/// параллельный запрос к той же задаче ждёт коммита и видит её новое состояние.
+pub(crate) async fn lock_task(conn: &mut PgConnection, list_id: Uuid, task_id: Uuid) -> Result<Task, AppError> {
+    task_repo::lock(conn, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))
+}
+
+/// Завершает изменение задачи в транзакции `conn`, где `current` заблокирована,
+/// а доска её списка — `status_repo::lock_board`: проверяет WIP-лимит колонки,
+/// пишет журнал и, если задача только что стала `done`, создаёт следующее
+/// повторение (тоже с проверкой лимита). Повторение появляется один раз, даже
+/// если задачу завершают несколько запросов сразу — остальные увидят её уже в `done`.
+pub(crate) async fn task_changed(
+    conn: &mut PgConnection,
+    user_id: Uuid,
+    current: &Task,
+    task: &Task,
+) -> Result<(), AppError> {
+    services::statuses::check_wip_limit(&mut *conn, Some(current), task).await?;
+    services::history::task_updated(&mut *conn, user_id, current, task).await?;
+    if (current.status == TaskStatus::Done) != (task.status == TaskStatus::Done) {
+        task_repo::touch_dependents(&mut *conn, &[task.id]).await?;
+    }
+
+    if current.status != TaskStatus::Done
+        && task.status == TaskStatus::Done
+        && let Some(next) = services::recurrence::on_task_completed(&mut *conn, task).await?
+    {
+        services::statuses::check_wip_limit(conn, None, &next).await?;
+    }
+    Ok(())
+}
+
+/// То же, что `task_changed`, для новой задачи (создание, копия).
+pub(crate) async fn task_added(conn: &mut PgConnection, user_id: Uuid, task: &Task) -> Result<(), AppError> {
+    services::statuses::check_wip_limit(&mut *conn, None, task).await?;
+    services::history::task_created(conn, user_id, task).await
+}
+
+/// Разбирает статус из запроса: неизвестное значение → 422.
+pub(crate) fn parse_status(status: &str) -> Result<TaskStatus, AppError> {
+    status
+        .parse()
+        .map_err(|err: UnknownTaskStatus| AppError::Validation(err.to_string()))
+}
+
+/// Проверяет переход статуса:
+/// - из `done` — только через reopen → 409;
+/// - заблокированную задачу нельзя перевести в работу без `force` → 409.
+pub(crate) async fn check_transition(
+    db: impl PgExecutor<'_>,
+    current: &Task,
+    next: TaskStatus,
+    force: bool,
+) -> Result<(), AppError> {
+    if !current.status.can_transition_to(next) {
+        return Err(AppError::Conflict(format!(
+            "Cannot change status from '{}' to '{}': reopen the task first",
+            current.status, next
+        )));
+    }
+
+    if next.starts_work()
+        && next != current.status
+        && !force
+        && dependency_repo::is_blocked(db, current.id).await?
+    {
+        return Err(AppError::Conflict(
+            "Task is blocked by unfinished dependencies".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Создаёт задачу в указанном списке.
 /// Сначала проверяем, что список принадлежит пользователю (авторизация на уровне данных).
 pub async fn create_task(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
-    title: &str,
+    body: &CreateTaskRequest,
 ) -> Result<TaskResponse, AppError> {
-    verify_list_ownership(pool, list_id, user_id).await?;
+    create_task_in_status(pool, list_id, user_id, body, TaskStatus::Todo).await
+}
+
+/// Создаёт задачу сразу в статусе `status` (быстрое добавление с `#done`):
+/// вставка, смена статуса и запись в журнал — одна транзакция.
+pub(crate) async fn create_task_in_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    body: &CreateTaskRequest,
+    status: TaskStatus,
+) -> Result<TaskResponse, AppError> {
+    verify_list_editable(pool, list_id, user_id).await?;
 
-    let task = task_repo::create(pool, list_id, title).await?;
-    Ok(to_response(task))
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let mut task = task_repo::create(&mut tx, list_id, &body.title, body.auto_complete, body.due_at).await?;
+    if status != task.status {
+        task = task_repo::set_status(&mut *tx, task.id, status, None)
+            .await?
+            .ok_or(AppError::NotFound("Task not found".to_string()))?;
+    }
+    task_added(&mut tx, user_id, &task).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, task).await
 }
 
-/// Возвращает все задачи списка.
+/// Возвращает страницу задач списка с фильтрами и сортировкой из query.
+/// Неизвестные поля и операторы → 422.
 pub async fn get_all_tasks(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
-) -> Result<Vec<TaskResponse>, AppError> {
+    query: &TaskListQuery,
+) -> Result<Page<TaskResponse>, AppError> {
     verify_list_ownership(pool, list_id, user_id).await?;
 
-    let tasks = task_repo::find_all_by_list(pool, list_id).await?;
-    let response = tasks.into_iter().map(to_response).collect();
-    Ok(response)
+    let (filter, sort) = parse_filter_and_sort(
+        query.filter.as_deref().unwrap_or_default(),
+        query.sort.as_deref().unwrap_or_default(),
+    )?;
+    let task_query = TaskQuery {
+        label_id: query.label_id,
+        include_archived: query.include_archived,
+        filter,
+        sort,
+    };
+
+    find_task_page(pool, TaskScope::List(list_id), &task_query, query.limit, query.cursor.as_deref()).await
+}
+
+/// Разбирает фильтр и сортировку (см. `models::task_query`); ошибки → 422.
+pub(crate) fn parse_filter_and_sort(filter: &str, sort: &str) -> Result<(Filter, Sort), AppError> {
+    let invalid = |err: InvalidTaskQuery| AppError::Validation(err.to_string());
+    Ok((filter.parse().map_err(invalid)?, sort.parse().map_err(invalid)?))
 }
 
-/// Возвращает одну задачу по ID.
+/// Страница задач из `scope` по запросу `task_query`: `limit` и `cursor` —
+/// как в остальных коллекциях (см. `services::pagination`). Курсор
+/// помнит сортировку, курсор другой сортировки → 422.
+pub(crate) async fn find_task_page(
+    pool: &PgPool,
+    scope: TaskScope,
+    task_query: &TaskQuery,
+    limit: Option<i64>,
+    cursor: Option<&str>,
+) -> Result<Page<TaskResponse>, AppError> {
+    let limit = pagination::page_size(limit)?;
+    let sort = task_query.sort.to_string();
+    let after = match pagination::decode_cursor::<SortCursor>(cursor)? {
+        Some(cursor) if cursor.sort == sort => Some(
+            task_query
+                .sort
+                .keyset(&cursor.values, cursor.id)
+                .map_err(|err| AppError::Validation(err.to_string()))?,
+        ),
+        Some(_) => return Err(AppError::Validation("Cursor does not match sort".to_string())),
+        None => None,
+    };
+
+    let tasks = task_repo::find_page(pool, scope, task_query, after.as_ref(), limit + 1).await?;
+    let page = pagination::to_page(tasks, limit, |task| {
+        let keyset = task_query.sort.keyset_of(task);
+        SortCursor {
+            sort: sort.clone(),
+            values: keyset.values.iter().map(|value| value.as_ref().map(ToString::to_string)).collect(),
+            id: keyset.id,
+        }
+    });
+
+    Ok(Page {
+        items: to_responses(pool, page.items).await?,
+        next_cursor: page.next_cursor,
+    })
+}
+
+/// Возвращает одну задачу по ID (владельцу списка или исполнителю).
 pub async fn get_task(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
     task_id: Uuid,
 ) -> Result<TaskResponse, AppError> {
-    verify_list_ownership(pool, list_id, user_id).await?;
+    let task = find_viewable_task(pool, list_id, user_id, task_id).await?;
+    to_single_response(pool, task).await
+}
+
+/// Обновляет задачу (title + status).
+///
+/// Заблокированную задачу (есть невыполненные зависимости) нельзя перевести
+/// в `in_progress` или `done` без `force: true` → 409.
+///
+/// Перевод повторяющейся задачи в `done` создаёт её следующее повторение.
+pub async fn update_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &UpdateTaskRequest,
+    preconditions: &Preconditions,
+) -> Result<TaskResponse, AppError> {
+    find_editable_task(pool, list_id, user_id, task_id).await?;
+    let status = parse_status(&body.status)?;
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+    check_transition(&mut *tx, &current, status, body.force).await?;
+
+    let task = task_repo::update(
+        &mut *tx,
+        list_id,
+        task_id,
+        &body.title,
+        status,
+        body.auto_complete,
+        body.due_at,
+        preconditions.expected_version(current.version),
+    )
+    .await?
+    .ok_or_else(|| preconditions.row_missing("Task not found"))?;
+    task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, task).await
+}
+
+/// Частично обновляет задачу (PATCH): разреженный JSON или JSON Patch.
+///
+/// Меняются только переданные поля. Правила блокировки и повторения —
+/// те же, что в `update_task`.
+pub async fn patch_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    payload: PatchPayload<PatchTaskRequest>,
+    preconditions: &Preconditions,
+) -> Result<TaskResponse, AppError> {
+    find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+
+    let body = services::patch::resolve(payload, || {
+        let mut document = serde_json::Map::new();
+        document.insert("title".to_string(), current.title.clone().into());
+        document.insert("status".to_string(), current.status.as_str().into());
+        document.insert("auto_complete".to_string(), current.auto_complete.into());
+        document.insert("due_at".to_string(), serde_json::json!(current.due_at));
+        document.insert("estimate_minutes".to_string(), serde_json::json!(current.estimate_minutes));
+        document
+    })?;
+
+    let title = body.title.required("title")?;
+    let status = body.status.required("status")?.as_deref().map(parse_status).transpose()?;
+    let auto_complete = body.auto_complete.required("auto_complete")?;
+    let estimate_minutes = body.estimate_minutes.nullable();
+    if estimate_minutes.flatten().is_some_and(|minutes| minutes < 1) {
+        return Err(AppError::Validation("estimate_minutes must be positive".to_string()));
+    }
+
+    if let Some(status) = status {
+        check_transition(&mut *tx, &current, status, body.force).await?;
+    }
+
+    let changes = TaskChanges {
+        title: title.as_deref(),
+        status,
+        auto_complete,
+        due_at: body.due_at.nullable(),
+        estimate_minutes,
+    };
+
+    let expected_version = preconditions.expected_version(current.version);
+    let task = task_repo::patch(&mut *tx, list_id, task_id, &changes, expected_version)
+        .await?
+        .ok_or_else(|| preconditions.row_missing("Task not found"))?;
+    task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, task).await
+}
+
+/// Меняет только статус задачи. Доступно владельцу списка и исполнителю.
+/// Правила блокировки и повторения — те же, что в `update_task`.
+pub async fn update_task_status(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &UpdateTaskStatusRequest,
+    preconditions: &Preconditions,
+) -> Result<TaskResponse, AppError> {
+    find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+    let status = parse_status(&body.status)?;
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+    check_transition(&mut *tx, &current, status, body.force).await?;
+
+    let expected_version = preconditions.expected_version(current.version);
+    let task = task_repo::set_status(&mut *tx, current.id, status, expected_version)
+        .await?
+        .ok_or_else(|| preconditions.row_missing("Task not found"))?;
+    task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, task).await
+}
+
+/// Переоткрывает выполненную задачу: `done` → `todo`, `completed_at` очищается.
+/// Доступно владельцу списка и исполнителю. Задача не в `done` → 409.
+pub async fn reopen_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    preconditions: &Preconditions,
+) -> Result<TaskResponse, AppError> {
+    find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, list_id).await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+
+    if current.status != TaskStatus::Done {
+        return Err(AppError::Conflict("Only a done task can be reopened".to_string()));
+    }
+
+    let expected_version = preconditions.expected_version(current.version);
+    let task = task_repo::set_status(&mut *tx, current.id, TaskStatus::Todo, expected_version)
+        .await?
+        .ok_or_else(|| preconditions.row_missing("Task not found"))?;
+    task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, task).await
+}
+
+/// Переносит задачу в корзину. С `If-Match` — только если версия не изменилась.
+pub async fn delete_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    preconditions: &Preconditions,
+) -> Result<(), AppError> {
+    find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    let mut tx = pool.begin().await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    preconditions.check(current.version)?;
+
+    let expected_version = preconditions.expected_version(current.version);
+    let deleted = task_repo::delete(&mut *tx, list_id, task_id, expected_version).await?;
+
+    if !deleted {
+        return Err(preconditions.row_missing("Task not found"));
+    }
+    task_repo::touch_dependents(&mut *tx, &[current.id]).await?;
+    services::history::task_deleted(&mut *tx, user_id, &current).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Перемещает задачу внутри списка (drag-and-drop).
+pub async fn reposition_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &PositionRequest,
+) -> Result<TaskResponse, AppError> {
+    let task = find_editable_task(pool, list_id, user_id, task_id).await?;
+
+    services::positions::reposition(pool, Scope::ListTasks(list_id), task.id, body).await?;
 
     let task = task_repo::find_by_id(pool, list_id, task_id)
         .await?
         .ok_or(AppError::NotFound("Task not found".to_string()))?;
 
-    Ok(to_response(task))
+    to_single_response(pool, task).await
 }
 
-/// Обновляет задачу (title + status).
-pub async fn update_task(
+/// Переносит задачу в другой список пользователя, сохраняя её ID и связи.
+pub async fn move_task(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
     task_id: Uuid,
-    title: &str,
-    status: &str,
+    body: &TaskTargetRequest,
 ) -> Result<TaskResponse, AppError> {
-    verify_list_ownership(pool, list_id, user_id).await?;
+    find_editable_task(pool, list_id, user_id, task_id).await?;
+    verify_list_editable(pool, body.target_list_id, user_id).await?;
 
-    let task = task_repo::update(pool, list_id, task_id, title, status)
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, body.target_list_id).await?;
+    let current = lock_task(&mut tx, list_id, task_id).await?;
+    let task = task_repo::move_to_list(&mut tx, current.id, list_id, body.target_list_id)
         .await?
         .ok_or(AppError::NotFound("Task not found".to_string()))?;
+    task_changed(&mut tx, user_id, &current, &task).await?;
+    tx.commit().await?;
 
-    Ok(to_response(task))
+    to_single_response(pool, task).await
 }
 
-/// Удаляет задачу.
-pub async fn delete_task(
+/// Копирует задачу (со всеми атрибутами) в список пользователя.
+/// Копировать можно и в тот же список.
+pub async fn copy_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &TaskTargetRequest,
+) -> Result<TaskResponse, AppError> {
+    let task = find_accessible_task(pool, list_id, user_id, task_id).await?;
+    verify_list_editable(pool, body.target_list_id, user_id).await?;
+
+    let mut tx = pool.begin().await?;
+    status_repo::lock_board(&mut tx, body.target_list_id).await?;
+    let copy = task_repo::copy_to_list(&mut tx, task.id, body.target_list_id).await?;
+    task_added(&mut tx, user_id, &copy).await?;
+    tx.commit().await?;
+
+    to_single_response(pool, copy).await
+}
+
+/// Навешивает метку на задачу. Метка должна принадлежать тому же пользователю.
+pub async fn attach_label(
     pool: &PgPool,
     list_id: Uuid,
     user_id: Uuid,
     task_id: Uuid,
+    label_id: Uuid,
+) -> Result<TaskResponse, AppError> {
+    verify_list_editable(pool, list_id, user_id).await?;
+
+    let task = task_repo::find_by_id(pool, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+    label_repo::find_by_id(pool, label_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Label not found".to_string()))?;
+
+    label_repo::attach(pool, task.id, label_id).await?;
+
+    // Метка увеличила версию задачи — перечитываем её.
+    let task = task_repo::find_by_id(pool, list_id, task.id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
+
+    to_single_response(pool, task).await
+}
+
+/// Снимает метку с задачи.
+pub async fn detach_label(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    label_id: Uuid,
 ) -> Result<(), AppError> {
-    verify_list_ownership(pool, list_id, user_id).await?;
+    verify_list_editable(pool, list_id, user_id).await?;
+
+    task_repo::find_by_id(pool, list_id, task_id)
+        .await?
+        .ok_or(AppError::NotFound("Task not found".to_string()))?;
 
-    let deleted = task_repo::delete(pool, list_id, task_id).await?;
+    let detached = label_repo::detach(pool, task_id, label_id).await?;
 
-    if !deleted {
-        return Err(AppError::NotFound("Task not found".to_string()));
+    if !detached {
+        return Err(AppError::NotFound("Label is not attached to this task".to_string()));
     }
 
     Ok(())
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use chrono::{Days, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::time_tracking::{
+    CreateTimeEntryRequest, ListTimeResponse, TaskTimeResponse, TimeEntryResponse, TimeReportQuery,
+    TimeReportResponse, TimeReportRowResponse,
+};
+use crate::errors::AppError;
+use crate::models::time_entry::TimeEntry;
+use crate::repo::{task_repo, time_entry_repo};
+use crate::services;
+
+/// Максимальная длина периода отчёта.
+const MAX_REPORT_DAYS: i64 = 366;
+
+/// Конвертирует доменную модель TimeEntry в TimeEntryResponse (DTO).
+fn to_response(entry: TimeEntry) -> TimeEntryResponse {
+    TimeEntryResponse {
+        duration_seconds: entry.duration_seconds(Utc::now()),
+        id: entry.id,
+        task_id: entry.task_id,
+        user_id: entry.user_id,
+        started_at: entry.started_at,
+        ended_at: entry.ended_at,
+        note: entry.note,
+    }
+}
+
+/// Запускает таймер пользователя на задаче (владелец списка или исполнитель).
+/// У пользователя может идти только один таймер → иначе 409.
+pub async fn start_timer(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<TimeEntryResponse, AppError> {
+    let task = services::tasks::find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+
+    match time_entry_repo::start(pool, task.id, user_id).await? {
+        Some(entry) => Ok(to_response(entry)),
+        None => {
+            let running = time_entry_repo::find_running(pool, user_id).await?;
+            Err(AppError::Conflict(match running {
+                Some(entry) => format!("A timer is already running on task {}", entry.task_id),
+                None => "A timer is already running".to_string(),
+            }))
+        }
+    }
+}
+
+/// Останавливает таймер пользователя на задаче. Таймер не идёт → 404.
+pub async fn stop_timer(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<TimeEntryResponse, AppError> {
+    let task = services::tasks::find_viewable_task(pool, list_id, user_id, task_id).await?;
+
+    let entry = time_entry_repo::stop(pool, task.id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("No running timer on this task".to_string()))?;
+
+    Ok(to_response(entry))
+}
+
+/// Возвращает идущий таймер пользователя (или 404).
+pub async fn get_running_timer(pool: &PgPool, user_id: Uuid) -> Result<TimeEntryResponse, AppError> {
+    let entry = time_entry_repo::find_running(pool, user_id)
+        .await?
+        .ok_or(AppError::NotFound("No running timer".to_string()))?;
+
+    Ok(to_response(entry))
+}
+
+/// Добавляет запись времени вручную (например, забытый таймер).
+pub async fn create_entry(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    body: &CreateTimeEntryRequest,
+) -> Result<TimeEntryResponse, AppError> {
+    let task = services::tasks::find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+
+    if body.ended_at <= body.started_at {
+        return Err(AppError::Validation("ended_at must be after started_at".to_string()));
+    }
+    if body.ended_at > Utc::now() {
+        return Err(AppError::Validation("ended_at must not be in the future".to_string()));
+    }
+
+    let note = body.note.as_deref().map(str::trim).filter(|note| !note.is_empty());
+    let entry = time_entry_repo::create(pool, task.id, user_id, body.started_at, body.ended_at, note).await?;
+
+    Ok(to_response(entry))
+}
+
+/// Возвращает все записи времени по задаче.
+pub async fn get_entries(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<Vec<TimeEntryResponse>, AppError> {
+    let task = services::tasks::find_viewable_task(pool, list_id, user_id, task_id).await?;
+
+    let entries = time_entry_repo::find_all_by_task(pool, task.id).await?;
+    Ok(entries.into_iter().map(to_response).collect())
+}
+
+/// Удаляет свою запись времени (идущий таймер удаляется вместе с записью).
+pub async fn delete_entry(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+    entry_id: Uuid,
+) -> Result<(), AppError> {
+    let task = services::tasks::find_viewable_task_for_update(pool, list_id, user_id, task_id).await?;
+
+    if !time_entry_repo::delete(pool, task.id, entry_id, user_id).await? {
+        return Err(AppError::NotFound("Time entry not found".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Сводка по времени списка: оценки и учтённое время по каждой задаче и в сумме.
+pub async fn get_list_time(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<ListTimeResponse, AppError> {
+    services::tasks::verify_list_ownership(pool, list_id, user_id).await?;
+
+    let tasks = task_repo::find_all_by_list(pool, list_id, None, true).await?;
+    let task_ids: Vec<Uuid> = tasks.iter().map(|task| task.id).collect();
+    let tracked: HashMap<Uuid, i64> = time_entry_repo::tracked_by_tasks(pool, &task_ids)
+        .await?
+        .into_iter()
+        .map(|row| (row.task_id, row.seconds))
+        .collect();
+
+    let tasks: Vec<TaskTimeResponse> = tasks
+        .into_iter()
+        .map(|task| TaskTimeResponse {
+            tracked_seconds: tracked.get(&task.id).copied().unwrap_or(0),
+            task_id: task.id,
+            title: task.title,
+            estimate_minutes: task.estimate_minutes,
+        })
+        .collect();
+
+    Ok(ListTimeResponse {
+        list_id,
+        estimate_minutes: tasks.iter().filter_map(|task| task.estimate_minutes).map(i64::from).sum(),
+        tracked_seconds: tasks.iter().map(|task| task.tracked_seconds).sum(),
+        tasks,
+    })
+}
+
+/// Отчёт по учтённому времени в списках пользователя за период `from..=to` (дни UTC).
+/// Записи, переходящие через полночь, делятся между днями; идущий таймер
+/// попадает в отчёт только после остановки.
+pub async fn get_report(pool: &PgPool, user_id: Uuid, query: &TimeReportQuery) -> Result<TimeReportResponse, AppError> {
+    if query.from > query.to {
+        return Err(AppError::Validation("from must not be after to".to_string()));
+    }
+    if (query.to - query.from).num_days() >= MAX_REPORT_DAYS {
+        return Err(AppError::Validation(format!(
+            "Report period must not exceed {} days",
+            MAX_REPORT_DAYS
+        )));
+    }
+    if let Some(list_id) = query.list_id {
+        services::tasks::verify_list_ownership(pool, list_id, user_id).await?;
+    }
+
+    let from = query.from.and_hms_opt(0, 0, 0).expect("midnight is valid").and_utc();
+    let to = query
+        .to
+        .checked_add_days(Days::new(1))
+        .ok_or(AppError::Validation("to is out of range".to_string()))?
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is valid")
+        .and_utc();
+
+    let rows: Vec<TimeReportRowResponse> = time_entry_repo::report(pool, user_id, from, to, query.list_id)
+        .await?
+        .into_iter()
+        .map(|row| TimeReportRowResponse {
+            list_id: row.list_id,
+            list_title: row.list_title,
+            date: row.day,
+            seconds: row.seconds,
+        })
+        .collect();
+
+    Ok(TimeReportResponse {
+        from: query.from,
+        to: query.to,
+        total_seconds: rows.iter().map(|row| row.seconds).sum(),
+        rows,
+    })
+}
+
+/// Отчёт в CSV: `list_id,list_title,date,seconds,hours`, одна строка на список и день.
+pub fn report_to_csv(report: &TimeReportResponse) -> String {
+    let mut csv = String::from("list_id,list_title,date,seconds,hours\n");
+    for row in &report.rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{:.2}\n",
+            row.list_id,
+            csv_field(&row.list_title),
+            row.date,
+            row.seconds,
+            row.seconds as f64 / 3600.0
+        ));
+    }
+    csv
+}
+
+/// Экранирует поле CSV (RFC 4180): в кавычки, если есть `,`, `"` или перевод строки.
+/// Значение, которое табличный редактор принял бы за формулу (начинается
+/// с `=`, `+`, `-`, `@`, табуляции или возврата каретки), получает префикс `'`.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
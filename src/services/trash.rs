@@ -0,0 +1,79 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::lists::ListResponse;
+use crate::dto::tasks::TaskResponse;
+use crate::dto::trash::{TrashResponse, TrashedListResponse, TrashedTaskResponse};
+use crate::errors::AppError;
+use crate::repo::{list_repo, task_repo};
+use crate::services;
+
+/// Содержимое корзины пользователя: удалённые списки (с числом задач,
+/// удалённых вместе с ними) и задачи, удалённые по отдельности.
+pub async fn get_trash(pool: &PgPool, user_id: Uuid) -> Result<TrashResponse, AppError> {
+    let lists = list_repo::find_deleted_by_user(pool, user_id)
+        .await?
+        .into_iter()
+        .map(|list| TrashedListResponse {
+            id: list.id,
+            title: list.title,
+            deleted_at: list.deleted_at,
+            task_count: list.task_count,
+        })
+        .collect();
+
+    let tasks = task_repo::find_deleted_by_user(pool, user_id)
+        .await?
+        .into_iter()
+        .map(|task| TrashedTaskResponse {
+            id: task.id,
+            list_id: task.list_id,
+            list_title: task.list_title,
+            list_deleted: task.list_deleted,
+            title: task.title,
+            status: task.status.to_string(),
+            deleted_at: task.deleted_at,
+        })
+        .collect();
+
+    Ok(TrashResponse { lists, tasks })
+}
+
+/// Восстанавливает список из корзины вместе с задачами, удалёнными вместе с ним.
+pub async fn restore_list(pool: &PgPool, list_id: Uuid, user_id: Uuid) -> Result<ListResponse, AppError> {
+    let mut tx = pool.begin().await?;
+    let list = list_repo::restore(&mut tx, list_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Deleted list not found".to_string()))?;
+    services::history::list_restored(&mut *tx, user_id, &list).await?;
+    tx.commit().await?;
+
+    Ok(services::lists::to_response(list))
+}
+
+/// Восстанавливает задачу из корзины. Пока её список в корзине → 409:
+/// сначала нужно восстановить список.
+pub async fn restore_task(
+    pool: &PgPool,
+    list_id: Uuid,
+    user_id: Uuid,
+    task_id: Uuid,
+) -> Result<TaskResponse, AppError> {
+    let trashed = task_repo::find_deleted(pool, list_id, task_id, user_id)
+        .await?
+        .ok_or(AppError::NotFound("Deleted task not found".to_string()))?;
+
+    if trashed.list_deleted {
+        return Err(AppError::Conflict("Restore the list of this task first".to_string()));
+    }
+
+    let mut tx = pool.begin().await?;
+    let task = task_repo::restore(&mut *tx, trashed.id)
+        .await?
+        .ok_or(AppError::NotFound("Deleted task not found".to_string()))?;
+    task_repo::touch_dependents(&mut *tx, &[task.id]).await?;
+    services::history::task_restored(&mut *tx, user_id, &task).await?;
+    tx.commit().await?;
+
+    services::tasks::to_single_response(pool, task).await
+}
@@ -0,0 +1,221 @@
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::dto::tasks::TaskResponse;
+use crate::dto::views::{ViewQuery, ViewResponse, ViewTasksQuery};
+use crate::errors::AppError;
+use crate::models::task_query::TaskQuery;
+use crate::models::view::{self, BuiltinView, SavedView, BUILTIN_VIEWS};
+use crate::repo::task_repo::TaskScope;
+use crate::repo::view_repo;
+use crate::services;
+use crate::services::pagination::{self, NameKey, Page};
+
+/// Ошибка 409 при занятом имени представления.
+const NAME_TAKEN: &str = "View with this name already exists";
+
+/// Конвертирует доменную модель SavedView в ViewResponse (DTO).
+fn to_response(view: SavedView) -> ViewResponse {
+    ViewResponse {
+        id: view.id.to_string(),
+        name: view.name,
+        filter: view.filter,
+        sort: view.sort,
+        builtin: false,
+        created_at: view.created_at,
+        updated_at: view.updated_at,
+    }
+}
+
+/// Конвертирует встроенное представление в ViewResponse (DTO).
+fn builtin_response(view: &BuiltinView) -> ViewResponse {
+    ViewResponse {
+        id: view.slug.to_string(),
+        name: view.name.to_string(),
+        filter: view.filter.to_string(),
+        sort: view.sort.to_string(),
+        builtin: true,
+        created_at: None,
+        updated_at: None,
+    }
+}
+
+/// Представление по идентификатору из пути: встроенное или сохранённое.
+enum ViewRef {
+    Builtin(&'static BuiltinView),
+    Saved(Uuid),
+}
+
+/// Разбирает идентификатор: имя встроенного представления или UUID.
+fn view_ref(view_id: &str) -> Result<ViewRef, AppError> {
+    if let Some(builtin) = view::builtin(view_id) {
+        return Ok(ViewRef::Builtin(builtin));
+    }
+    view_id
+        .parse()
+        .map(ViewRef::Saved)
+        .map_err(|_| AppError::NotFound("View not found".to_string()))
+}
+
+/// Встроенные представления менять нельзя → 409.
+fn saved_only(view_id: &str) -> Result<Uuid, AppError> {
+    match view_ref(view_id)? {
+        ViewRef::Builtin(_) => Err(AppError::Conflict("Built-in views cannot be changed".to_string())),
+        ViewRef::Saved(id) => Ok(id),
+    }
+}
+
+/// Проверяет имя (не пустое после trim), фильтр и сортировку (разбираются → иначе 422).
+fn validate<'a>(name: &'a str, filter: &str, sort: &str) -> Result<&'a str, AppError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Validation("View name must not be empty".to_string()));
+    }
+    services::tasks::parse_filter_and_sort(filter, sort)?;
+    Ok(name)
+}
+
+/// Проверяет, что имя не занято другим представлением пользователя.
+///
+/// Параллельный запрос с тем же именем может успеть между проверкой и
+/// записью — его отсекает UNIQUE (user_id, name), см. `name_conflict`.
+async fn ensure_name_free(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    except: Option<Uuid>,
+) -> Result<(), AppError> {
+    let existing = view_repo::find_by_name(pool, user_id, name).await?;
+    match existing {
+        Some(view) if Some(view.id) != except => {
+            Err(AppError::Conflict(NAME_TAKEN.to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Нарушение UNIQUE (user_id, name) (SQLSTATE 23505) → 409, как и при проверке заранее.
+fn name_conflict(err: sqlx::Error) -> AppError {
+    match err.as_database_error() {
+        Some(db_err) if db_err.is_unique_violation() => AppError::Conflict(NAME_TAKEN.to_string()),
+        _ => AppError::Internal(err),
+    }
+}
+
+/// Создаёт сохранённое представление.
+pub async fn create_view(
+    pool: &PgPool,
+    user_id: Uuid,
+    name: &str,
+    filter: Option<&str>,
+    sort: Option<&str>,
+) -> Result<ViewResponse, AppError> {
+    let (filter, sort) = (filter.unwrap_or_default(), sort.unwrap_or_default());
+    let name = validate(name, filter, sort)?;
+    ensure_name_free(pool, user_id, name, None).await?;
+
+    let view = view_repo::create(pool, user_id, name, filter, sort)
+        .await
+        .map_err(name_conflict)?;
+    Ok(to_response(view))
+}
+
+/// Возвращает страницу представлений: сохранённые — по имени, постранично.
+/// Встроенных немного и их число постоянно, поэтому они целиком идут
+/// в начале первой страницы и в `limit` не считаются.
+pub async fn get_all_views(
+    pool: &PgPool,
+    user_id: Uuid,
+    query: &ViewQuery,
+) -> Result<Page<ViewResponse>, AppError> {
+    let limit = pagination::page_size(query.limit)?;
+    let after: Option<NameKey> = pagination::decode_cursor(query.cursor.as_deref())?;
+
+    let saved = view_repo::find_page_by_user(
+        pool,
+        user_id,
+        after.as_ref().map(|key| (key.name.as_str(), key.id)),
+        limit + 1,
+    )
+    .await?;
+    let page = pagination::to_page(saved, limit, |view| NameKey { name: view.name.clone(), id: view.id });
+
+    let builtin: &[BuiltinView] = if after.is_none() { BUILTIN_VIEWS } else { &[] };
+    Ok(Page {
+        items: builtin
+            .iter()
+            .map(builtin_response)
+            .chain(page.items.into_iter().map(to_response))
+            .collect(),
+        next_cursor: page.next_cursor,
+    })
+}
+
+/// Возвращает одно представление по идентификатору.
+pub async fn get_view(pool: &PgPool, view_id: &str, user_id: Uuid) -> Result<ViewResponse, AppError> {
+    match view_ref(view_id)? {
+        ViewRef::Builtin(builtin) => Ok(builtin_response(builtin)),
+        ViewRef::Saved(id) => view_repo::find_by_id(pool, id, user_id)
+            .await?
+            .map(to_response)
+            .ok_or(AppError::NotFound("View not found".to_string())),
+    }
+}
+
+/// Обновляет сохранённое представление (name + filter + sort).
+pub async fn update_view(
+    pool: &PgPool,
+    view_id: &str,
+    user_id: Uuid,
+    name: &str,
+    filter: &str,
+    sort: &str,
+) -> Result<ViewResponse, AppError> {
+    let view_id = saved_only(view_id)?;
+    let name = validate(name, filter, sort)?;
+    ensure_name_free(pool, user_id, name, Some(view_id)).await?;
+
+    let view = view_repo::update(pool, view_id, user_id, name, filter, sort)
+        .await
+        .map_err(name_conflict)?
+        .ok_or(AppError::NotFound("View not found".to_string()))?;
+
+    Ok(to_response(view))
+}
+
+/// Удаляет сохранённое представление.
+pub async fn delete_view(pool: &PgPool, view_id: &str, user_id: Uuid) -> Result<(), AppError> {
+    let view_id = saved_only(view_id)?;
+    let deleted = view_repo::delete(pool, view_id, user_id).await?;
+
+    if !deleted {
+        return Err(AppError::NotFound("View not found".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Задачи представления по всем (неархивным) спискам пользователя, постранично.
+pub async fn get_view_tasks(
+    pool: &PgPool,
+    view_id: &str,
+    user_id: Uuid,
+    query: &ViewTasksQuery,
+) -> Result<Page<TaskResponse>, AppError> {
+    let view = get_view(pool, view_id, user_id).await?;
+    let (filter, sort) = services::tasks::parse_filter_and_sort(&view.filter, &view.sort)?;
+    let task_query = TaskQuery {
+        filter,
+        sort,
+        ..TaskQuery::default()
+    };
+
+    services::tasks::find_task_page(
+        pool,
+        TaskScope::User(user_id),
+        &task_query,
+        query.limit,
+        query.cursor.as_deref(),
+    )
+    .await
+}
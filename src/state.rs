@@ -1,5 +1,9 @@
+use std::sync::Arc;
+
 use sqlx::PgPool;
 
+use crate::storage::BlobStore;
+
 /// Общее состояние приложения, доступное во всех handler'ах.
 #[derive(Clone)]
 pub struct AppState {
@@ -7,4 +11,6 @@ pub struct AppState {
     pub db: PgPool,
     /// Секретный ключ для подписи JWT-токенов.
     pub jwt_secret: String,
+    /// Хранилище содержимого вложений.
+    pub blobs: Arc<dyn BlobStore>,
 }
@@ -0,0 +1,63 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use bytes::Bytes;
+use tokio_util::io::ReaderStream;
+
+use super::{BlobStore, ByteStream, StorageError};
+
+/// Хранилище в каталоге локальной файловой системы: ключ → файл `root/key`.
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Путь к файлу объекта. Ключи генерирует сервер, но на всякий случай
+    /// не выпускаем их за пределы `root`.
+    fn path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        if key.is_empty() || key.contains("..") || key.starts_with('/') || key.contains('\\') {
+            return Err(StorageError::Backend(format!("Invalid blob key: {key}")));
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+fn backend_error(err: std::io::Error) -> StorageError {
+    match err.kind() {
+        ErrorKind::NotFound => StorageError::NotFound,
+        _ => StorageError::Backend(err.to_string()),
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, data: Bytes, _content_type: &str) -> Result<(), StorageError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(backend_error)?;
+        }
+
+        // Пишем во временный файл и переименовываем — читатель никогда
+        // не увидит недописанный объект.
+        let tmp = path.with_extension("part");
+        tokio::fs::write(&tmp, &data).await.map_err(backend_error)?;
+        tokio::fs::rename(&tmp, &path).await.map_err(backend_error)
+    }
+
+    async fn get(&self, key: &str) -> Result<ByteStream, StorageError> {
+        let file = tokio::fs::File::open(self.path(key)?).await.map_err(backend_error)?;
+        Ok(Box::pin(ReaderStream::new(file)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(backend_error(err)),
+        }
+    }
+}
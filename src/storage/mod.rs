@@ -0,0 +1,69 @@
+//! Хранилище файлов (вложений) за трейтом [`BlobStore`].
+//!
+//! Метаданные вложений лежат в БД, а содержимое — в хранилище по ключу.
+//! Реализации: локальная файловая система и S3-совместимое хранилище
+//! (AWS S3, MinIO и т.п.).
+
+pub mod local;
+pub mod s3;
+
+use std::env;
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures_util::Stream;
+
+/// Поток байтов содержимого — для отдачи файла без загрузки в память.
+pub type ByteStream = Pin<Box<dyn Stream<Item = io::Result<Bytes>> + Send>>;
+
+/// Ошибка хранилища.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    /// Объекта с таким ключом нет.
+    #[error("Blob not found")]
+    NotFound,
+
+    /// Любая другая ошибка бэкенда (диск, сеть, ответ S3).
+    #[error("Storage backend error: {0}")]
+    Backend(String),
+}
+
+/// Хранилище содержимого файлов.
+#[async_trait::async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Сохраняет объект под ключом `key` (перезаписывает существующий).
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> Result<(), StorageError>;
+
+    /// Открывает объект на чтение потоком.
+    async fn get(&self, key: &str) -> Result<ByteStream, StorageError>;
+
+    /// Удаляет объект. Отсутствие объекта ошибкой не считается.
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// Выбирает хранилище по переменным окружения.
+///
+/// - `STORAGE_BACKEND=local` (по умолчанию) — каталог `STORAGE_DIR`
+///   (по умолчанию `./data/attachments`);
+/// - `STORAGE_BACKEND=s3` — `S3_ENDPOINT`, `S3_BUCKET`, `S3_REGION`,
+///   `S3_ACCESS_KEY`, `S3_SECRET_KEY`.
+pub fn from_env() -> Arc<dyn BlobStore> {
+    match env::var("STORAGE_BACKEND").as_deref() {
+        Ok("s3") => {
+            let config = s3::S3Config {
+                endpoint: env::var("S3_ENDPOINT").expect("S3_ENDPOINT must be set"),
+                bucket: env::var("S3_BUCKET").expect("S3_BUCKET must be set"),
+                region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+                access_key: env::var("S3_ACCESS_KEY").expect("S3_ACCESS_KEY must be set"),
+                secret_key: env::var("S3_SECRET_KEY").expect("S3_SECRET_KEY must be set"),
+            };
+            Arc::new(s3::S3BlobStore::new(config))
+        }
+        _ => {
+            let dir = env::var("STORAGE_DIR").unwrap_or_else(|_| "./data/attachments".to_string());
+            Arc::new(local::LocalBlobStore::new(dir))
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use hmac::{Hmac, Mac};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Method, StatusCode};
+use sha2::{Digest, Sha256};
+
+use super::{BlobStore, ByteStream, StorageError};
+
+/// Символы, которые SigV4 оставляет в пути как есть (плюс `/` между сегментами).
+const PATH_SAFE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~')
+    .remove(b'/');
+
+/// SHA-256 пустого тела — для GET/DELETE.
+const EMPTY_PAYLOAD_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Параметры подключения к S3-совместимому хранилищу.
+#[derive(Debug, Clone)]
+pub struct S3Config {
+    /// Базовый URL, например `https://s3.eu-central-1.amazonaws.com`
+    /// или `http://localhost:9000` для MinIO.
+    pub endpoint: String,
+    pub bucket: String,
+    pub region: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+/// Хранилище в S3-совместимом бакете (path-style адресация, подпись AWS SigV4).
+pub struct S3BlobStore {
+    config: S3Config,
+    client: reqwest::Client,
+}
+
+impl S3BlobStore {
+    pub fn new(config: S3Config) -> Self {
+        Self { config, client: reqwest::Client::new() }
+    }
+
+    /// Отправляет подписанный запрос к объекту `key`.
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Option<(Bytes, &str)>,
+    ) -> Result<reqwest::Response, StorageError> {
+        let path = utf8_percent_encode(&format!("/{}/{}", self.config.bucket, key), PATH_SAFE).to_string();
+        let url = format!("{}{}", self.config.endpoint.trim_end_matches('/'), path);
+        let parsed = reqwest::Url::parse(&url).map_err(|err| StorageError::Backend(err.to_string()))?;
+        let host = match parsed.port() {
+            Some(port) => format!("{}:{}", parsed.host_str().unwrap_or_default(), port),
+            None => parsed.host_str().unwrap_or_default().to_string(),
+        };
+
+        let payload_hash = match &body {
+            Some((data, _)) => hex::encode(Sha256::digest(data)),
+            None => EMPTY_PAYLOAD_SHA256.to_string(),
+        };
+        let now = Utc::now();
+        let authorization = sign(&self.config, method.as_str(), &host, &path, &payload_hash, now);
+
+        let mut request = self
+            .client
+            .request(method, parsed)
+            .header("x-amz-date", amz_date(now))
+            .header("x-amz-content-sha256", &payload_hash)
+            .header("authorization", authorization);
+        if let Some((data, content_type)) = body {
+            request = request.header("content-type", content_type).body(data);
+        }
+
+        request.send().await.map_err(|err| StorageError::Backend(err.to_string()))
+    }
+}
+
+#[async_trait::async_trait]
+impl BlobStore for S3BlobStore {
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> Result<(), StorageError> {
+        let response = self.send(Method::PUT, key, Some((data, content_type))).await?;
+        check_status(response).await.map(|_| ())
+    }
+
+    async fn get(&self, key: &str) -> Result<ByteStream, StorageError> {
+        let response = check_status(self.send(Method::GET, key, None).await?).await?;
+        let stream = response.bytes_stream().map_err(std::io::Error::other);
+        Ok(Box::pin(stream))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let response = self.send(Method::DELETE, key, None).await?;
+        match check_status(response).await {
+            Ok(_) | Err(StorageError::NotFound) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Превращает неуспешный ответ S3 в StorageError (тело ответа — в текст ошибки).
+async fn check_status(response: reqwest::Response) -> Result<reqwest::Response, StorageError> {
+    match response.status() {
+        status if status.is_success() => Ok(response),
+        StatusCode::NOT_FOUND => Err(StorageError::NotFound),
+        status => {
+            let body = response.text().await.unwrap_or_default();
+            Err(StorageError::Backend(format!("S3 responded {status}: {body}")))
+        }
+    }
+}
+
+fn amz_date(now: DateTime<Utc>) -> String {
+    now.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Строит заголовок `Authorization` по AWS Signature Version 4
+/// (подписываются `host`, `x-amz-content-sha256`, `x-amz-date`).
+pub fn sign(
+    config: &S3Config,
+    method: &str,
+    host: &str,
+    canonical_path: &str,
+    payload_hash: &str,
+    now: DateTime<Utc>,
+) -> String {
+    let amz_date = amz_date(now);
+    let date = now.format("%Y%m%d").to_string();
+    let scope = format!("{}/{}/s3/aws4_request", date, config.region);
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+
+    let canonical_request = format!(
+        "{method}\n{canonical_path}\n\nhost:{host}\nx-amz-content-sha256:{payload_hash}\n\
+         x-amz-date:{amz_date}\n\n{signed_headers}\n{payload_hash}"
+    );
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{amz_date}\n{scope}\n{}",
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = hmac_sha256(format!("AWS4{}", config.secret_key).as_bytes(), &date);
+    let key = hmac_sha256(&key, &config.region);
+    let key = hmac_sha256(&key, "s3");
+    let key = hmac_sha256(&key, "aws4_request");
+    let signature = hex::encode(hmac_sha256(&key, &string_to_sign));
+
+    format!(
+        "AWS4-HMAC-SHA256 Credential={}/{scope}, SignedHeaders={signed_headers}, Signature={signature}",
+        config.access_key
+    )
+}
@@ -0,0 +1,173 @@
+/// Тесты архивирования списков и автоархивации выполненных задач.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::jobs;
+
+/// Названия элементов JSON-массива.
+fn titles(items: &serde_json::Value) -> Vec<String> {
+    items
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|item| item["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// ==================== Архив списков ====================
+
+#[tokio::test]
+async fn archived_list_is_hidden_by_default() {
+    let state = common::test_app_state().await;
+    let email = "archive_hidden@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, list) = common::send(&state, &token, "POST", format!("/lists/{}/archive", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(list["archived_at"].is_string());
+
+    let (status, _) = common::send(&state, &token, "POST", format!("/lists/{}/archive", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, lists) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert!(lists.as_array().unwrap().is_empty());
+
+    let (_, lists) = common::send(&state, &token, "GET", "/lists?include_archived=true".to_string(), serde_json::Value::Null).await;
+    assert_eq!(titles(&lists), vec!["Test List for Tasks"]);
+
+    // По прямому адресу архивный список по-прежнему доступен.
+    let (status, _) = common::send(&state, &token, "GET", format!("/lists/{}", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn archived_list_is_read_only_until_unarchived() {
+    let state = common::test_app_state().await;
+    let email = "archive_read_only@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Старая задача").await;
+    let task_uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    common::send(&state, &token, "POST", format!("/lists/{}/archive", list_id), serde_json::Value::Null).await;
+
+    let (status, _) = common::send(&state, &token, "PATCH", format!("/lists/{}", list_id), serde_json::json!({ "title": "Новое" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks", list_id),
+        serde_json::json!({ "title": "Новая задача" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(&state, &token, "PATCH", task_uri.clone(), serde_json::json!({ "title": "Правка" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&tasks), vec!["Старая задача"]);
+
+    let (status, list) = common::send(&state, &token, "POST", format!("/lists/{}/unarchive", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(list["archived_at"].is_null());
+
+    let (status, _) = common::send(&state, &token, "PATCH", task_uri, serde_json::json!({ "title": "Правка" })).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = common::send(&state, &token, "POST", format!("/lists/{}/unarchive", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Автоархивация ====================
+
+#[tokio::test]
+async fn job_archives_tasks_done_long_ago() {
+    let state = common::test_app_state().await;
+    let email = "archive_auto@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let old_done = common::create_task(&state, &token, &list_id, "Давно сделана").await;
+    let fresh_done = common::create_task(&state, &token, &list_id, "Сделана сегодня").await;
+    common::create_task(&state, &token, &list_id, "В работе").await;
+
+    let (status, _) = common::send(&state, &token, "PATCH", format!("/lists/{}", list_id), serde_json::json!({ "auto_archive_days": 7 })).await;
+    assert_eq!(status, StatusCode::OK);
+    for task_id in [&old_done, &fresh_done] {
+        common::send(
+            &state,
+            &token,
+            "PUT",
+            format!("/lists/{}/tasks/{}/status", list_id, task_id),
+            serde_json::json!({ "status": "done" }),
+        )
+        .await;
+    }
+    sqlx::query("UPDATE tasks SET completed_at = now() - interval '8 days' WHERE id = $1")
+        .bind(old_done.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+    let archived = jobs::archive::run_once(&state.db).await.unwrap();
+    assert!(archived >= 1);
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    assert_eq!(titles(&tasks), vec!["Сделана сегодня", "В работе"]);
+
+    let (_, tasks) = common::send(
+        &state,
+        &token,
+        "GET",
+        format!("/lists/{}/tasks?include_archived=true", list_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(titles(&tasks), vec!["Давно сделана", "Сделана сегодня", "В работе"]);
+
+    // Переоткрытая задача возвращается из архива.
+    let (status, task) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/reopen", list_id, old_done),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(task["archived_at"].is_null());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn auto_archive_days_must_be_positive() {
+    let state = common::test_app_state().await;
+    let email = "archive_rule@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let uri = format!("/lists/{}", list_id);
+
+    let (status, _) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "auto_archive_days": 0 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (_, list) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "auto_archive_days": 14 })).await;
+    assert_eq!(list["auto_archive_days"], 14);
+
+    let (_, list) = common::send(&state, &token, "PATCH", uri, serde_json::json!({ "auto_archive_days": null })).await;
+    assert!(list["auto_archive_days"].is_null());
+
+    common::cleanup_user(&state.db, email).await;
+}
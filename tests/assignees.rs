@@ -0,0 +1,140 @@
+/// Интеграционные тесты для исполнителей задач.
+mod common;
+
+use axum::http::StatusCode;
+
+// ==================== PUT /lists/:list_id/tasks/:task_id/assignee ====================
+
+#[tokio::test]
+async fn assignee_can_read_and_update_status() {
+    let state = common::test_app_state().await;
+    let owner_email = "assign_owner@example.com";
+    let assignee_email = "assign_assignee@example.com";
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, assignee_email).await;
+    let owner = common::get_auth_token(&state, owner_email).await;
+    let assignee = common::get_auth_token(&state, assignee_email).await;
+    let list_id = common::create_list(&state, &owner).await;
+    let task_id = common::create_task(&state, &owner, &list_id, "Prepare report").await;
+    let task_uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    // До назначения чужая задача не видна.
+    let (status, _) = common::send(&state, &assignee, "GET", task_uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = common::send(
+        &state, &owner, "PUT", format!("{}/assignee", task_uri),
+        serde_json::json!({ "email": assignee_email }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["assignee"]["email"], assignee_email);
+
+    let (status, body) = common::send(&state, &assignee, "GET", task_uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Prepare report");
+
+    let (status, body) = common::send(
+        &state, &assignee, "PUT", format!("{}/status", task_uri),
+        serde_json::json!({ "status": "done" }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "done");
+
+    // Полное редактирование и весь список — только владельцу.
+    let (status, _) = common::send(
+        &state, &assignee, "PUT", task_uri.clone(),
+        serde_json::json!({ "title": "Hijacked", "status": "todo" }),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = common::send(
+        &state, &assignee, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null,
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, assignee_email).await;
+}
+
+#[tokio::test]
+async fn assign_unknown_email_returns_404() {
+    let state = common::test_app_state().await;
+    let email = "assign_unknown@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Task").await;
+
+    let (status, unknown) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/assignee", list_id, task_id),
+        serde_json::json!({ "email": "nobody_registered@example.com" }),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Чужая задача — тот же ответ: по нему не понять, зарегистрирован ли email.
+    let other_email = "assign_unknown_other@example.com";
+    common::cleanup_user(&state.db, other_email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+    let (status, forbidden) = common::send(
+        &state, &other, "PUT", format!("/lists/{}/tasks/{}/assignee", list_id, task_id),
+        serde_json::json!({ "email": email }),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(forbidden, unknown);
+
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+}
+
+
+// ==================== GET /me/assigned-tasks ====================
+
+#[tokio::test]
+async fn assigned_tasks_across_lists_and_unassign() {
+    let state = common::test_app_state().await;
+    let owner_email = "assign_cross_owner@example.com";
+    let assignee_email = "assign_cross_assignee@example.com";
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, assignee_email).await;
+    let owner = common::get_auth_token(&state, owner_email).await;
+    let assignee = common::get_auth_token(&state, assignee_email).await;
+    let list_a = common::create_list(&state, &owner).await;
+    let list_b = common::create_list(&state, &owner).await;
+    let task_a = common::create_task(&state, &owner, &list_a, "A").await;
+    let task_b = common::create_task(&state, &owner, &list_b, "B").await;
+    common::create_task(&state, &owner, &list_b, "Not assigned").await;
+
+    for (list_id, task_id) in [(&list_a, &task_a), (&list_b, &task_b)] {
+        let (status, _) = common::send(
+            &state, &owner, "PUT", format!("/lists/{}/tasks/{}/assignee", list_id, task_id),
+            serde_json::json!({ "email": assignee_email }),
+        ).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (status, body) = common::send(
+        &state, &assignee, "GET", "/me/assigned-tasks".to_string(), serde_json::Value::Null,
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    // Снятие исполнителя отзывает доступ к задаче.
+    let uri = format!("/lists/{}/tasks/{}/assignee", list_a, task_a);
+    let (status, _) = common::send(&state, &owner, "DELETE", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = common::send(&state, &owner, "DELETE", uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = common::send(
+        &state, &assignee, "GET", format!("/lists/{}/tasks/{}", list_a, task_a), serde_json::Value::Null,
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, body) = common::send(
+        &state, &assignee, "GET", "/me/assigned-tasks".to_string(), serde_json::Value::Null,
+    ).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["id"], task_b.as_str());
+
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, assignee_email).await;
+}
@@ -0,0 +1,255 @@
+/// Интеграционные тесты для вложений и хранилищ файлов.
+mod common;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, Request, StatusCode};
+use axum::routing::put;
+use futures_util::TryStreamExt;
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::services::attachments::{sanitize_filename, MAX_ATTACHMENT_SIZE, STORAGE_QUOTA_BYTES};
+use todo_api::state::AppState;
+use todo_api::storage::s3::{S3BlobStore, S3Config};
+use todo_api::storage::{BlobStore, StorageError};
+
+/// Минимальная "картинка": сигнатура PNG и немного байтов.
+const PNG: &[u8] = b"\x89PNG\r\n\x1a\n-fake-image-data-";
+
+/// Загружает файл multipart-запросом, возвращает статус и тело.
+async fn upload(
+    state: &AppState,
+    token: &str,
+    list_id: &str,
+    task_id: &str,
+    filename: &str,
+    content_type: &str,
+    data: &[u8],
+) -> (StatusCode, serde_json::Value) {
+    let boundary = "----todo-api-test-boundary";
+    let mut body = format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{filename}\"\r\n\
+         Content-Type: {content_type}\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(data);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/attachments", list_id, task_id))
+        .header("Content-Type", format!("multipart/form-data; boundary={boundary}"))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(body))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// GET/DELETE-запрос, возвращает статус, заголовки и сырое тело.
+async fn request(state: &AppState, token: &str, method: &str, uri: String) -> (StatusCode, HeaderMap, Bytes) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, headers, bytes)
+}
+
+
+// ==================== POST /lists/:list_id/tasks/:task_id/attachments ====================
+
+#[tokio::test]
+async fn upload_download_and_delete_attachment() {
+    let state = common::test_app_state().await;
+    let email = "attachments_roundtrip@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Expense report").await;
+
+    let (status, body) = upload(&state, &token, &list_id, &task_id, "чек.png", "image/png", PNG).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["filename"], "чек.png");
+    assert_eq!(body["size_bytes"], PNG.len());
+    let uri = format!("/lists/{}/tasks/{}/attachments/{}", list_id, task_id, body["id"].as_str().unwrap());
+
+    let (status, headers, bytes) = request(&state, &token, "GET", uri.clone()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["content-type"], "image/png");
+    let disposition = headers["content-disposition"].to_str().unwrap();
+    assert!(disposition.starts_with("attachment; filename=\"___.png\""));
+    assert!(disposition.contains("filename*=UTF-8''%D1%87%D0%B5%D0%BA%2Epng"));
+    assert_eq!(&bytes[..], PNG);
+
+    let (status, _, _) = request(&state, &token, "GET", "/me/storage".to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _, _) = request(&state, &token, "DELETE", uri.clone()).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, _) = request(&state, &token, "GET", uri).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn upload_rejects_bad_content_type() {
+    let state = common::test_app_state().await;
+    let email = "attachments_mime@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Task").await;
+
+    let (status, _) = upload(&state, &token, &list_id, &task_id, "a.exe", "application/x-msdownload", b"MZ").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Заявлен PNG, а внутри текст.
+    let (status, _) = upload(&state, &token, &list_id, &task_id, "a.png", "image/png", b"hello").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn upload_too_large_returns_413() {
+    let state = common::test_app_state().await;
+    let email = "attachments_large@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Task").await;
+
+    let data = vec![b'a'; MAX_ATTACHMENT_SIZE + 1];
+    let (status, _) = upload(&state, &token, &list_id, &task_id, "big.txt", "text/plain", &data).await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn upload_over_quota_returns_413() {
+    let state = common::test_app_state().await;
+    let email = "attachments_quota@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Task").await;
+
+    // Квота уже почти исчерпана (метаданные без реального файла).
+    sqlx::query(
+        "INSERT INTO attachments (task_id, uploader_id, filename, content_type, size_bytes, storage_key) \
+         SELECT $1, id, 'huge.pdf', 'application/pdf', $2, 'quota-test/' || gen_random_uuid() \
+         FROM users WHERE email = $3",
+    )
+    .bind(task_id.parse::<uuid::Uuid>().unwrap())
+    .bind(STORAGE_QUOTA_BYTES - 4)
+    .bind(email)
+    .execute(&state.db)
+    .await
+    .unwrap();
+
+    let (status, _) = upload(&state, &token, &list_id, &task_id, "a.txt", "text/plain", b"12345").await;
+    assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[test]
+fn sanitize_filename_strips_paths_and_quotes() {
+    assert_eq!(sanitize_filename("../../etc/passwd"), "passwd");
+    assert_eq!(sanitize_filename("C:\\Users\\me\\\"scan\".pdf"), "scan.pdf");
+    assert_eq!(sanitize_filename("  "), "file");
+}
+
+
+// ==================== S3BlobStore ====================
+
+/// Объекты поддельного S3: ключ пути → (content-type, содержимое).
+type Objects = Arc<Mutex<HashMap<String, (String, Bytes)>>>;
+
+/// Поднимает на случайном порту минимальный S3-совместимый сервер
+/// (path-style PUT/GET/DELETE) и возвращает его адрес.
+async fn spawn_fake_s3(objects: Objects) -> String {
+    async fn put_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+        headers: HeaderMap,
+        body: Bytes,
+    ) -> StatusCode {
+        let signed = headers
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("AWS4-HMAC-SHA256 Credential=test-key/"));
+        if !signed || !headers.contains_key("x-amz-date") {
+            return StatusCode::FORBIDDEN;
+        }
+        let content_type = headers["content-type"].to_str().unwrap().to_string();
+        objects.lock().unwrap().insert(format!("{bucket}/{key}"), (content_type, body));
+        StatusCode::OK
+    }
+
+    async fn get_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+    ) -> Result<Bytes, StatusCode> {
+        let objects = objects.lock().unwrap();
+        let (_, data) = objects.get(&format!("{bucket}/{key}")).ok_or(StatusCode::NOT_FOUND)?;
+        Ok(data.clone())
+    }
+
+    async fn delete_object(
+        State(objects): State<Objects>,
+        Path((bucket, key)): Path<(String, String)>,
+    ) -> StatusCode {
+        objects.lock().unwrap().remove(&format!("{bucket}/{key}"));
+        StatusCode::NO_CONTENT
+    }
+
+    let app = axum::Router::new()
+        .route("/{bucket}/{*key}", put(put_object).get(get_object).delete(delete_object))
+        .with_state(objects);
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    format!("http://{addr}")
+}
+
+#[tokio::test]
+async fn s3_blob_store_roundtrip() {
+    let objects = Objects::default();
+    let endpoint = spawn_fake_s3(objects.clone()).await;
+    let store = S3BlobStore::new(S3Config {
+        endpoint,
+        bucket: "attachments".to_string(),
+        region: "us-east-1".to_string(),
+        access_key: "test-key".to_string(),
+        secret_key: "test-secret".to_string(),
+    });
+
+    store.put("user/1", Bytes::from_static(PNG), "image/png").await.unwrap();
+    assert_eq!(objects.lock().unwrap()["attachments/user/1"].0, "image/png");
+
+    let stream = store.get("user/1").await.unwrap();
+    let chunks: Vec<Bytes> = stream.try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), PNG);
+
+    store.delete("user/1").await.unwrap();
+    assert!(matches!(store.get("user/1").await, Err(StorageError::NotFound)));
+}
@@ -0,0 +1,436 @@
+/// Тесты пользовательских статусов списка и канбан-доски.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::state::AppState;
+
+/// Имена колонок доски и задачи в каждой из них.
+fn board_layout(board: &serde_json::Value) -> Vec<(String, Vec<String>)> {
+    board["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|column| {
+            let tasks = column["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|task| task["title"].as_str().unwrap().to_string())
+                .collect();
+            (column["status"]["name"].as_str().unwrap().to_string(), tasks)
+        })
+        .collect()
+}
+
+/// ID статуса списка по имени.
+async fn status_id(state: &AppState, token: &str, list_id: &str, name: &str) -> String {
+    let (_, statuses) = common::send(state, token, "GET", format!("/lists/{}/statuses", list_id), serde_json::Value::Null).await;
+    statuses
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|status| status["name"] == name)
+        .map(|status| status["id"].as_str().unwrap().to_string())
+        .unwrap()
+}
+
+
+// ==================== Доска по умолчанию ====================
+
+#[tokio::test]
+async fn new_list_gets_default_columns_and_status_changes_follow() {
+    let state = common::test_app_state().await;
+    let email = "board_defaults@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, statuses) = common::send(&state, &token, "GET", format!("/lists/{}/statuses", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    let categories: Vec<&str> = statuses
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|status| status["category"].as_str().unwrap())
+        .collect();
+    assert_eq!(categories, vec!["not_started", "active", "done"]);
+
+    let first = common::create_task(&state, &token, &list_id, "Первая").await;
+    common::create_task(&state, &token, &list_id, "Вторая").await;
+
+    let (status, task) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/status", list_id, first),
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["column"]["name"], "In Progress");
+
+    let (status, board) = common::send(&state, &token, "GET", format!("/lists/{}/board", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        board_layout(&board),
+        vec![
+            ("To Do".to_string(), vec!["Вторая".to_string()]),
+            ("In Progress".to_string(), vec!["Первая".to_string()]),
+            ("Done".to_string(), vec![]),
+        ]
+    );
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Перемещение по колонкам ====================
+
+#[tokio::test]
+async fn move_to_custom_column_respects_wip_limit_and_transitions() {
+    let state = common::test_app_state().await;
+    let email = "board_wip@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, review) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/statuses", list_id),
+        serde_json::json!({ "name": "Review", "category": "active", "wip_limit": 1 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let review_id = review["id"].as_str().unwrap().to_string();
+
+    let first = common::create_task(&state, &token, &list_id, "Первая").await;
+    let second = common::create_task(&state, &token, &list_id, "Вторая").await;
+
+    let (status, task) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, first),
+        serde_json::json!({ "status_id": review_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["status"], "in_progress");
+    assert_eq!(task["column"]["name"], "Review");
+    assert!(task["started_at"].is_string());
+
+    // В колонке уже одна задача при лимите 1.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, second),
+        serde_json::json!({ "status_id": review_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Повторное перемещение в ту же колонку лимит не нарушает.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, first),
+        serde_json::json!({ "status_id": review_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let done_id = status_id(&state, &token, &list_id, "Done").await;
+    let (status, task) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, first),
+        serde_json::json!({ "status_id": done_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["status"], "done");
+
+    // Из done — только через reopen, как и при смене статуса.
+    let todo_id = status_id(&state, &token, &list_id, "To Do").await;
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, first),
+        serde_json::json!({ "status_id": todo_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn wip_limit_applies_to_every_path_into_a_column() {
+    let state = common::test_app_state().await;
+    let email = "board_wip_paths@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let other_list = common::create_list(&state, &token).await;
+
+    for (list, name, category) in [
+        (&list_id, "To Do", "not_started"),
+        (&list_id, "In Progress", "active"),
+        (&other_list, "To Do", "not_started"),
+    ] {
+        let id = status_id(&state, &token, list, name).await;
+        let (status, _) = common::send(
+            &state,
+            &token,
+            "PUT",
+            format!("/lists/{}/statuses/{}", list, id),
+            serde_json::json!({ "name": name, "category": category, "wip_limit": 1 }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Создание, копия и пакетное создание в заполненную колонку "To Do".
+    let first = common::create_task(&state, &token, &list_id, "Первая").await;
+    let tasks_uri = format!("/lists/{}/tasks", list_id);
+    let (status, _) = common::send(&state, &token, "POST", tasks_uri.clone(), serde_json::json!({ "title": "Вторая" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("{}/{}/copy", tasks_uri, first),
+        serde_json::json!({ "target_list_id": list_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("{}/bulk", tasks_uri),
+        serde_json::json!({ "operations": [{ "op": "create", "title": "Вторая" }] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Смена статуса: PUT .../status, PUT и PATCH задачи.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("{}/{}/status", tasks_uri, first),
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let second = common::create_task(&state, &token, &list_id, "Вторая").await;
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("{}/{}/status", tasks_uri, second),
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("{}/{}", tasks_uri, second),
+        serde_json::json!({ "title": "Вторая", "status": "in_progress" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PATCH",
+        format!("{}/{}", tasks_uri, second),
+        serde_json::json!({ "status": "in_progress" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Перенос в другой список с заполненной колонкой.
+    common::create_task(&state, &token, &other_list, "Чужая").await;
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("{}/{}/move", tasks_uri, second),
+        serde_json::json!({ "target_list_id": other_list }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Ни одна отклонённая операция не оставила следов.
+    let (_, board) = common::send(&state, &token, "GET", format!("/lists/{}/board", list_id), serde_json::Value::Null).await;
+    assert_eq!(
+        board_layout(&board),
+        vec![
+            ("To Do".to_string(), vec!["Вторая".to_string()]),
+            ("In Progress".to_string(), vec!["Первая".to_string()]),
+            ("Done".to_string(), vec![]),
+        ]
+    );
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Управление статусами ====================
+
+#[tokio::test]
+async fn status_management_keeps_every_category_covered() {
+    let state = common::test_app_state().await;
+    let email = "board_manage@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/statuses", list_id),
+        serde_json::json!({ "name": "Archived", "category": "archived" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/statuses", list_id),
+        serde_json::json!({ "name": "Done", "category": "done" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Последний статус категории нельзя удалить или перевести в другую категорию.
+    let in_progress_id = status_id(&state, &token, &list_id, "In Progress").await;
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "DELETE",
+        format!("/lists/{}/statuses/{}", list_id, in_progress_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/statuses/{}", list_id, in_progress_id),
+        serde_json::json!({ "name": "In Progress", "category": "done" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Задачи удалённой колонки переходят в первую колонку той же категории.
+    let (_, qa) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/statuses", list_id),
+        serde_json::json!({ "name": "QA", "category": "active" }),
+    )
+    .await;
+    let qa_id = qa["id"].as_str().unwrap().to_string();
+    let task_id = common::create_task(&state, &token, &list_id, "Проверить").await;
+    common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/column", list_id, task_id),
+        serde_json::json!({ "status_id": qa_id }),
+    )
+    .await;
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "DELETE",
+        format!("/lists/{}/statuses/{}", list_id, qa_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, task) = common::send(
+        &state,
+        &token,
+        "GET",
+        format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(task["status"], "in_progress");
+    assert_eq!(task["column"]["name"], "In Progress");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn concurrent_duplicate_statuses_return_409() {
+    let state = common::test_app_state().await;
+    let email = "board_duplicate_race@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    // Оба запроса могут пройти проверку имени до вставки — второй упрётся в UNIQUE.
+    let uri = format!("/lists/{}/statuses", list_id);
+    let results = futures_util::future::join_all((0..4).map(|_| {
+        common::send(&state, &token, "POST", &uri, serde_json::json!({ "name": "Review", "category": "active" }))
+    }))
+    .await;
+    let mut statuses: Vec<StatusCode> = results.into_iter().map(|(status, _)| status).collect();
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        vec![StatusCode::CREATED, StatusCode::CONFLICT, StatusCode::CONFLICT, StatusCode::CONFLICT]
+    );
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn columns_can_be_reordered() {
+    let state = common::test_app_state().await;
+    let email = "board_reorder@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let done_id = status_id(&state, &token, &list_id, "Done").await;
+    let todo_id = status_id(&state, &token, &list_id, "To Do").await;
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "PATCH",
+        format!("/lists/{}/statuses/{}/position", list_id, done_id),
+        serde_json::json!({ "before_id": todo_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, board) = common::send(&state, &token, "GET", format!("/lists/{}/board", list_id), serde_json::Value::Null).await;
+    let names: Vec<String> = board_layout(&board).into_iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["Done", "To Do", "In Progress"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
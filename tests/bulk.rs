@@ -0,0 +1,147 @@
+/// Тесты пакетных операций над задачами (POST /lists/:list_id/tasks/bulk).
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::state::AppState;
+
+/// Статусы результатов пакета по порядку.
+fn statuses(body: &serde_json::Value) -> Vec<u64> {
+    body["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["status"].as_u64().unwrap())
+        .collect()
+}
+
+/// Заголовки задач списка.
+async fn task_titles(state: &AppState, token: &str, list_id: &str) -> Vec<String> {
+    let (_, body) = common::send(state, token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+
+// ==================== mode: atomic ====================
+
+#[tokio::test]
+async fn atomic_batch_applies_all_operations() {
+    let state = common::test_app_state().await;
+    let email = "bulk_atomic@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let other_list = common::create_list(&state, &token).await;
+    let done = common::create_task(&state, &token, &list_id, "Готово").await;
+    let moved = common::create_task(&state, &token, &list_id, "Переезд").await;
+    let removed = common::create_task(&state, &token, &list_id, "Удалить").await;
+    let (_, label) = common::send(&state, &token, "POST", "/labels".to_string(),
+        serde_json::json!({ "name": "bulk", "color": "#ff9800" })).await;
+
+    let (status, body) = common::send(&state, &token, "POST", format!("/lists/{}/tasks/bulk", list_id), serde_json::json!({
+        "operations": [
+            { "op": "create", "title": "Новая" },
+            { "op": "update_status", "task_id": done, "status": "done" },
+            { "op": "label", "task_id": done, "label_id": label["id"] },
+            { "op": "move", "task_id": moved, "target_list_id": other_list },
+            { "op": "delete", "task_id": removed }
+        ]
+    })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["committed"], true);
+    assert_eq!(statuses(&body), [201, 200, 200, 200, 204]);
+    assert_eq!(body["results"][1]["task"]["status"], "done");
+    assert_eq!(body["results"][2]["task"]["labels"][0]["name"], "bulk");
+    assert!(body["results"][4]["task"].is_null());
+
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["Готово", "Новая"]);
+    assert_eq!(task_titles(&state, &token, &other_list).await, ["Переезд"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn atomic_batch_rolls_back_on_first_error() {
+    let state = common::test_app_state().await;
+    let email = "bulk_rollback@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+
+    let (status, body) = common::send(&state, &token, "POST", format!("/lists/{}/tasks/bulk", list_id), serde_json::json!({
+        "mode": "atomic",
+        "operations": [
+            { "op": "create", "title": "Не появится" },
+            { "op": "delete", "task_id": task_id },
+            { "op": "update_status", "task_id": task_id, "status": "done" },
+            { "op": "create", "title": "Не выполнится" }
+        ]
+    })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["committed"], false);
+    assert_eq!(statuses(&body), [424, 424, 404, 424]);
+
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["Задача"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== mode: best_effort ====================
+
+#[tokio::test]
+async fn best_effort_batch_skips_failed_operations() {
+    let state = common::test_app_state().await;
+    let email = "bulk_best_effort@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+
+    let (status, body) = common::send(&state, &token, "POST", format!("/lists/{}/tasks/bulk", list_id), serde_json::json!({
+        "mode": "best_effort",
+        "operations": [
+            { "op": "create", "title": "Появится" },
+            { "op": "update_status", "task_id": task_id, "status": "archived" },
+            { "op": "move", "task_id": task_id, "target_list_id": uuid::Uuid::new_v4() },
+            { "op": "update_status", "task_id": task_id, "status": "in_progress" }
+        ]
+    })).await;
+    assert_eq!(status, StatusCode::MULTI_STATUS);
+    assert_eq!(body["committed"], true);
+    assert_eq!(statuses(&body), [201, 422, 404, 200]);
+    assert!(body["results"][1]["error"].as_str().unwrap().contains("status"));
+
+    let (_, task) = common::send(&state, &token, "GET", format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::Value::Null).await;
+    assert_eq!(task["status"], "in_progress");
+    assert_eq!(task["list_id"], list_id.as_str());
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["Задача", "Появится"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn bulk_rejects_empty_or_oversized_batches() {
+    let state = common::test_app_state().await;
+    let email = "bulk_limits@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let uri = format!("/lists/{}/tasks/bulk", list_id);
+
+    let (status, _) = common::send(&state, &token, "POST", uri.clone(), serde_json::json!({ "operations": [] })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let operations: Vec<_> = (0..101).map(|i| serde_json::json!({ "op": "create", "title": i.to_string() })).collect();
+    let (status, _) = common::send(&state, &token, "POST", uri, serde_json::json!({ "operations": operations })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(task_titles(&state, &token, &list_id).await.is_empty());
+
+    common::cleanup_user(&state.db, email).await;
+}
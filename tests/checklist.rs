@@ -0,0 +1,212 @@
+/// Интеграционные тесты для чек-листа внутри задачи.
+mod common;
+
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::state::AppState;
+
+/// Добавляет пункт в чек-лист и возвращает его UUID.
+async fn create_item(state: &AppState, token: &str, list_id: &str, task_id: &str, title: &str) -> String {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/items", list_id, task_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "title": title }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+/// Отмечает пункт чек-листа выполненным.
+async fn check_item(state: &AppState, token: &str, list_id: &str, task_id: &str, item_id: &str) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/lists/{}/tasks/{}/items/{}", list_id, task_id, item_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "title": "Checked", "done": true }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+/// Получает задачу по ID (JSON).
+async fn get_task(state: &AppState, token: &str, list_id: &str, task_id: &str) -> serde_json::Value {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks/{}", list_id, task_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+
+// ==================== /lists/:list_id/tasks/:task_id/items ====================
+
+#[tokio::test]
+async fn create_and_list_items() {
+    let state = common::test_app_state().await;
+    let email = "checklist_create@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Plan trip").await;
+
+    create_item(&state, &token, &list_id, &task_id, "Book hotel").await;
+    create_item(&state, &token, &list_id, &task_id, "Buy tickets").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks/{}/items", list_id, task_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    let items = body.as_array().expect("Response should be an array");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["title"], "Book hotel");
+    assert_eq!(items[0]["done"], false);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn task_response_contains_checklist_progress() {
+    let state = common::test_app_state().await;
+    let email = "checklist_progress@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Release").await;
+
+    let first = create_item(&state, &token, &list_id, &task_id, "Tag").await;
+    create_item(&state, &token, &list_id, &task_id, "Publish").await;
+    check_item(&state, &token, &list_id, &task_id, &first).await;
+
+    let task = get_task(&state, &token, &list_id, &task_id).await;
+    assert_eq!(task["checklist"]["done"], 1);
+    assert_eq!(task["checklist"]["total"], 2);
+    // auto_complete выключен — задача остаётся в "todo".
+    assert_eq!(task["status"], "todo");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn auto_complete_marks_task_done_when_all_items_done() {
+    let state = common::test_app_state().await;
+    let email = "checklist_auto@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    // Создаём задачу с включённым auto_complete.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks", list_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "title": "Groceries", "auto_complete": true }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let created: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    let task_id = created["id"].as_str().unwrap().to_string();
+
+    let first = create_item(&state, &token, &list_id, &task_id, "Milk").await;
+    let second = create_item(&state, &token, &list_id, &task_id, "Bread").await;
+
+    check_item(&state, &token, &list_id, &task_id, &first).await;
+    let task = get_task(&state, &token, &list_id, &task_id).await;
+    assert_eq!(task["status"], "todo");
+
+    check_item(&state, &token, &list_id, &task_id, &second).await;
+    let task = get_task(&state, &token, &list_id, &task_id).await;
+    assert_eq!(task["status"], "done");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn delete_item_returns_204() {
+    let state = common::test_app_state().await;
+    let email = "checklist_delete@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Cleanup").await;
+    let item_id = create_item(&state, &token, &list_id, &task_id, "Vacuum").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("DELETE")
+        .uri(format!("/lists/{}/tasks/{}/items/{}", list_id, task_id, item_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    let task = get_task(&state, &token, &list_id, &task_id).await;
+    assert_eq!(task["checklist"]["total"], 0);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn items_of_foreign_task_return_404() {
+    let state = common::test_app_state().await;
+
+    let email_a = "checklist_foreign_a@example.com";
+    common::cleanup_user(&state.db, email_a).await;
+    let token_a = common::get_auth_token(&state, email_a).await;
+    let list_id = common::create_list(&state, &token_a).await;
+    let task_id = common::create_task(&state, &token_a, &list_id, "Private").await;
+
+    let email_b = "checklist_foreign_b@example.com";
+    common::cleanup_user(&state.db, email_b).await;
+    let token_b = common::get_auth_token(&state, email_b).await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks/{}/items", list_id, task_id))
+        .header("Authorization", format!("Bearer {}", token_b))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email_a).await;
+    common::cleanup_user(&state.db, email_b).await;
+}
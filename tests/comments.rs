@@ -0,0 +1,128 @@
+/// Интеграционные тесты для комментариев к задачам.
+mod common;
+
+use axum::http::StatusCode;
+
+// ==================== POST / GET /lists/:list_id/tasks/:task_id/comments ====================
+
+#[tokio::test]
+async fn create_list_and_edit_comment() {
+    let state = common::test_app_state().await;
+    let email = "comments_crud@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Release").await;
+    let uri = format!("/lists/{}/tasks/{}/comments", list_id, task_id);
+
+    let (status, body) = common::send(
+        &state, &token, "POST", uri.clone(),
+        serde_json::json!({ "body": "Ждём **ревью**" }),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["author_email"], email);
+    assert!(body["edited_at"].is_null());
+    let comment_id = body["id"].as_str().unwrap().to_string();
+
+    let (status, body) = common::send(
+        &state, &token, "PUT", format!("{}/{}", uri, comment_id),
+        serde_json::json!({ "body": "Ревью пройдено" }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["body"], "Ревью пройдено");
+    assert!(body["edited_at"].is_string());
+
+    let (status, body) = common::send(&state, &token, "GET", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    let (status, _) = common::send(&state, &token, "DELETE", format!("{}/{}", uri, comment_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn create_comment_empty_body_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "comments_empty@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Task").await;
+
+    let (status, _) = common::send(
+        &state, &token, "POST", format!("/lists/{}/tasks/{}/comments", list_id, task_id),
+        serde_json::json!({ "body": "   " }),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn comments_on_foreign_task_return_404() {
+    let state = common::test_app_state().await;
+    let owner_email = "comments_owner@example.com";
+    let other_email = "comments_other@example.com";
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let owner = common::get_auth_token(&state, owner_email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+    let list_id = common::create_list(&state, &owner).await;
+    let task_id = common::create_task(&state, &owner, &list_id, "Private").await;
+
+    let (status, _) = common::send(
+        &state, &other, "POST", format!("/lists/{}/tasks/{}/comments", list_id, task_id),
+        serde_json::json!({ "body": "Hi" }),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, other_email).await;
+}
+
+
+// ==================== PUT / DELETE .../comments/:comment_id ====================
+
+#[tokio::test]
+async fn only_author_can_modify_comment() {
+    let state = common::test_app_state().await;
+    let owner_email = "comments_author_owner@example.com";
+    let author_email = "comments_author_other@example.com";
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, author_email).await;
+    let owner = common::get_auth_token(&state, owner_email).await;
+    let author = common::get_auth_token(&state, author_email).await;
+    let list_id = common::create_list(&state, &owner).await;
+    let task_id = common::create_task(&state, &owner, &list_id, "Shared").await;
+
+    // Исполнитель задачи видит её комментарии и может писать свои.
+    let (status, _) = common::send(
+        &state, &owner, "PUT", format!("/lists/{}/tasks/{}/assignee", list_id, task_id),
+        serde_json::json!({ "email": author_email }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    let comments = format!("/lists/{}/tasks/{}/comments", list_id, task_id);
+    let (status, body) = common::send(&state, &author, "POST", comments.clone(), serde_json::json!({ "body": "Not yours" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["author_email"], author_email);
+    let uri = format!("{}/{}", comments, body["id"].as_str().unwrap());
+    let (status, body) = common::send(&state, &author, "GET", comments, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // Даже владелец списка не может править чужой комментарий.
+    let (status, _) = common::send(&state, &owner, "PUT", uri.clone(), serde_json::json!({ "body": "Edited" })).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = common::send(&state, &owner, "DELETE", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = common::send(&state, &author, "PUT", uri.clone(), serde_json::json!({ "body": "Edited" })).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = common::send(&state, &author, "DELETE", uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, owner_email).await;
+    common::cleanup_user(&state.db, author_email).await;
+}
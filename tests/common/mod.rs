@@ -1,6 +1,7 @@
 /// Общие утилиты для интеграционных тестов.
 use http_body_util::BodyExt;
-use axum::http::{Request};
+use axum::http::header::LINK;
+use axum::http::{HeaderMap, Request, StatusCode};
 use tower::ServiceExt;
 use sqlx::PgPool;
 
@@ -22,6 +23,9 @@ pub async fn test_app_state() -> AppState {
     AppState {
         db,
         jwt_secret: "test-secret-key".to_string(),
+        blobs: std::sync::Arc::new(todo_api::storage::local::LocalBlobStore::new(
+            std::env::temp_dir().join("todo-api-test-attachments"),
+        )),
     }
 }
 
@@ -76,4 +80,102 @@ pub async fn create_list(state: &todo_api::state::AppState, token: &str) -> Stri
     let bytes = resp.into_body().collect().await.unwrap().to_bytes();
     let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
     body["id"].as_str().unwrap().to_string()
-}
\ No newline at end of file
+}
+#[allow(dead_code)]
+/// Создаёт задачу в списке и возвращает её UUID.
+pub async fn create_task(
+    state: &todo_api::state::AppState,
+    token: &str,
+    list_id: &str,
+    title: &str,
+) -> String {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks", list_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "title": title }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+#[allow(dead_code)]
+/// Отправляет JSON-запрос от имени пользователя, возвращает статус и тело
+/// (пустое или не-JSON тело ответа — `Null`). `None` — запрос без тела.
+pub async fn send(
+    state: &AppState,
+    token: &str,
+    method: &str,
+    uri: impl AsRef<str>,
+    body: impl Into<Option<serde_json::Value>>,
+) -> (StatusCode, serde_json::Value) {
+    let (status, _, body) = send_with_headers(state, token, method, uri, &[], body).await;
+    (status, body)
+}
+
+#[allow(dead_code)]
+/// Как `send`, но возвращает ещё ссылку на следующую страницу (из `Link`).
+pub async fn send_paged(
+    state: &AppState,
+    token: &str,
+    method: &str,
+    uri: impl AsRef<str>,
+    body: impl Into<Option<serde_json::Value>>,
+) -> (StatusCode, Option<String>, serde_json::Value) {
+    let (status, headers, body) = send_with_headers(state, token, method, uri, &[], body).await;
+    let next = headers.get(LINK).map(|value| {
+        let value = value.to_str().unwrap();
+        value[1..value.find('>').unwrap()].to_string()
+    });
+    (status, next, body)
+}
+
+#[allow(dead_code)]
+/// Отправляет запрос с дополнительными заголовками, возвращает статус, заголовки и тело.
+pub async fn send_with_headers(
+    state: &AppState,
+    token: &str,
+    method: &str,
+    uri: impl AsRef<str>,
+    headers: &[(&str, &str)],
+    body: impl Into<Option<serde_json::Value>>,
+) -> (StatusCode, HeaderMap, serde_json::Value) {
+    let (status, headers, bytes) = send_raw(state, token, method, uri, headers, body).await;
+    (status, headers, serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+}
+
+#[allow(dead_code)]
+/// Отправляет запрос, возвращает статус, заголовки и тело ответа как есть.
+pub async fn send_raw(
+    state: &AppState,
+    token: &str,
+    method: &str,
+    uri: impl AsRef<str>,
+    headers: &[(&str, &str)],
+    body: impl Into<Option<serde_json::Value>>,
+) -> (StatusCode, HeaderMap, Vec<u8>) {
+    let app = create_router().with_state(state.clone());
+    let mut req = Request::builder()
+        .method(method)
+        .uri(uri.as_ref())
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token));
+    for (name, value) in headers {
+        req = req.header(*name, *value);
+    }
+    let body = match body.into() {
+        Some(body) => axum::body::Body::from(body.to_string()),
+        None => axum::body::Body::empty(),
+    };
+    let resp = app.oneshot(req.body(body).unwrap()).await.unwrap();
+    let status = resp.status();
+    let headers = resp.headers().clone();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, headers, bytes.to_vec())
+}
@@ -0,0 +1,235 @@
+/// Интеграционные тесты для зависимостей между задачами.
+mod common;
+
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::state::AppState;
+
+/// Добавляет зависимость `task_id → depends_on_id`, возвращает ответ целиком.
+async fn add_dependency(
+    state: &AppState,
+    token: &str,
+    list_id: &str,
+    task_id: &str,
+    depends_on_id: &str,
+) -> (StatusCode, serde_json::Value) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/dependencies", list_id, task_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "depends_on_id": depends_on_id }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+/// Обновляет задачу через PUT, возвращает HTTP-статус.
+async fn update_task(
+    state: &AppState,
+    token: &str,
+    list_id: &str,
+    task_id: &str,
+    body: serde_json::Value,
+) -> StatusCode {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/lists/{}/tasks/{}", list_id, task_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(body.to_string()))
+        .unwrap();
+    app.oneshot(req).await.unwrap().status()
+}
+
+
+// ==================== POST /lists/:list_id/tasks/:task_id/dependencies ====================
+
+#[tokio::test]
+async fn add_dependency_marks_task_blocked() {
+    let state = common::test_app_state().await;
+    let email = "deps_blocked@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+
+    let (status, body) = add_dependency(&state, &token, &list_id, &b, &a).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["blocked"], true);
+    assert_eq!(body["depends_on"][0], a.as_str());
+
+    // После выполнения A задача B больше не заблокирована.
+    let status = update_task(
+        &state, &token, &list_id, &a,
+        serde_json::json!({ "title": "A", "status": "done" }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks/{}", list_id, b))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["blocked"], false);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn add_dependency_across_lists() {
+    let state = common::test_app_state().await;
+    let email = "deps_cross_list@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_a = common::create_list(&state, &token).await;
+    let list_b = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_a, "Vendor delivers").await;
+    let b = common::create_task(&state, &token, &list_b, "Install").await;
+
+    let (status, body) = add_dependency(&state, &token, &list_b, &b, &a).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["blocked"], true);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn dependency_cycle_returns_409() {
+    let state = common::test_app_state().await;
+    let email = "deps_cycle@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+    let c = common::create_task(&state, &token, &list_id, "C").await;
+
+    // B → A, C → B; затем A → C замыкает цикл.
+    let (status, _) = add_dependency(&state, &token, &list_id, &b, &a).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = add_dependency(&state, &token, &list_id, &c, &b).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = add_dependency(&state, &token, &list_id, &a, &c).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Зависимость от самой себя — 422.
+    let (status, _) = add_dependency(&state, &token, &list_id, &a, &a).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn dependency_on_foreign_task_returns_404() {
+    let state = common::test_app_state().await;
+
+    let email_a = "deps_foreign_a@example.com";
+    common::cleanup_user(&state.db, email_a).await;
+    let token_a = common::get_auth_token(&state, email_a).await;
+    let list_a = common::create_list(&state, &token_a).await;
+    let own = common::create_task(&state, &token_a, &list_a, "Mine").await;
+
+    let email_b = "deps_foreign_b@example.com";
+    common::cleanup_user(&state.db, email_b).await;
+    let token_b = common::get_auth_token(&state, email_b).await;
+    let list_b = common::create_list(&state, &token_b).await;
+    let foreign = common::create_task(&state, &token_b, &list_b, "Theirs").await;
+
+    let (status, _) = add_dependency(&state, &token_a, &list_a, &own, &foreign).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email_a).await;
+    common::cleanup_user(&state.db, email_b).await;
+}
+
+
+// ==================== PUT с заблокированной задачей ====================
+
+#[tokio::test]
+async fn starting_blocked_task_returns_409_unless_forced() {
+    let state = common::test_app_state().await;
+    let email = "deps_force@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+    add_dependency(&state, &token, &list_id, &b, &a).await;
+
+    let status = update_task(
+        &state, &token, &list_id, &b,
+        serde_json::json!({ "title": "B", "status": "in_progress" }),
+    ).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Переименование без смены статуса разрешено.
+    let status = update_task(
+        &state, &token, &list_id, &b,
+        serde_json::json!({ "title": "B renamed", "status": "todo" }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let status = update_task(
+        &state, &token, &list_id, &b,
+        serde_json::json!({ "title": "B", "status": "in_progress", "force": true }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== DELETE /lists/:list_id/tasks/:task_id/dependencies/:id ====================
+
+#[tokio::test]
+async fn remove_dependency_returns_204() {
+    let state = common::test_app_state().await;
+    let email = "deps_remove@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+    add_dependency(&state, &token, &list_id, &b, &a).await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("DELETE")
+        .uri(format!("/lists/{}/tasks/{}/dependencies/{}", list_id, b, a))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    // Повторное удаление — 404.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("DELETE")
+        .uri(format!("/lists/{}/tasks/{}/dependencies/{}", list_id, b, a))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
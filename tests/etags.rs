@@ -0,0 +1,186 @@
+/// Тесты оптимистичных блокировок: ETag, If-Match и If-None-Match.
+mod common;
+
+use axum::http::StatusCode;
+
+// ==================== Задачи ====================
+
+#[tokio::test]
+async fn task_etag_changes_on_update_and_guards_writes() {
+    let state = common::test_app_state().await;
+    let email = "etags_tasks@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    let (status, headers, body) = common::send_with_headers(&state, &token, "GET", &uri, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"1\"");
+    assert_eq!(body["version"], 1);
+
+    let (status, headers, body) = common::send_with_headers(&state, &token, "GET", &uri, &[("If-None-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+    assert_eq!(headers["etag"], "\"1\"");
+    assert!(body.is_null());
+
+    let update = serde_json::json!({ "title": "Первая правка", "status": "todo" });
+    let (status, headers, _) =
+        common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"1\"")], Some(update)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"2\"");
+
+    // Второй клиент всё ещё держит версию 1 — его правка отклоняется.
+    let stale = serde_json::json!({ "title": "Вторая правка", "status": "todo" });
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"1\"")], Some(stale)).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+    let (status, _, _) = common::send_with_headers(&state, &token, "PATCH", &uri, &[("If-Match", "\"1\"")],
+        Some(serde_json::json!({ "status": "done" }))).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (status, _, body) = common::send_with_headers(&state, &token, "GET", &uri, &[("If-None-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Первая правка");
+
+    let (status, headers, _) = common::send_with_headers(&state, &token, "PATCH", &uri, &[("If-Match", "*")],
+        Some(serde_json::json!({ "status": "done" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"3\"");
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "DELETE", &uri, &[("If-Match", "\"2\"")], None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+    let (status, _, _) = common::send_with_headers(&state, &token, "DELETE", &uri, &[("If-Match", "\"2\", \"3\"")], None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn task_status_update_honours_if_match() {
+    let state = common::test_app_state().await;
+    let email = "etags_status@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+    let uri = format!("/lists/{}/tasks/{}/status", list_id, task_id);
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"7\"")],
+        Some(serde_json::json!({ "status": "done" }))).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (status, headers, body) = common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"1\"")],
+        Some(serde_json::json!({ "status": "done" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"2\"");
+    assert_eq!(body["status"], "done");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Списки ====================
+
+#[tokio::test]
+async fn task_etag_changes_when_embedded_data_changes() {
+    let state = common::test_app_state().await;
+    let email = "etags_relations@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+    let blocker_id = common::create_task(&state, &token, &list_id, "Блокер").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    // После каждого изменения старый ETag не даёт 304, а новое тело приходит целиком.
+    let mut etag = String::new();
+    let mut expect_changed = async |what: &str| {
+        let (status, headers, _) = common::send_with_headers(&state, &token, "GET", &uri, &[("If-None-Match", &etag)], None).await;
+        assert_eq!(status, StatusCode::OK, "{what}");
+        etag = headers["etag"].to_str().unwrap().to_string();
+    };
+    expect_changed("initial").await;
+
+    let (_, _, label) = common::send_with_headers(&state, &token, "POST", "/labels", &[], Some(serde_json::json!({ "name": "etag" }))).await;
+    let label_uri = format!("{}/labels/{}", uri, label["id"].as_str().unwrap());
+    let (status, _, _) = common::send_with_headers(&state, &token, "POST", &label_uri, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("label attached").await;
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &format!("/labels/{}", label["id"].as_str().unwrap()), &[],
+        Some(serde_json::json!({ "name": "etag-2", "color": "#ff9800" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("label renamed").await;
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "POST", &format!("{}/items", uri), &[],
+        Some(serde_json::json!({ "title": "Пункт" }))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    expect_changed("checklist item added").await;
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "POST", &format!("{}/dependencies", uri), &[],
+        Some(serde_json::json!({ "depends_on_id": blocker_id }))).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("dependency added").await;
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &format!("/lists/{}/tasks/{}/status", list_id, blocker_id), &[],
+        Some(serde_json::json!({ "status": "done" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("blocker completed").await;
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "POST", &format!("{}/timer/start", uri), &[], None).await;
+    assert_eq!(status, StatusCode::CREATED);
+    expect_changed("timer started").await;
+    let (status, _, _) = common::send_with_headers(&state, &token, "POST", &format!("{}/timer/stop", uri), &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("timer stopped").await;
+
+    let (_, _, statuses) = common::send_with_headers(&state, &token, "GET", &format!("/lists/{}/statuses", list_id), &[], None).await;
+    let todo = statuses.as_array().unwrap().iter().find(|status| status["category"] == "not_started").unwrap();
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &format!("/lists/{}/statuses/{}", list_id, todo["id"].as_str().unwrap()), &[],
+        Some(serde_json::json!({ "name": "Бэклог", "category": "not_started", "wip_limit": null }))).await;
+    assert_eq!(status, StatusCode::OK);
+    expect_changed("column renamed").await;
+
+    // If-Match со старой версией после изменения связей отклоняется.
+    let (status, _, _) = common::send_with_headers(&state, &token, "DELETE", &uri, &[("If-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn list_etag_and_conditional_requests() {
+    let state = common::test_app_state().await;
+    let email = "etags_lists@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let uri = format!("/lists/{}", list_id);
+
+    let (status, headers, _) = common::send_with_headers(&state, &token, "GET", &uri, &[], None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"1\"");
+
+    // If-None-Match сравнивается слабо.
+    let (status, _, _) = common::send_with_headers(&state, &token, "GET", &uri, &[("If-None-Match", "W/\"1\"")], None).await;
+    assert_eq!(status, StatusCode::NOT_MODIFIED);
+
+    let (status, headers, _) = common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"1\"")],
+        Some(serde_json::json!({ "title": "Работа" }))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["etag"], "\"2\"");
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "PUT", &uri, &[("If-Match", "\"1\"")],
+        Some(serde_json::json!({ "title": "Дом" }))).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    let (status, _, _) = common::send_with_headers(&state, &token, "DELETE", &uri, &[("If-Match", "\"1\"")], None).await;
+    assert_eq!(status, StatusCode::PRECONDITION_FAILED);
+
+    // Без If-Match запись безусловная, как раньше.
+    let (status, _, _) = common::send_with_headers(&state, &token, "DELETE", &uri, &[], None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, email).await;
+}
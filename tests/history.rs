@@ -0,0 +1,182 @@
+/// Тесты журнала изменений списков и задач.
+mod common;
+
+use axum::http::StatusCode;
+
+/// Действия событий страницы журнала (от новых к старым).
+fn actions(page: &serde_json::Value) -> Vec<String> {
+    page["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["action"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// ==================== Журнал задачи ====================
+
+#[tokio::test]
+async fn task_changes_are_recorded_with_actor_and_diff() {
+    let state = common::test_app_state().await;
+    let email = "history_task@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Черновик").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "title": "Отчёт", "estimate_minutes": 30 })).await;
+    // Повтор тех же значений ничего не меняет — и в журнал не попадает.
+    common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "title": "Отчёт" })).await;
+
+    let (status, page) = common::send(&state, &token, "GET", format!("{}/history", uri), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(actions(&page), vec!["updated", "created"]);
+    assert!(page["next_before"].is_null());
+
+    let updated = &page["events"][0];
+    assert_eq!(updated["actor_email"], email);
+    assert_eq!(
+        updated["changes"],
+        serde_json::json!({
+            "title": { "old": "Черновик", "new": "Отчёт" },
+            "estimate_minutes": { "old": null, "new": 30 },
+        })
+    );
+
+    let created = &page["events"][1];
+    assert_eq!(created["changes"]["title"], serde_json::json!({ "old": null, "new": "Черновик" }));
+    assert_eq!(created["changes"]["status"], serde_json::json!({ "old": null, "new": "todo" }));
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Журнал списка ====================
+
+#[tokio::test]
+async fn list_history_keeps_deleted_tasks() {
+    let state = common::test_app_state().await;
+    let email = "history_list@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Удалить меня").await;
+
+    common::send(&state, &token, "PUT", format!("/lists/{}", list_id), serde_json::json!({ "title": "Переименован" })).await;
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "DELETE",
+        format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, page) = common::send(&state, &token, "GET", format!("/lists/{}/history", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(actions(&page), vec!["deleted", "updated", "created", "created"]);
+
+    let deleted = &page["events"][0];
+    assert_eq!(deleted["task_id"], task_id.as_str());
+    assert_eq!(deleted["changes"]["title"], serde_json::json!({ "old": "Удалить меня", "new": null }));
+
+    let renamed = &page["events"][1];
+    assert!(renamed["task_id"].is_null());
+    assert_eq!(renamed["changes"]["title"]["new"], "Переименован");
+
+    // Журнал удалённой задачи больше не доступен по её адресу.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "GET",
+        format!("/lists/{}/tasks/{}/history", list_id, task_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Чужой журнал не виден.
+    let stranger_email = "history_stranger@example.com";
+    common::cleanup_user(&state.db, stranger_email).await;
+    let stranger = common::get_auth_token(&state, stranger_email).await;
+    let (status, _) = common::send(&state, &stranger, "GET", format!("/lists/{}/history", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, stranger_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Пагинация ====================
+
+#[tokio::test]
+async fn history_is_paginated_from_newest() {
+    let state = common::test_app_state().await;
+    let email = "history_pages@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "v0").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    for version in 1..=4 {
+        common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "title": format!("v{}", version) })).await;
+    }
+
+    let mut titles = Vec::new();
+    let mut next = format!("{}/history?limit=2", uri);
+    loop {
+        let (status, page) = common::send(&state, &token, "GET", next.clone(), serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(page["events"].as_array().unwrap().len() <= 2);
+        for event in page["events"].as_array().unwrap() {
+            titles.push(event["changes"]["title"]["new"].as_str().unwrap().to_string());
+        }
+        match page["next_before"].as_i64() {
+            Some(before) => next = format!("{}/history?limit=2&before={}", uri, before),
+            None => break,
+        }
+    }
+    assert_eq!(titles, vec!["v4", "v3", "v2", "v1", "v0"]);
+
+    let (status, _) = common::send(&state, &token, "GET", format!("{}/history?limit=0", uri), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Append-only ====================
+
+#[tokio::test]
+async fn history_rows_cannot_be_changed_directly() {
+    let state = common::test_app_state().await;
+    let email = "history_append_only@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let list_uuid: uuid::Uuid = list_id.parse().unwrap();
+
+    let updated = sqlx::query("UPDATE history_events SET changes = '{}' WHERE list_id = $1")
+        .bind(list_uuid)
+        .execute(&state.db)
+        .await;
+    assert!(updated.is_err());
+
+    let deleted = sqlx::query("DELETE FROM history_events WHERE list_id = $1")
+        .bind(list_uuid)
+        .execute(&state.db)
+        .await;
+    assert!(deleted.is_err());
+
+    // Удаление пользователя обнуляет автора — каскад разрешён.
+    common::cleanup_user(&state.db, email).await;
+    let actors: Vec<Option<uuid::Uuid>> = sqlx::query_scalar("SELECT actor_id FROM history_events WHERE list_id = $1")
+        .bind(list_uuid)
+        .fetch_all(&state.db)
+        .await
+        .unwrap();
+    assert_eq!(actors, vec![None]);
+}
@@ -0,0 +1,294 @@
+/// Интеграционные тесты для меток (labels) и их привязки к задачам.
+mod common;
+
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::state::AppState;
+
+/// Создаёт метку и возвращает её UUID.
+async fn create_label(state: &AppState, token: &str, name: &str) -> String {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri("/labels")
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "name": name, "color": "#ff9800" }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+
+// ==================== CRUD /labels ====================
+
+#[tokio::test]
+async fn create_label_returns_201() {
+    let state = common::test_app_state().await;
+    let email = "labels_create@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri("/labels")
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "name": "work" }).to_string(),
+        ))
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["name"], "work");
+    // Цвет по умолчанию — серый.
+    assert_eq!(body["color"], "#9e9e9e");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn create_duplicate_label_returns_409() {
+    let state = common::test_app_state().await;
+    let email = "labels_duplicate@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    create_label(&state, &token, "errands").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri("/labels")
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "name": "errands" }).to_string(),
+        ))
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn concurrent_duplicate_labels_return_409() {
+    let state = common::test_app_state().await;
+    let email = "labels_duplicate_race@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    // Оба запроса могут пройти проверку имени до вставки — второй упрётся в UNIQUE.
+    let body = || serde_json::json!({ "name": "errands" });
+    let results = futures_util::future::join_all(
+        (0..4).map(|_| common::send(&state, &token, "POST", "/labels", body())),
+    )
+    .await;
+    let mut statuses: Vec<StatusCode> = results.into_iter().map(|(status, _)| status).collect();
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        vec![StatusCode::CREATED, StatusCode::CONFLICT, StatusCode::CONFLICT, StatusCode::CONFLICT]
+    );
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn create_label_with_invalid_color_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "labels_bad_color@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri("/labels")
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "name": "work", "color": "orange" }).to_string(),
+        ))
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn update_and_delete_label() {
+    let state = common::test_app_state().await;
+    let email = "labels_update_delete@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let label_id = create_label(&state, &token, "old").await;
+
+    // Обновляем name + color.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("PUT")
+        .uri(format!("/labels/{}", label_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "name": "new", "color": "#00ff00" }).to_string(),
+        ))
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["name"], "new");
+    assert_eq!(body["color"], "#00ff00");
+
+    // Удаляем метку.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("DELETE")
+        .uri(format!("/labels/{}", label_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Метки на задачах ====================
+
+#[tokio::test]
+async fn attach_label_embeds_it_in_task_response() {
+    let state = common::test_app_state().await;
+    let email = "labels_attach@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Call vendor").await;
+    let label_id = create_label(&state, &token, "blocked-on-vendor").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/labels/{}", list_id, task_id, label_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    let labels = body["labels"].as_array().expect("labels should be an array");
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0]["name"], "blocked-on-vendor");
+
+    // Снимаем метку.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("DELETE")
+        .uri(format!("/lists/{}/tasks/{}/labels/{}", list_id, task_id, label_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn attach_foreign_label_returns_404() {
+    let state = common::test_app_state().await;
+
+    let email_a = "labels_foreign_a@example.com";
+    common::cleanup_user(&state.db, email_a).await;
+    let token_a = common::get_auth_token(&state, email_a).await;
+    let list_id = common::create_list(&state, &token_a).await;
+    let task_id = common::create_task(&state, &token_a, &list_id, "My task").await;
+
+    // Метка принадлежит другому пользователю.
+    let email_b = "labels_foreign_b@example.com";
+    common::cleanup_user(&state.db, email_b).await;
+    let token_b = common::get_auth_token(&state, email_b).await;
+    let label_id = create_label(&state, &token_b, "work").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/labels/{}", list_id, task_id, label_id))
+        .header("Authorization", format!("Bearer {}", token_a))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email_a).await;
+    common::cleanup_user(&state.db, email_b).await;
+}
+
+#[tokio::test]
+async fn get_tasks_filtered_by_label() {
+    let state = common::test_app_state().await;
+    let email = "labels_filter@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let labelled = common::create_task(&state, &token, &list_id, "Labelled").await;
+    common::create_task(&state, &token, &list_id, "Plain").await;
+    let label_id = create_label(&state, &token, "work").await;
+
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks/{}/labels/{}", list_id, labelled, label_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // Фильтруем задачи по метке.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks?label_id={}", list_id, label_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+
+    let resp = app.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    let tasks = body.as_array().expect("Response should be an array");
+    assert_eq!(tasks.len(), 1);
+    assert_eq!(tasks[0]["title"], "Labelled");
+
+    common::cleanup_user(&state.db, email).await;
+}
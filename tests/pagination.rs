@@ -0,0 +1,127 @@
+/// Тесты постраничного вывода коллекций (курсоры и заголовок `Link`).
+mod common;
+
+use axum::http::header::LINK;
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::state::AppState;
+
+/// Отправляет GET-запрос от имени пользователя, возвращает статус,
+/// ссылку на следующую страницу (из `Link`) и тело.
+async fn get(state: &AppState, token: &str, uri: String) -> (StatusCode, Option<String>, serde_json::Value) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let next = resp.headers().get(LINK).map(|value| {
+        let value = value.to_str().unwrap();
+        assert!(value.ends_with("; rel=\"next\""));
+        value[1..value.find('>').unwrap()].to_string()
+    });
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, next, serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+}
+
+/// Проходит все страницы, начиная с `uri`; возвращает названия элементов
+/// и количество страниц.
+async fn collect_titles(state: &AppState, token: &str, uri: String) -> (Vec<String>, usize) {
+    let mut titles = Vec::new();
+    let mut pages = 0;
+    let mut next = Some(uri);
+    while let Some(uri) = next {
+        let (status, link, items) = get(state, token, uri).await;
+        assert_eq!(status, StatusCode::OK);
+        pages += 1;
+        for item in items.as_array().unwrap() {
+            titles.push(item["title"].as_str().unwrap().to_string());
+        }
+        next = link;
+    }
+    (titles, pages)
+}
+
+
+// ==================== Задачи ====================
+
+#[tokio::test]
+async fn tasks_are_walked_page_by_page() {
+    let state = common::test_app_state().await;
+    let email = "pages_tasks@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let mut expected = Vec::new();
+    for index in 1..=5 {
+        let title = format!("Задача {}", index);
+        common::create_task(&state, &token, &list_id, &title).await;
+        expected.push(title);
+    }
+
+    let (titles, pages) = collect_titles(&state, &token, format!("/lists/{}/tasks?limit=2", list_id)).await;
+    assert_eq!(titles, expected);
+    assert_eq!(pages, 3);
+
+    // Без limit все задачи помещаются на одну страницу — заголовка Link нет.
+    let (status, next, tasks) = get(&state, &token, format!("/lists/{}/tasks", list_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(next.is_none());
+    assert_eq!(tasks.as_array().unwrap().len(), 5);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn next_link_keeps_filters() {
+    let state = common::test_app_state().await;
+    let email = "pages_filters@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    for _ in 0..3 {
+        common::create_list(&state, &token).await;
+    }
+
+    let (status, next, lists) = get(&state, &token, "/lists?include_archived=true&limit=2".to_string()).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(lists.as_array().unwrap().len(), 2);
+    let next = next.unwrap();
+    assert!(next.starts_with("/lists?include_archived=true&limit=2&cursor="));
+
+    let (status, last, lists) = get(&state, &token, next).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(lists.as_array().unwrap().len(), 1);
+    assert!(last.is_none());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация ====================
+
+#[tokio::test]
+async fn bad_limit_or_cursor_is_rejected() {
+    let state = common::test_app_state().await;
+    let email = "pages_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    for uri in [
+        "/lists?limit=0".to_string(),
+        "/lists?limit=501".to_string(),
+        "/lists?cursor=not-a-cursor".to_string(),
+        format!("/lists/{}/tasks?cursor=e30", list_id),
+    ] {
+        let (status, _, _) = get(&state, &token, uri.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", uri);
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,188 @@
+/// Тесты частичного обновления: разреженный JSON и JSON Patch (RFC 6902).
+mod common;
+
+use axum::http::{Request, StatusCode};
+use http_body_util::BodyExt;
+use tower::ServiceExt;
+
+use todo_api::app::create_router;
+use todo_api::state::AppState;
+
+const JSON_PATCH: &str = "application/json-patch+json";
+
+/// Отправляет PATCH с указанным Content-Type, возвращает статус и тело.
+async fn patch(
+    state: &AppState,
+    token: &str,
+    uri: String,
+    content_type: &str,
+    body: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("PATCH")
+        .uri(uri)
+        .header("Content-Type", content_type)
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+}
+
+/// Создаёт задачу со сроком и возвращает её ID.
+async fn create_task_with_due(state: &AppState, token: &str, list_id: &str) -> String {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(format!("/lists/{}/tasks", list_id))
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(
+            serde_json::json!({ "title": "Отчёт", "due_at": "2026-03-10T09:00:00Z" }).to_string(),
+        ))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    body["id"].as_str().unwrap().to_string()
+}
+
+
+// ==================== PATCH /lists/:list_id/tasks/:task_id (application/json) ====================
+
+#[tokio::test]
+async fn sparse_patch_changes_only_given_fields() {
+    let state = common::test_app_state().await;
+    let email = "patch_sparse@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = create_task_with_due(&state, &token, &list_id).await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    let (status, body) = patch(&state, &token, uri.clone(), "application/json",
+        serde_json::json!({ "status": "in_progress" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "in_progress");
+    assert_eq!(body["title"], "Отчёт");
+    assert_eq!(body["due_at"], "2026-03-10T09:00:00Z");
+
+    // Явный null снимает срок, остальные поля не трогаются.
+    let (status, body) = patch(&state, &token, uri.clone(), "application/json",
+        serde_json::json!({ "due_at": null })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["due_at"].is_null());
+    assert_eq!(body["status"], "in_progress");
+
+    // Пустое тело — ничего не меняется.
+    let (status, body) = patch(&state, &token, uri, "application/json", serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Отчёт");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn sparse_patch_rejects_invalid_fields() {
+    let state = common::test_app_state().await;
+    let email = "patch_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    for body in [
+        serde_json::json!({ "title": null }),
+        serde_json::json!({ "status": "archived" }),
+        serde_json::json!({ "list_id": list_id }),
+    ] {
+        let (status, _) = patch(&state, &token, uri.clone(), "application/json", body).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== PATCH /lists/:list_id/tasks/:task_id (application/json-patch+json) ====================
+
+#[tokio::test]
+async fn json_patch_applies_operations() {
+    let state = common::test_app_state().await;
+    let email = "patch_json_patch@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = create_task_with_due(&state, &token, &list_id).await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    let (status, body) = patch(&state, &token, uri.clone(), JSON_PATCH, serde_json::json!([
+        { "op": "test", "path": "/title", "value": "Отчёт" },
+        { "op": "replace", "path": "/title", "value": "Квартальный отчёт" },
+        { "op": "replace", "path": "/status", "value": "done" },
+        { "op": "remove", "path": "/due_at" }
+    ])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Квартальный отчёт");
+    assert_eq!(body["status"], "done");
+    assert!(body["due_at"].is_null());
+
+    // Неудачный test → 409, задача не меняется.
+    let (status, _) = patch(&state, &token, uri.clone(), JSON_PATCH, serde_json::json!([
+        { "op": "test", "path": "/title", "value": "Отчёт" },
+        { "op": "replace", "path": "/title", "value": "Другое" }
+    ])).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // Неизменяемые поля и удаление обязательных — 422.
+    for operations in [
+        serde_json::json!([{ "op": "add", "path": "/list_id", "value": list_id }]),
+        serde_json::json!([{ "op": "remove", "path": "/title" }]),
+        serde_json::json!([{ "op": "jump", "path": "/title" }]),
+    ] {
+        let (status, _) = patch(&state, &token, uri.clone(), JSON_PATCH, operations).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (_, body) = patch(&state, &token, uri, "application/json", serde_json::json!({})).await;
+    assert_eq!(body["title"], "Квартальный отчёт");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== PATCH /lists/:id ====================
+
+#[tokio::test]
+async fn patch_list_title() {
+    let state = common::test_app_state().await;
+    let email = "patch_list@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, "patch_list_other@example.com").await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let uri = format!("/lists/{}", list_id);
+
+    let (status, body) = patch(&state, &token, uri.clone(), "application/json",
+        serde_json::json!({ "title": "Работа" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Работа");
+
+    let (status, body) = patch(&state, &token, uri.clone(), JSON_PATCH, serde_json::json!([
+        { "op": "replace", "path": "/title", "value": "Дом" }
+    ])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["title"], "Дом");
+
+    let other = common::get_auth_token(&state, "patch_list_other@example.com").await;
+    let (status, _) = patch(&state, &other, uri, "application/json",
+        serde_json::json!({ "title": "Чужой" })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, "patch_list_other@example.com").await;
+}
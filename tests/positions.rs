@@ -0,0 +1,235 @@
+/// Тесты ручной сортировки: ранги (models::rank) и PATCH .../position.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::models::rank::{self, MAX_RANK_LEN};
+use todo_api::state::AppState;
+
+/// Заголовки задач списка в порядке выдачи.
+async fn task_titles(state: &AppState, token: &str, list_id: &str) -> Vec<String> {
+    let (_, body) = common::send(state, token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+
+// ==================== models::rank ====================
+
+#[test]
+fn between_produces_ordered_ranks() {
+    assert_eq!(rank::between(None, None).as_deref(), Some("i"));
+
+    let cases = [
+        (None, Some("1")),
+        (Some("a"), Some("b")),
+        (Some("a"), Some("a1")),
+        (Some("a5"), Some("b")),
+        (Some("zz"), None),
+        (Some("00000001i"), Some("00000002i")),
+    ];
+    for (before, after) in cases {
+        let rank = rank::between(before, after).unwrap();
+        assert!(before.is_none_or(|before| before < rank.as_str()), "{before:?} < {rank}");
+        assert!(after.is_none_or(|after| rank.as_str() < after), "{rank} < {after:?}");
+        assert!(!rank.ends_with('0'));
+    }
+
+    assert_eq!(rank::between(Some("b"), Some("a")), None);
+    assert_eq!(rank::between(Some("a"), Some("a")), None);
+}
+
+#[test]
+fn repeated_inserts_stay_ordered() {
+    // Всё время вставляем сразу после первого элемента — худший случай роста.
+    let mut ranks = vec![rank::between(None, None).unwrap()];
+    ranks.push(rank::between(Some(&ranks[0]), None).unwrap());
+    for _ in 0..200 {
+        let rank = rank::between(Some(&ranks[0]), Some(&ranks[1])).unwrap();
+        ranks.insert(1, rank);
+    }
+    assert!(ranks.windows(2).all(|pair| pair[0] < pair[1]));
+
+    let spaced = rank::evenly_spaced(ranks.len());
+    assert!(spaced.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(spaced.iter().all(|rank| rank.len() <= 2 && !rank.ends_with('0')));
+}
+
+#[test]
+fn repeated_edge_inserts_stay_short() {
+    // Добавление в конец и в начало не делит отрезок пополам, а сдвигает цифру.
+    let mut last = rank::between(None, None).unwrap();
+    let mut first = last.clone();
+    for _ in 0..1000 {
+        let next = rank::between(Some(&last), None).unwrap();
+        assert!(last < next && !next.ends_with('0'), "{last} < {next}");
+        last = next;
+
+        let previous = rank::between(None, Some(&first)).unwrap();
+        assert!(previous < first && !previous.ends_with('0'), "{previous} < {first}");
+        first = previous;
+    }
+    assert!(last.len() <= 30, "{last}");
+    assert!(first.len() <= 30, "{first}");
+}
+
+
+// ==================== PATCH /lists/:list_id/tasks/:task_id/position ====================
+
+#[tokio::test]
+async fn reposition_task_between_neighbours() {
+    let state = common::test_app_state().await;
+    let email = "positions_tasks@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+    let c = common::create_task(&state, &token, &list_id, "C").await;
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["A", "B", "C"]);
+
+    let (status, _) = common::send(
+        &state, &token, "PATCH", format!("/lists/{}/tasks/{}/position", list_id, c),
+        serde_json::json!({ "after_id": a }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["A", "C", "B"]);
+
+    let (status, _) = common::send(
+        &state, &token, "PATCH", format!("/lists/{}/tasks/{}/position", list_id, b),
+        serde_json::json!({ "before_id": a }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["B", "A", "C"]);
+
+    // Без соседей и относительно самой себя — 422, перепутанные соседи — тоже.
+    let uri = format!("/lists/{}/tasks/{}/position", list_id, a);
+    let (status, _) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({})).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "after_id": a })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = common::send(
+        &state, &token, "PATCH", uri, serde_json::json!({ "after_id": c, "before_id": b }),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn reposition_task_relative_to_other_list_returns_404() {
+    let state = common::test_app_state().await;
+    let email = "positions_foreign@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_a = common::create_list(&state, &token).await;
+    let list_b = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_a, "A").await;
+    let b = common::create_task(&state, &token, &list_b, "B").await;
+
+    let (status, _) = common::send(
+        &state, &token, "PATCH", format!("/lists/{}/tasks/{}/position", list_a, a),
+        serde_json::json!({ "after_id": b }),
+    ).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn long_ranks_trigger_rebalance() {
+    let state = common::test_app_state().await;
+    let email = "positions_rebalance@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+    let c = common::create_task(&state, &token, &list_id, "C").await;
+
+    // Между A и B места почти не осталось: новый ранг вышел бы за MAX_RANK_LEN.
+    let tight = format!("i{}1", "0".repeat(MAX_RANK_LEN - 2));
+    for (id, position) in [(&a, "i"), (&b, tight.as_str())] {
+        sqlx::query("UPDATE tasks SET position = $1 WHERE id = $2")
+            .bind(position)
+            .bind(id.parse::<uuid::Uuid>().unwrap())
+            .execute(&state.db)
+            .await
+            .unwrap();
+    }
+
+    let (status, body) = common::send(
+        &state, &token, "PATCH", format!("/lists/{}/tasks/{}/position", list_id, c),
+        serde_json::json!({ "after_id": a, "before_id": b }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["position"].as_str().unwrap().len() < MAX_RANK_LEN);
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["A", "C", "B"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn appending_after_longest_rank_rebalances() {
+    let state = common::test_app_state().await;
+    let email = "positions_append_rebalance@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let a = common::create_task(&state, &token, &list_id, "A").await;
+    let b = common::create_task(&state, &token, &list_id, "B").await;
+
+    // Последний ранг — одни `z` максимальной длины: следующий в конец вышел бы длиннее.
+    let longest = "z".repeat(MAX_RANK_LEN);
+    for (id, position) in [(&a, "y"), (&b, longest.as_str())] {
+        sqlx::query("UPDATE tasks SET position = $1 WHERE id = $2")
+            .bind(position)
+            .bind(id.parse::<uuid::Uuid>().unwrap())
+            .execute(&state.db)
+            .await
+            .unwrap();
+    }
+
+    common::create_task(&state, &token, &list_id, "C").await;
+    assert_eq!(task_titles(&state, &token, &list_id).await, ["A", "B", "C"]);
+    let positions: Vec<String> = sqlx::query_scalar("SELECT position FROM tasks WHERE list_id = $1")
+        .bind(list_id.parse::<uuid::Uuid>().unwrap())
+        .fetch_all(&state.db)
+        .await
+        .unwrap();
+    assert!(positions.iter().all(|position| position.len() <= 2), "{positions:?}");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== PATCH /lists/:id/position ====================
+
+#[tokio::test]
+async fn reposition_list() {
+    let state = common::test_app_state().await;
+    let email = "positions_lists@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let first = common::create_list(&state, &token).await;
+    let second = common::create_list(&state, &token).await;
+
+    // Новые списки встают первыми.
+    let (_, body) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert_eq!(body[0]["id"], second.as_str());
+
+    let (status, _) = common::send(
+        &state, &token, "PATCH", format!("/lists/{}/position", first),
+        serde_json::json!({ "before_id": second }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, body) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert_eq!(body[0]["id"], first.as_str());
+    assert_eq!(body[1]["id"], second.as_str());
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,205 @@
+/// Тесты быстрого добавления задач из текста (POST /tasks/quick-add).
+mod common;
+
+use axum::http::StatusCode;
+use chrono::{Datelike, Duration, FixedOffset, NaiveTime, TimeZone, Utc, Weekday};
+
+use todo_api::state::AppState;
+
+/// Создаёт список с названием `title`, возвращает его ID.
+async fn create_list(state: &AppState, token: &str, title: &str) -> String {
+    let (status, list) = common::send(state, token, "POST", "/lists".to_string(), serde_json::json!({ "title": title })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    list["id"].as_str().unwrap().to_string()
+}
+
+/// Быстрое добавление с телом `body`.
+async fn quick_add(state: &AppState, token: &str, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+    common::send(state, token, "POST", "/tasks/quick-add".to_string(), body).await
+}
+
+/// Момент `days` дней спустя в `hour:minute` по местному времени UTC+3, в формате ответа.
+fn moscow(days: i64, hour: u32, minute: u32) -> String {
+    let offset = FixedOffset::east_opt(3 * 3600).unwrap();
+    let date = Utc::now().with_timezone(&offset).date_naive() + Duration::days(days);
+    let local = date.and_time(NaiveTime::from_hms_opt(hour, minute, 0).unwrap());
+    let at = offset.from_local_datetime(&local).unwrap().with_timezone(&Utc);
+    serde_json::to_value(at).unwrap().as_str().unwrap().to_string()
+}
+
+
+// ==================== Разбор ====================
+
+#[tokio::test]
+async fn english_text_creates_populated_task() {
+    let state = common::test_app_state().await;
+    let email = "quick_add_en@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = create_list(&state, &token, "Покупки").await;
+
+    let (status, body) = quick_add(
+        &state,
+        &token,
+        serde_json::json!({ "text": "Buy milk tomorrow 9am @Покупки", "utc_offset_minutes": 180 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let parsed = &body["parsed"];
+    assert_eq!(parsed["title"], "Buy milk");
+    assert_eq!(parsed["list_id"], list_id.as_str());
+    assert_eq!(parsed["list_title"], "Покупки");
+    assert_eq!(parsed["status"], "todo");
+    assert_eq!(parsed["recognized"], serde_json::json!(["tomorrow", "9am", "@Покупки"]));
+    assert_eq!(parsed["due_at"], moscow(1, 9, 0).as_str());
+
+    let task = &body["task"];
+    assert_eq!(task["title"], "Buy milk");
+    assert_eq!(task["list_id"], list_id.as_str());
+    assert_eq!(task["due_at"], parsed["due_at"]);
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 1);
+
+    let (_, body) = quick_add(
+        &state,
+        &token,
+        serde_json::json!({ "text": "Call the plumber at 5:30 pm the day after tomorrow #wip", "list_id": list_id, "utc_offset_minutes": 180 }),
+    )
+    .await;
+    assert_eq!(body["parsed"]["title"], "Call the plumber");
+    assert_eq!(body["parsed"]["due_at"], moscow(2, 17, 30).as_str());
+    assert_eq!(body["task"]["status"], "in_progress");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn russian_text_with_weekday_status_and_relative_time() {
+    let state = common::test_app_state().await;
+    let email = "quick_add_ru@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    create_list(&state, &token, "Дом").await;
+
+    let (status, body) = quick_add(
+        &state,
+        &token,
+        serde_json::json!({ "text": "Позвонить маме в пятницу в 19:30 @дом #готово", "utc_offset_minutes": 180 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let today = Utc::now().with_timezone(&FixedOffset::east_opt(3 * 3600).unwrap()).date_naive();
+    let ahead = (7 + Weekday::Fri.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    let ahead = if ahead == 0 { 7 } else { i64::from(ahead) };
+    assert_eq!(body["parsed"]["title"], "Позвонить маме");
+    assert_eq!(body["parsed"]["list_title"], "Дом");
+    assert_eq!(body["parsed"]["due_at"], moscow(ahead, 19, 30).as_str());
+    assert_eq!(body["task"]["status"], "done");
+    assert!(body["task"]["completed_at"].is_string());
+
+    let before = Utc::now();
+    let (_, body) = quick_add(&state, &token, serde_json::json!({ "text": "Выключить духовку через 2 часа @Дом" })).await;
+    assert_eq!(body["parsed"]["title"], "Выключить духовку");
+    let due_at: chrono::DateTime<Utc> = serde_json::from_value(body["task"]["due_at"].clone()).unwrap();
+    assert!(due_at >= before + Duration::hours(2) && due_at <= Utc::now() + Duration::hours(2));
+
+    // Дата без времени — в 9:00; числа в названии не считаются временем.
+    let (_, body) = quick_add(
+        &state,
+        &token,
+        serde_json::json!({ "text": "Купить 2 лампочки завтра, @Дом", "utc_offset_minutes": 180 }),
+    )
+    .await;
+    assert_eq!(body["parsed"]["title"], "Купить 2 лампочки");
+    assert_eq!(body["parsed"]["due_at"], moscow(1, 9, 0).as_str());
+
+    let (_, body) = quick_add(&state, &token, serde_json::json!({ "text": "Сходить в магазин @Дом" })).await;
+    assert_eq!(body["parsed"]["title"], "Сходить в магазин");
+    assert!(body["parsed"]["due_at"].is_null());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Выбор списка ====================
+
+#[tokio::test]
+async fn target_list_must_be_known_and_unambiguous() {
+    let state = common::test_app_state().await;
+    let email = "quick_add_lists@example.com";
+    let other_email = "quick_add_lists_other@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+    create_list(&state, &token, "Работа").await;
+    create_list(&state, &token, "Работа").await;
+    create_list(&state, &token, "Список дел").await;
+    let foreign = create_list(&state, &other, "Чужой").await;
+
+    let (status, body) = quick_add(&state, &token, serde_json::json!({ "text": "Отчёт @Список_дел" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["parsed"]["list_title"], "Список дел");
+
+    // Список с `_` в названии важнее списка с пробелом.
+    create_list(&state, &token, "Дела дома").await;
+    let literal = create_list(&state, &token, "Дела_дома").await;
+    let (status, body) = quick_add(&state, &token, serde_json::json!({ "text": "Полить цветы @Дела_дома" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["parsed"]["list_id"], literal);
+
+    let (status, _) = quick_add(&state, &token, serde_json::json!({ "text": "Отчёт @работа" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = quick_add(&state, &token, serde_json::json!({ "text": "Отчёт @Чужой" })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = quick_add(&state, &token, serde_json::json!({ "text": "Отчёт", "list_id": foreign })).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = quick_add(&state, &token, serde_json::json!({ "text": "Отчёт завтра" })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, other_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация ====================
+
+#[tokio::test]
+async fn empty_title_and_bad_offset_are_rejected() {
+    let state = common::test_app_state().await;
+    let email = "quick_add_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = create_list(&state, &token, "Inbox").await;
+
+    for body in [
+        serde_json::json!({ "text": "   ", "list_id": list_id }),
+        serde_json::json!({ "text": "tomorrow at 9 #done @Inbox" }),
+        serde_json::json!({ "text": "Task", "list_id": list_id, "utc_offset_minutes": 900 }),
+    ] {
+        let (status, _) = quick_add(&state, &token, body.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    assert!(tasks.as_array().unwrap().is_empty());
+
+    // Даты вне 1970..=9999 не распознаются и остаются в названии.
+    for (text, title) in [
+        ("x +262142-12-31 11pm", "x +262142-12-31"),
+        ("x -5000-01-01", "x -5000-01-01"),
+        ("x 01.01.1969", "x 01.01.1969"),
+    ] {
+        let (status, body) = quick_add(
+            &state, &token,
+            serde_json::json!({ "text": text, "list_id": list_id, "utc_offset_minutes": -300 }),
+        ).await;
+        assert_eq!(status, StatusCode::CREATED, "{}", text);
+        assert_eq!(body["task"]["title"], title);
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,258 @@
+/// Тесты повторяющихся задач: разбор RRULE и создание следующего повторения.
+mod common;
+
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+
+use todo_api::models::recurrence::RecurrenceRule;
+
+fn at(value: &str) -> DateTime<Utc> {
+    value.parse().unwrap()
+}
+
+// ==================== RecurrenceRule ====================
+
+#[test]
+fn weekly_rule_with_byday_picks_next_matching_day() {
+    let rule = RecurrenceRule::parse("RRULE:FREQ=WEEKLY;BYDAY=MO,TH").unwrap();
+    // 2026-03-02 — понедельник.
+    let next = rule.next_after(at("2026-03-02T09:00:00Z")).unwrap();
+    assert_eq!(next, at("2026-03-05T09:00:00Z"));
+    let next = rule.next_after(next).unwrap();
+    assert_eq!(next, at("2026-03-09T09:00:00Z"));
+}
+
+#[test]
+fn biweekly_rule_skips_inactive_week() {
+    let rule = RecurrenceRule::parse("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO").unwrap();
+    let next = rule.next_after(at("2026-03-02T09:00:00Z")).unwrap();
+    assert_eq!(next, at("2026-03-16T09:00:00Z"));
+}
+
+#[test]
+fn monthly_rule_skips_missing_dates() {
+    let rule = RecurrenceRule::parse("FREQ=MONTHLY").unwrap();
+    // 31 февраля и 31 апреля не существуют → пропускаются.
+    let next = rule.next_after(at("2026-01-31T10:00:00Z")).unwrap();
+    assert_eq!(next, at("2026-03-31T10:00:00Z"));
+}
+
+#[test]
+fn rule_stops_after_until() {
+    let rule = RecurrenceRule::parse("FREQ=DAILY;UNTIL=20260303").unwrap();
+    assert!(rule.next_after(at("2026-03-03T09:00:00Z")).is_none());
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    assert!(RecurrenceRule::parse("INTERVAL=2").is_err());
+    assert!(RecurrenceRule::parse("FREQ=HOURLY").is_err());
+    assert!(RecurrenceRule::parse("FREQ=DAILY;BYDAY=MO").is_err());
+    assert!(RecurrenceRule::parse("FREQ=DAILY;COUNT=2;UNTIL=20260101").is_err());
+    assert!(RecurrenceRule::parse("FREQ=DAILY;INTERVAL=4000000000").is_err());
+    assert!(RecurrenceRule::parse("FREQ=DAILY;INTERVAL=1001").is_err());
+}
+
+#[test]
+fn rule_ends_instead_of_overflowing_dates() {
+    let last = DateTime::<Utc>::MAX_UTC - chrono::Duration::hours(1);
+    for rrule in ["FREQ=DAILY", "FREQ=WEEKLY", "FREQ=WEEKLY;BYDAY=MO", "FREQ=MONTHLY", "FREQ=YEARLY;INTERVAL=1000"] {
+        let rule = RecurrenceRule::parse(rrule).unwrap();
+        assert!(rule.next_after(last).is_none(), "{rrule}");
+    }
+}
+
+
+// ==================== /lists/:list_id/tasks/:task_id/recurrence ====================
+
+#[tokio::test]
+async fn completing_recurring_task_spawns_next_occurrence() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_schedule@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, task) = common::send(
+        &state, &token, "POST", format!("/lists/{}/tasks", list_id),
+        Some(serde_json::json!({ "title": "Take out trash", "due_at": "2026-03-02T09:00:00Z" })),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let task_id = task["id"].as_str().unwrap().to_string();
+
+    let (status, task) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "rrule": "FREQ=WEEKLY" })),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["recurrence"]["mode"], "schedule");
+    assert_eq!(task["recurrence"]["series_id"], task_id.as_str());
+
+    let (status, _) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}", list_id, task_id),
+        Some(serde_json::json!({ "title": "Take out trash", "status": "done" })),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), None).await;
+    let tasks = tasks.as_array().unwrap();
+    assert_eq!(tasks.len(), 2);
+    let next = tasks.iter().find(|t| t["id"] != task_id.as_str()).unwrap();
+    assert_eq!(next["status"], "todo");
+    assert_eq!(next["due_at"], "2026-03-09T09:00:00Z");
+    assert_eq!(next["recurrence"]["series_id"], task_id.as_str());
+
+    let (status, history) = common::send(
+        &state, &token, "GET",
+        format!("/lists/{}/tasks/{}/recurrence/history", list_id, next["id"].as_str().unwrap()),
+        None,
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    let history = history.as_array().unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0]["task_id"], task_id.as_str());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn recurring_series_ends_after_count() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_count@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Only once").await;
+
+    common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "rrule": "FREQ=DAILY;COUNT=1" })),
+    ).await;
+    common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}", list_id, task_id),
+        Some(serde_json::json!({ "title": "Only once", "status": "done" })),
+    ).await;
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), None).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 1);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn after_completion_mode_uses_interval_days() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_after@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Water plants").await;
+
+    let (status, _) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "mode": "after_completion", "interval_days": 3 })),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let before = Utc::now();
+    common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}", list_id, task_id),
+        Some(serde_json::json!({ "title": "Water plants", "status": "done" })),
+    ).await;
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), None).await;
+    let next = tasks.as_array().unwrap().iter().find(|t| t["id"] != task_id.as_str()).unwrap().clone();
+    let due: DateTime<Utc> = next["due_at"].as_str().unwrap().parse().unwrap();
+    assert!(due >= before + chrono::Duration::days(3));
+    assert!(due <= Utc::now() + chrono::Duration::days(3));
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn concurrent_completions_spawn_one_occurrence() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_concurrent@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Feed the cat").await;
+
+    common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "rrule": "FREQ=DAILY" })),
+    ).await;
+
+    let completions = (0..5).map(|_| {
+        common::send(
+            &state, &token, "PUT", format!("/lists/{}/tasks/{}/status", list_id, task_id),
+            Some(serde_json::json!({ "status": "done" })),
+        )
+    });
+    for (status, _) in futures_util::future::join_all(completions).await {
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), None).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 2);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn reopened_and_completed_again_task_does_not_spawn_twice() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_reopen@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Water plants").await;
+
+    common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "rrule": "FREQ=DAILY" })),
+    ).await;
+    let complete = || common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/status", list_id, task_id),
+        Some(serde_json::json!({ "status": "done" })),
+    );
+    assert_eq!(complete().await.0, StatusCode::OK);
+    let (status, _) = common::send(
+        &state, &token, "POST", format!("/lists/{}/tasks/{}/reopen", list_id, task_id), None,
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(complete().await.0, StatusCode::OK);
+
+    let (_, tasks) = common::send(&state, &token, "GET", format!("/lists/{}/tasks", list_id), None).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 2);
+    let (_, history) = common::send(
+        &state, &token, "GET", format!("/lists/{}/tasks/{}/recurrence/history", list_id, task_id), None,
+    ).await;
+    assert_eq!(history.as_array().unwrap().len(), 1);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn invalid_rrule_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "recurrence_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Broken").await;
+
+    let (status, _) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "rrule": "FREQ=SOMETIMES" })),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = common::send(
+        &state, &token, "PUT", format!("/lists/{}/tasks/{}/recurrence", list_id, task_id),
+        Some(serde_json::json!({ "mode": "after_completion", "interval_days": 100000000 })),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
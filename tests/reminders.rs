@@ -0,0 +1,271 @@
+/// Интеграционные тесты для напоминаний и фонового воркера.
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use axum::http::StatusCode;
+
+use todo_api::jobs;
+use todo_api::notifications::{Dispatcher, Notification, Notifier};
+use todo_api::state::AppState;
+
+/// Канал-заглушка: запоминает отправленные уведомления вместо доставки.
+#[derive(Default)]
+struct RecordingNotifier {
+    sent: Mutex<Vec<(String, Notification)>>,
+}
+
+impl RecordingNotifier {
+    /// Сколько раз было отправлено напоминание `reminder_id`.
+    fn count_for(&self, reminder_id: &str) -> usize {
+        self.sent
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, notification)| notification.reminder_id.to_string() == reminder_id)
+            .count()
+    }
+}
+
+#[async_trait::async_trait]
+impl Notifier for RecordingNotifier {
+    async fn send(&self, target: &str, notification: &Notification) -> Result<(), String> {
+        self.sent.lock().unwrap().push((target.to_string(), notification.clone()));
+        Ok(())
+    }
+}
+
+/// Создаёт напоминание, которое уже должно сработать, возвращает его id.
+async fn create_due_reminder(state: &AppState, token: &str, list_id: &str, task_id: &str) -> String {
+    let remind_at = chrono::Utc::now() - chrono::Duration::minutes(1);
+    let (status, body) = common::send(
+        state, token, "POST",
+        format!("/lists/{}/tasks/{}/reminders", list_id, task_id),
+        serde_json::json!({
+            "remind_at": remind_at,
+            "channel": "webhook",
+            "target": "https://93.184.215.14/reminders",
+        }),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["status"], "pending");
+    body["id"].as_str().unwrap().to_string()
+}
+
+
+// ==================== POST /lists/:list_id/tasks/:task_id/reminders ====================
+
+#[tokio::test]
+async fn create_reminder_invalid_channel_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "reminders_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Call mom").await;
+    let uri = format!("/lists/{}/tasks/{}/reminders", list_id, task_id);
+
+    let (status, _) = common::send(
+        &state, &token, "POST", uri.clone(),
+        serde_json::json!({ "remind_at": chrono::Utc::now(), "channel": "sms", "target": "+100" }),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = common::send(
+        &state, &token, "POST", uri,
+        serde_json::json!({ "remind_at": chrono::Utc::now(), "channel": "email", "target": "nobody" }),
+    ).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn create_webhook_reminder_to_internal_address_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "reminders_ssrf@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Check backups").await;
+    let uri = format!("/lists/{}/tasks/{}/reminders", list_id, task_id);
+
+    for target in [
+        "http://127.0.0.1:8080/hook",
+        "http://169.254.169.254/latest/meta-data/",
+        "https://10.0.0.1/hook",
+        "http://[::1]/hook",
+        "http://[::ffff:192.168.0.1]/hook",
+        "http://localhost/hook",
+        "ftp://93.184.215.14/hook",
+    ] {
+        let (status, _) = common::send(
+            &state, &token, "POST", uri.clone(),
+            serde_json::json!({ "remind_at": chrono::Utc::now(), "channel": "webhook", "target": target }),
+        ).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{target}");
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Воркер напоминаний ====================
+
+#[tokio::test]
+async fn worker_sends_reminder_exactly_once() {
+    let state = common::test_app_state().await;
+    let email = "reminders_once@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Pay rent").await;
+    let reminder_id = create_due_reminder(&state, &token, &list_id, &task_id).await;
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let dispatcher = Dispatcher::new().with_channel("webhook", notifier.clone());
+
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    assert_eq!(notifier.count_for(&reminder_id), 1);
+
+    let (status, body) = common::send(
+        &state, &token, "GET",
+        format!("/lists/{}/tasks/{}/reminders", list_id, task_id),
+        serde_json::Value::Null,
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body[0]["status"], "sent");
+    assert!(body[0]["sent_at"].is_string());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn unconfigured_channel_marks_reminder_for_retry() {
+    let state = common::test_app_state().await;
+    let email = "reminders_retry@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Renew passport").await;
+    create_due_reminder(&state, &token, &list_id, &task_id).await;
+
+    // Канал webhook не зарегистрирован — доставка падает.
+    jobs::reminders::run_once(&state.db, &Dispatcher::new()).await.unwrap();
+
+    let (_, body) = common::send(
+        &state, &token, "GET",
+        format!("/lists/{}/tasks/{}/reminders", list_id, task_id),
+        serde_json::Value::Null,
+    ).await;
+    assert_eq!(body[0]["status"], "pending");
+    assert_eq!(body[0]["attempts"], 1);
+    assert!(body[0]["last_error"].is_string());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn worker_skips_leased_reminder_until_lease_expires() {
+    let state = common::test_app_state().await;
+    let email = "reminders_lease@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Call the bank").await;
+    let reminder_id = create_due_reminder(&state, &token, &list_id, &task_id).await;
+    let id: uuid::Uuid = reminder_id.parse().unwrap();
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let dispatcher = Dispatcher::new().with_channel("webhook", notifier.clone());
+
+    // Напоминание доставляет другой воркер — аренда ещё действует.
+    sqlx::query("UPDATE reminders SET status = 'sending', lease_until = now() + interval '5 minutes' WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    assert_eq!(notifier.count_for(&reminder_id), 0);
+
+    // Воркер упал, не отметив результат: после истечения аренды доставляем снова.
+    sqlx::query("UPDATE reminders SET lease_until = now() - interval '1 second' WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .unwrap();
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    assert_eq!(notifier.count_for(&reminder_id), 1);
+
+    let (_, body) = common::send(
+        &state, &token, "GET",
+        format!("/lists/{}/tasks/{}/reminders", list_id, task_id),
+        serde_json::Value::Null,
+    ).await;
+    assert_eq!(body[0]["status"], "sent");
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== POST .../reminders/:reminder_id/snooze ====================
+
+#[tokio::test]
+async fn snooze_rearms_sent_reminder() {
+    let state = common::test_app_state().await;
+    let email = "reminders_snooze@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Water plants").await;
+    let reminder_id = create_due_reminder(&state, &token, &list_id, &task_id).await;
+
+    let notifier = Arc::new(RecordingNotifier::default());
+    let dispatcher = Dispatcher::new().with_channel("webhook", notifier.clone());
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    assert_eq!(notifier.count_for(&reminder_id), 1);
+
+    let uri = format!("/lists/{}/tasks/{}/reminders/{}/snooze", list_id, task_id, reminder_id);
+    let (status, _) = common::send(&state, &token, "POST", uri.clone(), serde_json::json!({ "minutes": 0 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    // Больше года — 422, а не паника на переполнении даты.
+    for minutes in [365 * 24 * 60 + 1, i64::MAX] {
+        let (status, _) =
+            common::send(&state, &token, "POST", uri.clone(), serde_json::json!({ "minutes": minutes })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (status, body) = common::send(&state, &token, "POST", uri, serde_json::json!({ "minutes": 10 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "pending");
+
+    // Отложенное напоминание ещё не наступило.
+    jobs::reminders::run_once(&state.db, &dispatcher).await.unwrap();
+    assert_eq!(notifier.count_for(&reminder_id), 1);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== DELETE .../reminders/:reminder_id ====================
+
+#[tokio::test]
+async fn delete_reminder() {
+    let state = common::test_app_state().await;
+    let email = "reminders_delete@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Book tickets").await;
+    let reminder_id = create_due_reminder(&state, &token, &list_id, &task_id).await;
+    let uri = format!("/lists/{}/tasks/{}/reminders/{}", list_id, task_id, reminder_id);
+
+    let (status, _) = common::send(&state, &token, "DELETE", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = common::send(&state, &token, "DELETE", uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,186 @@
+/// Тесты полнотекстового поиска (GET /search).
+mod common;
+
+use axum::http::StatusCode;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use todo_api::state::AppState;
+
+/// Ищет `q`, возвращает статус и результаты первой страницы.
+async fn search(state: &AppState, token: &str, q: &str) -> (StatusCode, Vec<serde_json::Value>) {
+    let uri = format!("/search?q={}", utf8_percent_encode(q, NON_ALPHANUMERIC));
+    let (status, _, body) = common::send_paged(state, token, "GET", uri, serde_json::Value::Null).await;
+    let results = body.as_array().cloned().unwrap_or_default();
+    (status, results)
+}
+
+/// Названия найденного.
+fn titles(results: &[serde_json::Value]) -> Vec<&str> {
+    results.iter().map(|hit| hit["title"].as_str().unwrap()).collect()
+}
+
+
+// ==================== Совпадения ====================
+
+#[tokio::test]
+async fn finds_words_by_stem_and_prefix_in_both_languages() {
+    let state = common::test_app_state().await;
+    let email = "search_words@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &list_id, "Записаться к стоматологу").await;
+    common::create_task(&state, &token, &list_id, "Book dentist appointments").await;
+    common::create_task(&state, &token, &list_id, "Купить молоко").await;
+
+    let (status, results) = search(&state, &token, "стоматолог").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&results), vec!["Записаться к стоматологу"]);
+    let hit = &results[0];
+    assert_eq!(hit["kind"], "task");
+    assert_eq!(hit["list_id"], list_id.as_str());
+    assert_eq!(hit["highlight"], "Записаться к <mark>стоматологу</mark>");
+
+    // Начало слова.
+    let (_, results) = search(&state, &token, "стомат").await;
+    assert_eq!(titles(&results), vec!["Записаться к стоматологу"]);
+
+    // Английские слова приводятся к основе.
+    let (_, results) = search(&state, &token, "appointment dentists").await;
+    assert_eq!(titles(&results), vec!["Book dentist appointments"]);
+
+    // Название списка тоже ищется.
+    let (_, results) = search(&state, &token, "test list").await;
+    assert_eq!(results[0]["kind"], "list");
+    assert_eq!(results[0]["id"], list_id.as_str());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn tolerates_typos_and_ranks_exact_matches_higher() {
+    let state = common::test_app_state().await;
+    let email = "search_typos@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &list_id, "Записаться к стоматологу").await;
+    common::create_task(&state, &token, &list_id, "Call the dentist").await;
+
+    let (_, results) = search(&state, &token, "стамотолог").await;
+    assert_eq!(titles(&results), vec!["Записаться к стоматологу"]);
+
+    let (_, results) = search(&state, &token, "dentst").await;
+    assert_eq!(titles(&results), vec!["Call the dentist"]);
+
+    common::create_task(&state, &token, &list_id, "Dentst").await;
+    let (_, results) = search(&state, &token, "dentist").await;
+    assert_eq!(titles(&results), vec!["Call the dentist", "Dentst"]);
+    assert!(results[0]["rank"].as_f64().unwrap() > results[1]["rank"].as_f64().unwrap());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Доступ ====================
+
+#[tokio::test]
+async fn results_are_scoped_to_accessible_tasks() {
+    let state = common::test_app_state().await;
+    let email = "search_owner@example.com";
+    let colleague_email = "search_colleague@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, colleague_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let colleague = common::get_auth_token(&state, colleague_email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let assigned = common::create_task(&state, &token, &list_id, "Dentist for colleague").await;
+    common::create_task(&state, &token, &list_id, "Dentist for owner").await;
+    let deleted = common::create_task(&state, &token, &list_id, "Dentist, deleted").await;
+
+    common::send_paged(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/assignee", list_id, assigned),
+        serde_json::json!({ "email": colleague_email }),
+    )
+    .await;
+    common::send_paged(&state, &token, "DELETE", format!("/lists/{}/tasks/{}", list_id, deleted), serde_json::Value::Null).await;
+
+    let (_, results) = search(&state, &token, "dentist").await;
+    let mut found = titles(&results);
+    found.sort();
+    assert_eq!(found, vec!["Dentist for colleague", "Dentist for owner"]);
+
+    // Исполнитель видит только назначенную ему задачу, но не чужой список.
+    let (_, results) = search(&state, &colleague, "dentist").await;
+    assert_eq!(titles(&results), vec!["Dentist for colleague"]);
+    let (_, results) = search(&state, &colleague, "test list").await;
+    assert!(results.iter().all(|hit| hit["kind"] == "task"));
+
+    common::cleanup_user(&state.db, colleague_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Пагинация ====================
+
+#[tokio::test]
+async fn results_are_paginated_by_cursor() {
+    let state = common::test_app_state().await;
+    let email = "search_pages@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    for title in ["Dentist", "Call the dentist", "Dentst", "Dentist appointment", "Pay the dentist"] {
+        common::create_task(&state, &token, &list_id, title).await;
+    }
+    let (_, everything) = search(&state, &token, "dentist").await;
+    assert_eq!(everything.len(), 5);
+
+    // Страницы по два результата в сумме дают ту же выдачу в том же порядке.
+    let mut uri = Some("/search?q=dentist&limit=2".to_string());
+    let mut paged = Vec::new();
+    while let Some(next) = uri {
+        let (status, link, body) = common::send_paged(&state, &token, "GET", next, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        let page = body.as_array().unwrap();
+        assert!(page.len() <= 2);
+        paged.extend(page.iter().cloned());
+        uri = link;
+    }
+    assert_eq!(titles(&paged), titles(&everything));
+
+    let (status, _, _) = common::send_paged(&state, &token, "GET", "/search?q=dentist&limit=501".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _, _) = common::send_paged(&state, &token, "GET", "/search?q=dentist&cursor=oops".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация ====================
+
+#[tokio::test]
+async fn highlight_is_escaped_and_empty_query_rejected() {
+    let state = common::test_app_state().await;
+    let email = "search_escape@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &list_id, "<b>Dentist</b> & co").await;
+
+    let (_, results) = search(&state, &token, "dentist").await;
+    assert_eq!(results[0]["highlight"], "&lt;b&gt;<mark>Dentist</mark>&lt;/b&gt; &amp; co");
+
+    // Операторы tsquery в запросе — просто пунктуация.
+    let (status, _) = search(&state, &token, "dentist & !co:*").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = search(&state, &token, " ,!? ").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
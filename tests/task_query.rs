@@ -0,0 +1,196 @@
+/// Тесты фильтрации и сортировки задач (`filter` и `sort` в GET /lists/{id}/tasks).
+mod common;
+
+use axum::http::StatusCode;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+use todo_api::state::AppState;
+
+/// Создаёт задачу с полями `body`, возвращает её ID.
+async fn create_task(state: &AppState, token: &str, list_id: &str, body: serde_json::Value) -> String {
+    let (status, _, task) = common::send_paged(state, token, "POST", format!("/lists/{}/tasks", list_id), body).await;
+    assert_eq!(status, StatusCode::CREATED);
+    task["id"].as_str().unwrap().to_string()
+}
+
+/// GET задач списка с query-параметрами `params` (значения кодируются).
+async fn query_tasks(
+    state: &AppState,
+    token: &str,
+    list_id: &str,
+    params: &[(&str, &str)],
+) -> (StatusCode, Option<String>, serde_json::Value) {
+    let query: Vec<String> = params
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, utf8_percent_encode(value, NON_ALPHANUMERIC)))
+        .collect();
+    let uri = format!("/lists/{}/tasks?{}", list_id, query.join("&"));
+    common::send_paged(state, token, "GET", uri, serde_json::Value::Null).await
+}
+
+/// Названия задач из ответа.
+fn titles(tasks: &serde_json::Value) -> Vec<String> {
+    tasks
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Список с четырьмя задачами разных статусов и сроков.
+async fn seed(state: &AppState, token: &str) -> String {
+    let list_id = common::create_list(state, token).await;
+    create_task(state, token, &list_id, serde_json::json!({ "title": "Отчёт Q1, черновик", "due_at": "2026-03-10T09:00:00Z" })).await;
+    let review = create_task(state, token, &list_id, serde_json::json!({ "title": "Ревью", "due_at": "2026-03-20T09:00:00Z" })).await;
+    let report = create_task(state, token, &list_id, serde_json::json!({ "title": "Финальный отчёт Q1", "due_at": "2026-04-15T09:00:00Z" })).await;
+    create_task(state, token, &list_id, serde_json::json!({ "title": "Без срока" })).await;
+
+    common::send_paged(state, token, "PUT", format!("/lists/{}/tasks/{}/status", list_id, review), serde_json::json!({ "status": "in_progress" })).await;
+    common::send_paged(state, token, "PUT", format!("/lists/{}/tasks/{}/status", list_id, report), serde_json::json!({ "status": "done" })).await;
+    list_id
+}
+
+
+// ==================== Фильтры ====================
+
+#[tokio::test]
+async fn filters_by_status_text_and_dates() {
+    let state = common::test_app_state().await;
+    let email = "query_filters@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = seed(&state, &token).await;
+
+    let (status, _, tasks) = query_tasks(&state, &token, &list_id, &[("filter", "status:in:todo|in_progress")]).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&tasks), vec!["Отчёт Q1, черновик", "Ревью", "Без срока"]);
+
+    // Подстрока без учёта регистра; запятая в значении экранируется.
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("filter", "title:contains:q1")]).await;
+    assert_eq!(titles(&tasks), vec!["Отчёт Q1, черновик", "Финальный отчёт Q1"]);
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("filter", r"title:contains:q1\, чер")]).await;
+    assert_eq!(titles(&tasks), vec!["Отчёт Q1, черновик"]);
+
+    let (_, _, tasks) = query_tasks(
+        &state,
+        &token,
+        &list_id,
+        &[("filter", "due_at:gte:2026-03-15,due_at:lt:2026-05-01T00:00:00Z,status:ne:done")],
+    )
+    .await;
+    assert_eq!(titles(&tasks), vec!["Ревью"]);
+
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("filter", "due_at:null:true")]).await;
+    assert_eq!(titles(&tasks), vec!["Без срока"]);
+
+    // Спецсимволы LIKE ищутся буквально.
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("filter", "title:contains:%")]).await;
+    assert!(titles(&tasks).is_empty());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Сортировка ====================
+
+#[tokio::test]
+async fn sorts_by_several_keys() {
+    let state = common::test_app_state().await;
+    let email = "query_sort@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = seed(&state, &token).await;
+
+    // По убыванию пустой срок идёт первым.
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("sort", "-due_at")]).await;
+    assert_eq!(titles(&tasks), vec!["Без срока", "Финальный отчёт Q1", "Ревью", "Отчёт Q1, черновик"]);
+
+    // Статусы — по ходу работы, внутри статуса — по названию.
+    let (_, _, tasks) = query_tasks(&state, &token, &list_id, &[("sort", "status,title")]).await;
+    assert_eq!(titles(&tasks), vec!["Без срока", "Отчёт Q1, черновик", "Ревью", "Финальный отчёт Q1"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn custom_sort_is_paginated() {
+    let state = common::test_app_state().await;
+    let email = "query_pages@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = seed(&state, &token).await;
+    create_task(&state, &token, &list_id, serde_json::json!({ "title": "Тоже без срока" })).await;
+
+    let mut collected = Vec::new();
+    let (status, mut next, tasks) = query_tasks(&state, &token, &list_id, &[("sort", "due_at,title"), ("limit", "2")]).await;
+    assert_eq!(status, StatusCode::OK);
+    collected.extend(titles(&tasks));
+    while let Some(uri) = next {
+        let (status, link, tasks) = common::send_paged(&state, &token, "GET", uri, serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        collected.extend(titles(&tasks));
+        next = link;
+    }
+    assert_eq!(
+        collected,
+        vec!["Отчёт Q1, черновик", "Ревью", "Финальный отчёт Q1", "Без срока", "Тоже без срока"]
+    );
+
+    // Курсор другой сортировки не подходит.
+    let (_, next, _) = query_tasks(&state, &token, &list_id, &[("sort", "due_at"), ("limit", "1")]).await;
+    let cursor = next.unwrap().split("cursor=").nth(1).unwrap().to_string();
+    let (status, _, _) = query_tasks(&state, &token, &list_id, &[("sort", "title"), ("cursor", &cursor)]).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация ====================
+
+#[tokio::test]
+async fn unknown_fields_and_operators_are_rejected() {
+    let state = common::test_app_state().await;
+    let email = "query_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    for params in [
+        [("filter", "owner:eq:me")],
+        [("filter", "title:lt:b")],
+        [("filter", "status:eq:blocked")],
+        [("filter", "due_at:gt:завтра")],
+        [("filter", "estimate_minutes")],
+        [("sort", "-assignee_id")],
+        [("sort", "title,title")],
+        [("sort", "priority")],
+    ] {
+        let (status, _, body) = query_tasks(&state, &token, &list_id, &params).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{:?}", params);
+        assert!(body["error"].is_string());
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn relative_date_with_non_ascii_suffix_is_rejected() {
+    let state = common::test_app_state().await;
+    let email = "query_non_ascii@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, _, _) = query_tasks(&state, &token, &list_id, &[("filter", "due_at:lt:nowж")]).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // То же значение может прийти и в курсоре.
+    let cursor = serde_json::json!({ "sort": "due_at", "values": ["nowж"], "id": uuid::Uuid::new_v4() });
+    let cursor = base64::Engine::encode(&base64::engine::general_purpose::URL_SAFE_NO_PAD, cursor.to_string());
+    let (status, _, _) = query_tasks(&state, &token, &list_id, &[("sort", "due_at"), ("cursor", &cursor)]).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,89 @@
+/// Тесты жизненного цикла статуса задачи: переходы, reopen и отметки времени.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::models::task_status::TaskStatus;
+
+// ==================== TaskStatus ====================
+
+#[test]
+fn done_is_left_only_by_reopen() {
+    use TaskStatus::*;
+
+    assert!(Todo.can_transition_to(InProgress));
+    assert!(Todo.can_transition_to(Done));
+    assert!(InProgress.can_transition_to(Todo));
+    assert!(InProgress.can_transition_to(Done));
+    assert!(Done.can_transition_to(Done));
+    assert!(!Done.can_transition_to(Todo));
+    assert!(!Done.can_transition_to(InProgress));
+
+    assert_eq!("in_progress".parse::<TaskStatus>().unwrap(), InProgress);
+    assert!("archived".parse::<TaskStatus>().is_err());
+}
+
+
+// ==================== Переходы через API ====================
+
+#[tokio::test]
+async fn unknown_status_returns_422() {
+    let state = common::test_app_state().await;
+    let email = "task_status_unknown@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+
+    let (status, body) = common::send(&state, &token, "PUT", format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::json!({ "title": "Задача", "status": "archived" })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(body["error"].as_str().unwrap().contains("archived"));
+
+    let (status, _) = common::send(&state, &token, "PUT", format!("/lists/{}/tasks/{}/status", list_id, task_id),
+        serde_json::json!({ "status": "DONE" })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn transitions_record_timestamps_and_require_reopen() {
+    let state = common::test_app_state().await;
+    let email = "task_status_flow@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Задача").await;
+    let status_uri = format!("/lists/{}/tasks/{}/status", list_id, task_id);
+    let reopen_uri = format!("/lists/{}/tasks/{}/reopen", list_id, task_id);
+
+    let (status, _) = common::send(&state, &token, "POST", reopen_uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, body) = common::send(&state, &token, "PUT", status_uri.clone(), serde_json::json!({ "status": "in_progress" })).await;
+    let started_at = body["started_at"].clone();
+    assert!(started_at.is_string());
+    assert!(body["completed_at"].is_null());
+
+    let (_, body) = common::send(&state, &token, "PUT", status_uri.clone(), serde_json::json!({ "status": "done" })).await;
+    assert_eq!(body["status"], "done");
+    assert!(body["completed_at"].is_string());
+    assert_eq!(body["started_at"], started_at);
+
+    // Из done обычной сменой статуса не выйти.
+    let (status, body) = common::send(&state, &token, "PUT", status_uri.clone(), serde_json::json!({ "status": "todo" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].as_str().unwrap().contains("reopen"));
+    let (status, _) = common::send(&state, &token, "PATCH", format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::json!({ "status": "in_progress" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = common::send(&state, &token, "POST", reopen_uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "todo");
+    assert!(body["completed_at"].is_null());
+    assert_eq!(body["started_at"], started_at);
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -329,3 +329,121 @@ async fn delete_task_returns_204() {
 
     common::cleanup_user(&state.db, email).await;
 }
+
+
+// ==================== POST /lists/:list_id/tasks/:task_id/move | copy ====================
+
+/// POST с JSON-телом, возвращает статус и тело ответа.
+async fn post_json(
+    state: &todo_api::state::AppState,
+    token: &str,
+    uri: String,
+    body: serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("POST")
+        .uri(uri)
+        .header("Content-Type", "application/json")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::from(body.to_string()))
+        .unwrap();
+    let resp = app.oneshot(req).await.unwrap();
+    let status = resp.status();
+    let bytes = resp.into_body().collect().await.unwrap().to_bytes();
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn move_task_keeps_id_and_relations() {
+    let state = common::test_app_state().await;
+    let email = "tasks_move@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let from = common::create_list(&state, &token).await;
+    let to = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &from, "Movable").await;
+    post_json(
+        &state, &token, format!("/lists/{}/tasks/{}/items", from, task_id),
+        serde_json::json!({ "title": "Step" }),
+    ).await;
+
+    let (status, body) = post_json(
+        &state, &token, format!("/lists/{}/tasks/{}/move", from, task_id),
+        serde_json::json!({ "target_list_id": to }),
+    ).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], task_id.as_str());
+    assert_eq!(body["list_id"], to.as_str());
+    assert_eq!(body["checklist"]["total"], 1);
+
+    // В исходном списке задачи больше нет.
+    let app = create_router().with_state(state.clone());
+    let req = Request::builder()
+        .method("GET")
+        .uri(format!("/lists/{}/tasks/{}", from, task_id))
+        .header("Authorization", format!("Bearer {}", token))
+        .body(axum::body::Body::empty())
+        .unwrap();
+    assert_eq!(app.oneshot(req).await.unwrap().status(), StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn copy_task_duplicates_attributes() {
+    let state = common::test_app_state().await;
+    let email = "tasks_copy@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let from = common::create_list(&state, &token).await;
+    let to = common::create_list(&state, &token).await;
+    let (_, created) = post_json(
+        &state, &token, format!("/lists/{}/tasks", from),
+        serde_json::json!({ "title": "Template", "due_at": "2026-05-01T09:00:00Z", "auto_complete": true }),
+    ).await;
+    let task_id = created["id"].as_str().unwrap();
+    post_json(
+        &state, &token, format!("/lists/{}/tasks/{}/items", from, task_id),
+        serde_json::json!({ "title": "Step" }),
+    ).await;
+
+    let (status, body) = post_json(
+        &state, &token, format!("/lists/{}/tasks/{}/copy", from, task_id),
+        serde_json::json!({ "target_list_id": to }),
+    ).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_ne!(body["id"], task_id);
+    assert_eq!(body["list_id"], to.as_str());
+    assert_eq!(body["title"], "Template");
+    assert_eq!(body["auto_complete"], true);
+    assert_eq!(body["due_at"], created["due_at"]);
+    assert_eq!(body["checklist"]["total"], 1);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn move_task_to_foreign_list_returns_404() {
+    let state = common::test_app_state().await;
+    let email = "tasks_move_foreign@example.com";
+    let other_email = "tasks_move_foreign_other@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+    let from = common::create_list(&state, &token).await;
+    let foreign = common::create_list(&state, &other).await;
+    let task_id = common::create_task(&state, &token, &from, "Mine").await;
+
+    for action in ["move", "copy"] {
+        let (status, _) = post_json(
+            &state, &token, format!("/lists/{}/tasks/{}/{}", from, task_id, action),
+            serde_json::json!({ "target_list_id": foreign }),
+        ).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+}
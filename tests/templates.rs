@@ -0,0 +1,305 @@
+/// Тесты шаблонов списков (POST /lists/{id}/template, /templates).
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::state::AppState;
+
+/// Создаёт задачу со сроком `due_at`, возвращает её ID.
+async fn create_task(state: &AppState, token: &str, list_id: &str, title: &str, due_at: serde_json::Value) -> String {
+    let (status, task) = common::send(
+        state,
+        token,
+        "POST",
+        format!("/lists/{}/tasks", list_id),
+        serde_json::json!({ "title": title, "due_at": due_at }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    task["id"].as_str().unwrap().to_string()
+}
+
+/// Задачи списка: название, статус и срок.
+async fn tasks_of(state: &AppState, token: &str, list_id: &str) -> Vec<(String, String, serde_json::Value)> {
+    let (_, tasks) = common::send(state, token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    tasks
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| {
+            (
+                task["title"].as_str().unwrap().to_string(),
+                task["status"].as_str().unwrap().to_string(),
+                task["due_at"].clone(),
+            )
+        })
+        .collect()
+}
+
+
+// ==================== Сохранение и создание списка ====================
+
+#[tokio::test]
+async fn list_saved_as_template_is_recreated_with_shifted_due_dates() {
+    let state = common::test_app_state().await;
+    let email = "templates_roundtrip@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    // Смещения считаются от самого раннего срока, а не от создания списка.
+    sqlx::query("UPDATE todo_lists SET created_at = '2026-02-20T12:00:00Z' WHERE id = $1")
+        .bind(list_id.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+    let freeze = create_task(&state, &token, &list_id, "Заморозить ветку", serde_json::json!("2026-03-02T09:00:00Z")).await;
+    let deploy = create_task(&state, &token, &list_id, "Выкатить", serde_json::json!("2026-03-04T15:30:00Z")).await;
+    create_task(&state, &token, &list_id, "Написать заметки", serde_json::Value::Null).await;
+    let dropped = create_task(&state, &token, &list_id, "Отменённая", serde_json::Value::Null).await;
+
+    // «Выкатить» — в конец; выполненная задача в шаблоне снова открыта; удалённой нет.
+    common::send(
+        &state,
+        &token,
+        "PATCH",
+        format!("/lists/{}/tasks/{}/position", list_id, deploy),
+        serde_json::json!({ "after_id": dropped }),
+    )
+    .await;
+    common::send(
+        &state,
+        &token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/status", list_id, freeze),
+        serde_json::json!({ "status": "done" }),
+    )
+    .await;
+    common::send(&state, &token, "DELETE", format!("/lists/{}/tasks/{}", list_id, dropped), serde_json::Value::Null).await;
+
+    let (status, template) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/template", list_id),
+        serde_json::json!({ "title": "Релиз" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(template["title"], "Релиз");
+    assert_eq!(template["builtin"], false);
+    assert_eq!(
+        template["tasks"],
+        serde_json::json!([
+            { "title": "Заморозить ветку", "due_offset_minutes": 0 },
+            { "title": "Написать заметки", "due_offset_minutes": null },
+            { "title": "Выкатить", "due_offset_minutes": 2 * 24 * 60 + 6 * 60 + 30 },
+        ])
+    );
+
+    let (status, list) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/templates/{}/instantiate", template["id"].as_str().unwrap()),
+        serde_json::json!({ "title": "Релиз 2.4", "starts_at": "2026-04-06T08:00:00Z" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(list["title"], "Релиз 2.4");
+    let new_list_id = list["id"].as_str().unwrap();
+    assert_ne!(new_list_id, list_id);
+
+    assert_eq!(
+        tasks_of(&state, &token, new_list_id).await,
+        vec![
+            ("Заморозить ветку".to_string(), "todo".to_string(), serde_json::json!("2026-04-06T08:00:00Z")),
+            ("Написать заметки".to_string(), "todo".to_string(), serde_json::Value::Null),
+            ("Выкатить".to_string(), "todo".to_string(), serde_json::json!("2026-04-08T14:30:00Z")),
+        ]
+    );
+
+    // Новый список — первым в списках пользователя.
+    let (_, lists) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert_eq!(lists[0]["id"], new_list_id);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn builtin_template_is_instantiated_with_defaults() {
+    let state = common::test_app_state().await;
+    let email = "templates_builtin@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    let (status, template) = common::send(&state, &token, "GET", "/templates/release_checklist".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(template["builtin"], true);
+    let template_tasks = template["tasks"].as_array().unwrap().clone();
+
+    let before = chrono::Utc::now();
+    let (status, list) = common::send(
+        &state,
+        &token,
+        "POST",
+        "/templates/release_checklist/instantiate".to_string(),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(list["title"], template["title"]);
+
+    let tasks = tasks_of(&state, &token, list["id"].as_str().unwrap()).await;
+    assert_eq!(tasks.len(), template_tasks.len());
+    for ((title, _, due_at), expected) in tasks.iter().zip(&template_tasks) {
+        assert_eq!(title, expected["title"].as_str().unwrap());
+        let due_at: chrono::DateTime<chrono::Utc> = serde_json::from_value(due_at.clone()).unwrap();
+        let offset = chrono::Duration::minutes(expected["due_offset_minutes"].as_i64().unwrap());
+        assert!(due_at >= before + offset && due_at <= chrono::Utc::now() + offset);
+    }
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Список шаблонов и доступ ====================
+
+#[tokio::test]
+async fn templates_are_listed_and_deleted_by_owner_only() {
+    let state = common::test_app_state().await;
+    let email = "templates_owner@example.com";
+    let other_email = "templates_stranger@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (_, template) = common::send(&state, &token, "POST", format!("/lists/{}/template", list_id), serde_json::json!({})).await;
+    assert_eq!(template["title"], "Test List for Tasks");
+    assert!(template["tasks"].as_array().unwrap().is_empty());
+    let template_uri = format!("/templates/{}", template["id"].as_str().unwrap());
+
+    let (_, templates) = common::send(&state, &token, "GET", "/templates".to_string(), serde_json::Value::Null).await;
+    let ids: Vec<&str> = templates.as_array().unwrap().iter().map(|template| template["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["release_checklist", "weekly_review", "trip", template["id"].as_str().unwrap()]);
+
+    let (_, templates) = common::send(&state, &other, "GET", "/templates".to_string(), serde_json::Value::Null).await;
+    assert_eq!(templates.as_array().unwrap().len(), 3);
+    for (method, uri) in [
+        ("GET", template_uri.clone()),
+        ("POST", format!("{}/instantiate", template_uri)),
+        ("DELETE", template_uri.clone()),
+        ("POST", format!("/lists/{}/template", list_id)),
+        ("GET", "/templates/sprint".to_string()),
+    ] {
+        let (status, _) = common::send(&state, &other, method, uri.clone(), serde_json::json!({})).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{} {}", method, uri);
+    }
+
+    let (status, _) = common::send(&state, &token, "DELETE", "/templates/trip".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = common::send(&state, &token, "DELETE", template_uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = common::send(&state, &token, "GET", template_uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, other_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn saved_templates_are_paginated_by_title() {
+    let state = common::test_app_state().await;
+    let email = "templates_paged@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    for title in ["Вечер", "Бэклог", "Арсенал"] {
+        common::send(&state, &token, "POST", format!("/lists/{}/template", list_id), serde_json::json!({ "title": title }))
+            .await;
+    }
+
+    // Встроенные — только на первой странице и в limit не считаются.
+    let (status, next, templates) = common::send_paged(&state, &token, "GET", "/templates?limit=2", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let templates = templates.as_array().unwrap();
+    assert_eq!(templates.iter().filter(|template| template["builtin"] == true).count(), 3);
+    let saved: Vec<&str> = templates[3..].iter().map(|template| template["title"].as_str().unwrap()).collect();
+    assert_eq!(saved, vec!["Арсенал", "Бэклог"]);
+
+    let (_, last, templates) = common::send_paged(&state, &token, "GET", next.unwrap(), None).await;
+    let saved: Vec<&str> =
+        templates.as_array().unwrap().iter().map(|template| template["title"].as_str().unwrap()).collect();
+    assert_eq!(saved, vec!["Вечер"]);
+    assert!(last.is_none());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация ====================
+
+#[tokio::test]
+async fn empty_titles_are_rejected() {
+    let state = common::test_app_state().await;
+    let email = "templates_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    let (status, _) = common::send(&state, &token, "POST", format!("/lists/{}/template", list_id), serde_json::json!({ "title": "  " })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        "/templates/weekly_review/instantiate".to_string(),
+        serde_json::json!({ "title": "" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Ничего не создано.
+    let (_, lists) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert_eq!(lists.as_array().unwrap().len(), 1);
+    let (_, templates) = common::send(&state, &token, "GET", "/templates".to_string(), serde_json::Value::Null).await;
+    assert_eq!(templates.as_array().unwrap().len(), 3);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn too_large_list_is_not_saved_as_template() {
+    let state = common::test_app_state().await;
+    let email = "templates_too_large@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    sqlx::query(
+        "INSERT INTO tasks (list_id, title, position) \
+         SELECT $1, 'Задача ' || n, lpad(n::text, 4, '0') FROM generate_series(1, 501) AS n",
+    )
+    .bind(list_id.parse::<uuid::Uuid>().unwrap())
+    .execute(&state.db)
+    .await
+    .unwrap();
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/template", list_id),
+        serde_json::json!({ "title": "Огромный" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (_, templates) = common::send(&state, &token, "GET", "/templates".to_string(), serde_json::Value::Null).await;
+    assert_eq!(templates.as_array().unwrap().len(), 3);
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,375 @@
+/// Тесты учёта времени: оценки, таймеры, ручные записи, сводки и отчёт.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::state::AppState;
+
+/// Создаёт список с указанным названием и возвращает его UUID.
+async fn create_named_list(state: &AppState, token: &str, title: &str) -> String {
+    let (_, list) = common::send(state, token, "POST", "/lists".to_string(), serde_json::json!({ "title": title })).await;
+    list["id"].as_str().unwrap().to_string()
+}
+
+// ==================== Оценки ====================
+
+#[tokio::test]
+async fn estimate_is_set_and_cleared_with_patch() {
+    let state = common::test_app_state().await;
+    let email = "time_estimate@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Оценить").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    let (status, task) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "estimate_minutes": 90 })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["estimate_minutes"], 90);
+    assert_eq!(task["tracked_seconds"], 0);
+
+    let (status, _) = common::send(&state, &token, "PATCH", uri.clone(), serde_json::json!({ "estimate_minutes": 0 })).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, task) = common::send(&state, &token, "PATCH", uri, serde_json::json!({ "estimate_minutes": null })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(task["estimate_minutes"].is_null());
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Таймер ====================
+
+#[tokio::test]
+async fn only_one_timer_runs_per_user() {
+    let state = common::test_app_state().await;
+    let email = "time_timer@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let first = common::create_task(&state, &token, &list_id, "Первая").await;
+    let second = common::create_task(&state, &token, &list_id, "Вторая").await;
+
+    let (status, entry) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/start", list_id, first),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert!(entry["ended_at"].is_null());
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/start", list_id, second),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, running) = common::send(&state, &token, "GET", "/me/timer".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(running["task_id"], first.as_str());
+
+    // На второй задаче таймер не идёт.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/stop", list_id, second),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, entry) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/stop", list_id, first),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(entry["ended_at"].is_string());
+
+    let (status, _) = common::send(&state, &token, "GET", "/me/timer".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // После остановки можно запустить таймер на другой задаче.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/start", list_id, second),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Ручные записи и сводка ====================
+
+#[tokio::test]
+async fn manual_entries_roll_up_to_task_and_list() {
+    let state = common::test_app_state().await;
+    let email = "time_manual@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Сделать отчёт").await;
+    common::create_task(&state, &token, &list_id, "Без времени").await;
+    let entries_uri = format!("/lists/{}/tasks/{}/time-entries", list_id, task_id);
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        entries_uri.clone(),
+        serde_json::json!({ "started_at": "2026-01-05T10:00:00Z", "ended_at": "2026-01-05T09:00:00Z" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (status, entry) = common::send(
+        &state,
+        &token,
+        "POST",
+        entries_uri.clone(),
+        serde_json::json!({ "started_at": "2026-01-05T09:00:00Z", "ended_at": "2026-01-05T10:30:00Z", "note": "черновик" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(entry["duration_seconds"], 5400);
+    let (_, extra) = common::send(
+        &state,
+        &token,
+        "POST",
+        entries_uri.clone(),
+        serde_json::json!({ "started_at": "2026-01-06T09:00:00Z", "ended_at": "2026-01-06T09:30:00Z" }),
+    )
+    .await;
+
+    let (_, task) = common::send(&state, &token, "GET", format!("/lists/{}/tasks/{}", list_id, task_id), serde_json::Value::Null).await;
+    assert_eq!(task["tracked_seconds"], 7200);
+
+    common::send(
+        &state,
+        &token,
+        "PATCH",
+        format!("/lists/{}/tasks/{}", list_id, task_id),
+        serde_json::json!({ "estimate_minutes": 180 }),
+    )
+    .await;
+
+    let (status, summary) = common::send(&state, &token, "GET", format!("/lists/{}/time", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(summary["estimate_minutes"], 180);
+    assert_eq!(summary["tracked_seconds"], 7200);
+    assert_eq!(summary["tasks"].as_array().unwrap().len(), 2);
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "DELETE",
+        format!("{}/{}", entries_uri, extra["id"].as_str().unwrap()),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, entries) = common::send(&state, &token, "GET", entries_uri, serde_json::Value::Null).await;
+    assert_eq!(entries.as_array().unwrap().len(), 1);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Отчёт ====================
+
+#[tokio::test]
+async fn report_groups_by_list_and_day_and_exports_csv() {
+    let state = common::test_app_state().await;
+    let email = "time_report@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let acme = create_named_list(&state, &token, "Acme, Inc.").await;
+    let other = create_named_list(&state, &token, "Other").await;
+    let acme_task = common::create_task(&state, &token, &acme, "Вёрстка").await;
+    let other_task = common::create_task(&state, &token, &other, "Поддержка").await;
+
+    for (list_id, task_id, started_at, ended_at) in [
+        (&acme, &acme_task, "2026-01-05T09:00:00Z", "2026-01-05T10:00:00Z"),
+        (&acme, &acme_task, "2026-01-05T14:00:00Z", "2026-01-05T14:30:00Z"),
+        (&other, &other_task, "2026-01-06T09:00:00Z", "2026-01-06T09:15:00Z"),
+        // Вне периода отчёта.
+        (&other, &other_task, "2026-01-10T09:00:00Z", "2026-01-10T10:00:00Z"),
+    ] {
+        common::send(
+            &state,
+            &token,
+            "POST",
+            format!("/lists/{}/tasks/{}/time-entries", list_id, task_id),
+            serde_json::json!({ "started_at": started_at, "ended_at": ended_at }),
+        )
+        .await;
+    }
+
+    let (status, report) = common::send(
+        &state,
+        &token,
+        "GET",
+        "/reports/time?from=2026-01-05&to=2026-01-06".to_string(),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report["total_seconds"], 5400 + 900);
+    let rows: Vec<(&str, &str, i64)> = report["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| {
+            (
+                row["list_title"].as_str().unwrap(),
+                row["date"].as_str().unwrap(),
+                row["seconds"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(rows, vec![("Acme, Inc.", "2026-01-05", 5400), ("Other", "2026-01-06", 900)]);
+
+    let (status, headers, body) = common::send_raw(
+        &state,
+        &token,
+        "GET",
+        format!("/reports/time?from=2026-01-05&to=2026-01-06&list_id={}&format=csv", acme),
+        &[],
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(headers["content-type"].to_str().unwrap().starts_with("text/csv"));
+    let csv = String::from_utf8(body).unwrap();
+    assert_eq!(
+        csv,
+        format!("list_id,list_title,date,seconds,hours\n{},\"Acme, Inc.\",2026-01-05,5400,1.50\n", acme)
+    );
+
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "GET",
+        "/reports/time?from=2026-01-06&to=2026-01-05".to_string(),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // Последний день, который представим в chrono: следующего за ним нет.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "GET",
+        "/reports/time?from=%2B262142-12-30&to=%2B262142-12-31".to_string(),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn report_splits_entries_by_day_and_skips_running_timers() {
+    let state = common::test_app_state().await;
+    let email = "time_report_split@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = create_named_list(&state, &token, "=HYPERLINK(\"http://x\")").await;
+    let task_id = common::create_task(&state, &token, &list_id, "Ночная смена").await;
+
+    for (started_at, ended_at) in [
+        // Начата до периода — учитывается только часть внутри него.
+        ("2026-01-04T23:00:00Z", "2026-01-05T01:00:00Z"),
+        // Через полночь — делится между днями.
+        ("2026-01-05T23:30:00Z", "2026-01-06T00:30:00Z"),
+        // Закончилась после периода.
+        ("2026-01-06T22:00:00Z", "2026-01-07T02:00:00Z"),
+    ] {
+        let (status, _) = common::send(
+            &state,
+            &token,
+            "POST",
+            format!("/lists/{}/tasks/{}/time-entries", list_id, task_id),
+            serde_json::json!({ "started_at": started_at, "ended_at": ended_at }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    let (_, report) = common::send(
+        &state,
+        &token,
+        "GET",
+        "/reports/time?from=2026-01-05&to=2026-01-06".to_string(),
+        serde_json::Value::Null,
+    )
+    .await;
+    let rows: Vec<(&str, i64)> = report["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| (row["date"].as_str().unwrap(), row["seconds"].as_i64().unwrap()))
+        .collect();
+    assert_eq!(rows, vec![("2026-01-05", 3600 + 1800), ("2026-01-06", 1800 + 7200)]);
+
+    // Название, похожее на формулу, в CSV начинается с апострофа.
+    let (_, _, body) = common::send_raw(
+        &state,
+        &token,
+        "GET",
+        "/reports/time?from=2026-01-05&to=2026-01-05&format=csv".to_string(),
+        &[],
+        serde_json::Value::Null,
+    )
+    .await;
+    let csv = String::from_utf8(body).unwrap();
+    assert!(csv.contains(",\"'=HYPERLINK(\"\"http://x\"\")\",2026-01-05,"), "{csv}");
+
+    // Идущий таймер не попадает в отчёт, пока его не остановят.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/timer/start", list_id, task_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    sqlx::query("UPDATE time_entries SET started_at = now() - interval '1 hour' WHERE ended_at IS NULL AND task_id = $1")
+        .bind(task_id.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+    let today = chrono::Utc::now().date_naive();
+    let (_, report) = common::send(
+        &state,
+        &token,
+        "GET",
+        format!("/reports/time?from={}&to={}", today - chrono::Days::new(1), today),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(report["total_seconds"], 0);
+
+    common::cleanup_user(&state.db, email).await;
+}
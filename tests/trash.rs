@@ -0,0 +1,241 @@
+/// Тесты корзины: мягкое удаление, восстановление и очистка.
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::jobs;
+use todo_api::state::AppState;
+use todo_api::storage::StorageError;
+
+/// Названия задач списка.
+async fn task_titles(state: &AppState, token: &str, list_id: &str) -> Vec<String> {
+    let (_, tasks) = common::send(state, token, "GET", format!("/lists/{}/tasks", list_id), serde_json::Value::Null).await;
+    tasks
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+// ==================== Задачи ====================
+
+#[tokio::test]
+async fn deleted_task_goes_to_trash_and_can_be_restored() {
+    let state = common::test_app_state().await;
+    let email = "trash_task@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "Случайно удалённая").await;
+    common::create_task(&state, &token, &list_id, "Живая").await;
+    let uri = format!("/lists/{}/tasks/{}", list_id, task_id);
+
+    let (status, _) = common::send(&state, &token, "DELETE", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = common::send(&state, &token, "GET", uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(task_titles(&state, &token, &list_id).await, vec!["Живая"]);
+
+    let (status, trash) = common::send(&state, &token, "GET", "/trash".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(trash["tasks"].as_array().unwrap().len(), 1);
+    assert_eq!(trash["tasks"][0]["id"], task_id.as_str());
+    assert_eq!(trash["tasks"][0]["list_deleted"], false);
+
+    let (status, task) = common::send(&state, &token, "POST", format!("{}/restore", uri), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["title"], "Случайно удалённая");
+    assert_eq!(task_titles(&state, &token, &list_id).await, vec!["Случайно удалённая", "Живая"]);
+
+    // Задачи больше нет в корзине.
+    let (status, _) = common::send(&state, &token, "POST", format!("{}/restore", uri), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (_, history) = common::send(&state, &token, "GET", format!("{}/history", uri), serde_json::Value::Null).await;
+    let actions: Vec<&str> = history["events"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|event| event["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, vec!["restored", "deleted", "created"]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn deleting_task_or_list_stops_running_timer() {
+    let state = common::test_app_state().await;
+    let email = "trash_timer@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "С таймером").await;
+    let other_task = common::create_task(&state, &token, &list_id, "Следующая").await;
+
+    let start = |task_id: &str| format!("/lists/{}/tasks/{}/timer/start", list_id, task_id);
+    let (status, _) = common::send(&state, &token, "POST", start(&task_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    common::send(&state, &token, "DELETE", format!("/lists/{}/tasks/{}", list_id, task_id), serde_json::Value::Null).await;
+    let (status, _) = common::send(&state, &token, "GET", "/me/timer".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // Таймер по задаче из удалённого списка тоже останавливается.
+    let (status, _) = common::send(&state, &token, "POST", start(&other_task), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CREATED);
+    common::send(&state, &token, "DELETE", format!("/lists/{}", list_id), serde_json::Value::Null).await;
+    let (status, _) = common::send(&state, &token, "GET", "/me/timer".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Списки ====================
+
+#[tokio::test]
+async fn restoring_list_revives_only_tasks_deleted_with_it() {
+    let state = common::test_app_state().await;
+    let email = "trash_list@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &list_id, "Первая").await;
+    common::create_task(&state, &token, &list_id, "Вторая").await;
+    let earlier = common::create_task(&state, &token, &list_id, "Удалена раньше").await;
+
+    common::send(&state, &token, "DELETE", format!("/lists/{}/tasks/{}", list_id, earlier), serde_json::Value::Null).await;
+    let (status, _) = common::send(&state, &token, "DELETE", format!("/lists/{}", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (_, lists) = common::send(&state, &token, "GET", "/lists".to_string(), serde_json::Value::Null).await;
+    assert!(lists.as_array().unwrap().is_empty());
+
+    let (_, trash) = common::send(&state, &token, "GET", "/trash".to_string(), serde_json::Value::Null).await;
+    assert_eq!(trash["lists"].as_array().unwrap().len(), 1);
+    assert_eq!(trash["lists"][0]["task_count"], 2);
+    assert_eq!(trash["tasks"].as_array().unwrap().len(), 1);
+    assert_eq!(trash["tasks"][0]["list_deleted"], true);
+
+    // Задачу удалённого списка вернуть нельзя, пока не вернули сам список.
+    let (status, _) = common::send(
+        &state,
+        &token,
+        "POST",
+        format!("/lists/{}/tasks/{}/restore", list_id, earlier),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = common::send(&state, &token, "POST", format!("/lists/{}/restore", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task_titles(&state, &token, &list_id).await, vec!["Первая", "Вторая"]);
+
+    let (_, trash) = common::send(&state, &token, "GET", "/trash".to_string(), serde_json::Value::Null).await;
+    assert!(trash["lists"].as_array().unwrap().is_empty());
+    assert_eq!(trash["tasks"][0]["id"], earlier.as_str());
+    assert_eq!(trash["tasks"][0]["list_deleted"], false);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn trash_is_private() {
+    let state = common::test_app_state().await;
+    let email = "trash_owner@example.com";
+    let stranger_email = "trash_stranger@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, stranger_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let stranger = common::get_auth_token(&state, stranger_email).await;
+    let list_id = common::create_list(&state, &token).await;
+
+    common::send(&state, &token, "DELETE", format!("/lists/{}", list_id), serde_json::Value::Null).await;
+
+    let (_, trash) = common::send(&state, &stranger, "GET", "/trash".to_string(), serde_json::Value::Null).await;
+    assert!(trash["lists"].as_array().unwrap().is_empty());
+
+    let (status, _) = common::send(&state, &stranger, "POST", format!("/lists/{}/restore", list_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, stranger_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Очистка ====================
+
+#[tokio::test]
+async fn purge_removes_only_expired_items() {
+    let state = common::test_app_state().await;
+    let email = "trash_purge@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let old_task = common::create_task(&state, &token, &list_id, "Давно удалена").await;
+    let fresh_task = common::create_task(&state, &token, &list_id, "Только что удалена").await;
+
+    for task_id in [&old_task, &fresh_task] {
+        common::send(&state, &token, "DELETE", format!("/lists/{}/tasks/{}", list_id, task_id), serde_json::Value::Null).await;
+    }
+    sqlx::query("UPDATE tasks SET deleted_at = now() - interval '31 days' WHERE id = $1")
+        .bind(old_task.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+    let purged = jobs::trash::run_once(&state.db, state.blobs.as_ref(), chrono::Duration::days(30)).await.unwrap();
+    assert!(purged >= 1);
+
+    let (_, trash) = common::send(&state, &token, "GET", "/trash".to_string(), serde_json::Value::Null).await;
+    let ids: Vec<&str> = trash["tasks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids, vec![fresh_task.as_str()]);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn purge_deletes_attachment_blobs() {
+    let state = common::test_app_state().await;
+    let email = "trash_purge_blobs@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let list_id = common::create_list(&state, &token).await;
+    let task_id = common::create_task(&state, &token, &list_id, "С вложением").await;
+    let task_uuid = task_id.parse::<uuid::Uuid>().unwrap();
+
+    let key = format!("trash-purge/{}", uuid::Uuid::new_v4());
+    state.blobs.put(&key, "attachment".into(), "text/plain").await.unwrap();
+    sqlx::query(
+        "INSERT INTO attachments (task_id, uploader_id, filename, content_type, size_bytes, storage_key) \
+         SELECT $1, id, 'notes.txt', 'text/plain', 10, $2 FROM users WHERE email = $3",
+    )
+    .bind(task_uuid)
+    .bind(&key)
+    .bind(email)
+    .execute(&state.db)
+    .await
+    .unwrap();
+
+    common::send(&state, &token, "DELETE", format!("/lists/{}", list_id), serde_json::Value::Null).await;
+    sqlx::query("UPDATE todo_lists SET deleted_at = now() - interval '31 days' WHERE id = $1")
+        .bind(list_id.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+
+    jobs::trash::run_once(&state.db, state.blobs.as_ref(), chrono::Duration::days(30)).await.unwrap();
+
+    assert!(matches!(state.blobs.get(&key).await, Err(StorageError::NotFound)));
+
+    common::cleanup_user(&state.db, email).await;
+}
@@ -0,0 +1,281 @@
+/// Тесты представлений (GET/POST /views, GET /views/{id}/tasks).
+mod common;
+
+use axum::http::StatusCode;
+
+use todo_api::state::AppState;
+
+/// Названия задач из ответа.
+fn titles(tasks: &serde_json::Value) -> Vec<String> {
+    tasks
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|task| task["title"].as_str().unwrap().to_string())
+        .collect()
+}
+
+/// Задачи представления `view_id`.
+async fn view_tasks(state: &AppState, token: &str, view_id: &str) -> (StatusCode, serde_json::Value) {
+    let (status, _, tasks) = common::send_paged(state, token, "GET", format!("/views/{}/tasks", view_id), serde_json::Value::Null).await;
+    (status, tasks)
+}
+
+/// Меняет статус задачи.
+async fn set_status(state: &AppState, token: &str, list_id: &str, task_id: &str, status: &str) {
+    let (code, _, _) = common::send_paged(
+        state,
+        token,
+        "PUT",
+        format!("/lists/{}/tasks/{}/status", list_id, task_id),
+        serde_json::json!({ "status": status }),
+    )
+    .await;
+    assert_eq!(code, StatusCode::OK);
+}
+
+
+// ==================== Встроенные представления ====================
+
+#[tokio::test]
+async fn builtin_views_collect_tasks_from_all_lists() {
+    let state = common::test_app_state().await;
+    let email = "views_builtin@example.com";
+    let other_email = "views_builtin_other@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+
+    let home = common::create_list(&state, &token).await;
+    let work = common::create_list(&state, &token).await;
+    let archived = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &home, "Купить молоко").await;
+    let report = common::create_task(&state, &token, &work, "Отчёт").await;
+    let review = common::create_task(&state, &token, &work, "Ревью").await;
+    common::create_task(&state, &token, &archived, "Из архива").await;
+    let foreign = common::create_list(&state, &other).await;
+    common::create_task(&state, &other, &foreign, "Чужая задача").await;
+
+    set_status(&state, &token, &work, &report, "done").await;
+    set_status(&state, &token, &work, &review, "in_progress").await;
+    common::send_paged(&state, &token, "POST", format!("/lists/{}/archive", archived), serde_json::Value::Null).await;
+
+    let (status, _, views) = common::send_paged(&state, &token, "GET", "/views".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::OK);
+    let ids: Vec<&str> = views.as_array().unwrap().iter().map(|view| view["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, vec!["open", "in_progress", "recently_completed", "recently_created"]);
+    assert!(views[0]["builtin"].as_bool().unwrap());
+
+    let (status, tasks) = view_tasks(&state, &token, "open").await;
+    assert_eq!(status, StatusCode::OK);
+    let mut open = titles(&tasks);
+    open.sort();
+    assert_eq!(open, vec!["Купить молоко", "Ревью"]);
+
+    let (_, tasks) = view_tasks(&state, &token, "in_progress").await;
+    assert_eq!(titles(&tasks), vec!["Ревью"]);
+
+    let (_, tasks) = view_tasks(&state, &token, "recently_completed").await;
+    assert_eq!(titles(&tasks), vec!["Отчёт"]);
+
+    // Выполненная давно уже не «недавно выполненная».
+    sqlx::query("UPDATE tasks SET completed_at = now() - interval '8 days' WHERE id = $1")
+        .bind(report.parse::<uuid::Uuid>().unwrap())
+        .execute(&state.db)
+        .await
+        .unwrap();
+    let (_, tasks) = view_tasks(&state, &token, "recently_completed").await;
+    assert!(titles(&tasks).is_empty());
+
+    let (_, tasks) = view_tasks(&state, &token, "recently_created").await;
+    assert_eq!(tasks.as_array().unwrap().len(), 3);
+
+    let (status, _) = view_tasks(&state, &token, "someday").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, other_email).await;
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Сохранённые представления ====================
+
+#[tokio::test]
+async fn saved_view_crud_and_tasks() {
+    let state = common::test_app_state().await;
+    let email = "views_saved@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let home = common::create_list(&state, &token).await;
+    let work = common::create_list(&state, &token).await;
+    common::create_task(&state, &token, &home, "Q1 дома").await;
+    common::create_task(&state, &token, &work, "Q1 отчёт").await;
+    common::create_task(&state, &token, &work, "Q1 бюджет").await;
+    common::create_task(&state, &token, &work, "Прочее").await;
+
+    let (status, _, view) = common::send_paged(
+        &state,
+        &token,
+        "POST",
+        "/views".to_string(),
+        serde_json::json!({ "name": "  Квартал  ", "filter": "title:contains:q1", "sort": "title" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(view["name"], "Квартал");
+    assert_eq!(view["builtin"], false);
+    let view_id = view["id"].as_str().unwrap().to_string();
+
+    // Постранично, ссылка на следующую страницу сохраняет адрес представления.
+    let (status, next, tasks) = common::send_paged(
+        &state,
+        &token,
+        "GET",
+        format!("/views/{}/tasks?limit=2", view_id),
+        serde_json::Value::Null,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(titles(&tasks), vec!["Q1 бюджет", "Q1 дома"]);
+    let next = next.unwrap();
+    assert!(next.starts_with(&format!("/views/{}/tasks?limit=2&cursor=", view_id)));
+    let (_, last, tasks) = common::send_paged(&state, &token, "GET", next, serde_json::Value::Null).await;
+    assert_eq!(titles(&tasks), vec!["Q1 отчёт"]);
+    assert!(last.is_none());
+
+    let (status, _, view) = common::send_paged(
+        &state,
+        &token,
+        "PUT",
+        format!("/views/{}", view_id),
+        serde_json::json!({ "name": "Работа", "filter": format!("list_id:eq:{}", work), "sort": "-title" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(view["filter"], format!("list_id:eq:{}", work));
+    let (_, tasks) = view_tasks(&state, &token, &view_id).await;
+    assert_eq!(titles(&tasks), vec!["Прочее", "Q1 отчёт", "Q1 бюджет"]);
+
+    let (_, _, views) = common::send_paged(&state, &token, "GET", "/views".to_string(), serde_json::Value::Null).await;
+    assert_eq!(views.as_array().unwrap().last().unwrap()["name"], "Работа");
+
+    let (status, _, _) = common::send_paged(&state, &token, "DELETE", format!("/views/{}", view_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, _) = common::send_paged(&state, &token, "GET", format!("/views/{}", view_id), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn saved_views_are_paginated_by_name() {
+    let state = common::test_app_state().await;
+    let email = "views_paged@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+    for name in ["Вечер", "Бэклог", "Арсенал"] {
+        common::send(&state, &token, "POST", "/views", serde_json::json!({ "name": name })).await;
+    }
+
+    // Встроенные — только на первой странице и в limit не считаются.
+    let (status, next, views) = common::send_paged(&state, &token, "GET", "/views?limit=2", None).await;
+    assert_eq!(status, StatusCode::OK);
+    let views = views.as_array().unwrap();
+    assert_eq!(views.iter().filter(|view| view["builtin"] == true).count(), 4);
+    let saved: Vec<&str> = views[4..].iter().map(|view| view["name"].as_str().unwrap()).collect();
+    assert_eq!(saved, vec!["Арсенал", "Бэклог"]);
+
+    let (_, last, views) = common::send_paged(&state, &token, "GET", next.unwrap(), None).await;
+    let saved: Vec<&str> = views.as_array().unwrap().iter().map(|view| view["name"].as_str().unwrap()).collect();
+    assert_eq!(saved, vec!["Вечер"]);
+    assert!(last.is_none());
+
+    let (status, _, _) = common::send_paged(&state, &token, "GET", "/views?cursor=broken", None).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+
+// ==================== Валидация и доступ ====================
+
+#[tokio::test]
+async fn invalid_views_are_rejected() {
+    let state = common::test_app_state().await;
+    let email = "views_invalid@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    let (status, _, _) = common::send_paged(&state, &token, "POST", "/views".to_string(), serde_json::json!({ "name": "Все" })).await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    for body in [
+        serde_json::json!({ "name": " " }),
+        serde_json::json!({ "name": "Плохой фильтр", "filter": "owner:eq:me" }),
+        serde_json::json!({ "name": "Плохая дата", "filter": "due_at:lt:now-7y" }),
+        serde_json::json!({ "name": "Плохая сортировка", "sort": "priority" }),
+    ] {
+        let (status, _, _) = common::send_paged(&state, &token, "POST", "/views".to_string(), body.clone()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{}", body);
+    }
+
+    let (status, _, _) = common::send_paged(&state, &token, "POST", "/views".to_string(), serde_json::json!({ "name": "Все" })).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn concurrent_duplicate_views_return_409() {
+    let state = common::test_app_state().await;
+    let email = "views_duplicate_race@example.com";
+    common::cleanup_user(&state.db, email).await;
+    let token = common::get_auth_token(&state, email).await;
+
+    // Оба запроса могут пройти проверку имени до вставки — второй упрётся в UNIQUE.
+    let results = futures_util::future::join_all(
+        (0..4).map(|_| common::send(&state, &token, "POST", "/views", serde_json::json!({ "name": "Срочное" }))),
+    )
+    .await;
+    let mut statuses: Vec<StatusCode> = results.into_iter().map(|(status, _)| status).collect();
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        vec![StatusCode::CREATED, StatusCode::CONFLICT, StatusCode::CONFLICT, StatusCode::CONFLICT]
+    );
+
+    common::cleanup_user(&state.db, email).await;
+}
+
+#[tokio::test]
+async fn builtin_and_foreign_views_cannot_be_changed() {
+    let state = common::test_app_state().await;
+    let email = "views_owner@example.com";
+    let other_email = "views_stranger@example.com";
+    common::cleanup_user(&state.db, email).await;
+    common::cleanup_user(&state.db, other_email).await;
+    let token = common::get_auth_token(&state, email).await;
+    let other = common::get_auth_token(&state, other_email).await;
+
+    let update = serde_json::json!({ "name": "Моё", "filter": "", "sort": "" });
+    let (status, _, _) = common::send_paged(&state, &token, "PUT", "/views/open".to_string(), update.clone()).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    let (status, _, _) = common::send_paged(&state, &token, "DELETE", "/views/in_progress".to_string(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (_, _, view) = common::send_paged(&state, &token, "POST", "/views".to_string(), serde_json::json!({ "name": "Личное" })).await;
+    let view_uri = format!("/views/{}", view["id"].as_str().unwrap());
+
+    let (status, _, _) = common::send_paged(&state, &other, "GET", view_uri.clone(), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _, _) = common::send_paged(&state, &other, "GET", format!("{}/tasks", view_uri), serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _, _) = common::send_paged(&state, &other, "PUT", view_uri.clone(), update).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _, _) = common::send_paged(&state, &other, "DELETE", view_uri, serde_json::Value::Null).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    common::cleanup_user(&state.db, other_email).await;
+    common::cleanup_user(&state.db, email).await;
+}